@@ -0,0 +1,150 @@
+//! A local stand-in for the drand HTTP API, serving quicknet and evmnet style beacons signed with
+//! test keys.
+
+use bls_solidity_drand::server;
+use bls_solidity_rs::drand::{DrandScheme, TestChain};
+
+use anyhow::{Context, bail};
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str =
+    "Usage: beacon-server [--listen <address>] [--period <seconds>] [--genesis <unix time>]
+                     [--seed <string>]
+Serves /v2/beacons/{quicknet,evmnet}/{info,rounds/latest,rounds/<round>}, and the same routes
+under /v2/chains/<chain hash>, like api.drand.sh.
+--listen defaults to 127.0.0.1:8080, --period to 3 and --genesis to the start time. The test keys
+are derived from --seed, so a seed always gives the same public keys.";
+
+const DEFAULT_SEED: &str = "bls-solidity-drand";
+
+struct Options {
+    listen: String,
+    period: u64,
+    genesis: Option<u64>,
+    seed: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let options = match parse_options(&std::env::args().skip(1).collect::<Vec<_>>()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    let genesis = options.genesis.unwrap_or_else(now);
+    let chains: Arc<Vec<TestChain>> = Arc::new(
+        [
+            (DrandScheme::UnchainedG1Rfc9380, "quicknet"),
+            (DrandScheme::Bn254UnchainedOnG1, "evmnet"),
+        ]
+        .into_iter()
+        .map(|(scheme, id)| {
+            TestChain::new(
+                scheme,
+                options.seed.as_bytes(),
+                options.period,
+                genesis,
+                Some(id.to_owned()),
+            )
+        })
+        .collect(),
+    );
+
+    let listener = TcpListener::bind(&options.listen)
+        .with_context(|| format!("cannot listen on {}", options.listen))?;
+    for chain in chains.iter() {
+        println!(
+            "http://{}/v2/beacons/{}/info {}",
+            listener.local_addr()?,
+            chain.info.beacon_id.as_deref().unwrap_or_default(),
+            chain.info.to_json()
+        );
+    }
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        let chains = Arc::clone(&chains);
+        thread::spawn(move || {
+            if let Err(e) = serve(stream, &chains) {
+                eprintln!("{e:#}");
+            }
+        });
+    }
+    Ok(())
+}
+
+fn parse_options(args: &[String]) -> anyhow::Result<Options> {
+    let mut options = Options {
+        listen: "127.0.0.1:8080".to_owned(),
+        period: 3,
+        genesis: None,
+        seed: DEFAULT_SEED.to_owned(),
+    };
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .with_context(|| format!("missing value for {flag}"))?;
+        match flag.as_str() {
+            "--listen" => options.listen = value.clone(),
+            "--period" => options.period = value.parse().context("invalid period")?,
+            "--genesis" => options.genesis = Some(value.parse().context("invalid genesis")?),
+            "--seed" => options.seed = value.clone(),
+            _ => bail!("unknown option {flag}"),
+        }
+    }
+    if options.period == 0 {
+        bail!("period must not be zero");
+    }
+    Ok(options)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time after the epoch")
+        .as_secs()
+}
+
+/// Answers one request, then closes the connection.
+fn serve(mut stream: TcpStream, chains: &[TestChain]) -> anyhow::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // headers and body are not needed for GET
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let (status, body) = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["GET", path, _] => server::route(path, chains, now()),
+        _ => (405, "method not allowed".to_owned()),
+    };
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Too Early",
+    };
+    let content_type = if status == 200 {
+        "application/json"
+    } else {
+        "text/plain"
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+//! HTTP requests to the drand API, a node or a beacon server, over `http://` or `https://`.
+
+use anyhow::{Context, ensure};
+
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `GET url`, returning the body of a 2xx response.
+pub fn get(url: &str) -> anyhow::Result<Vec<u8>> {
+    let response = agent()
+        .get(url)
+        .call()
+        .with_context(|| format!("GET {url}"))?;
+    body("GET", url, response)
+}
+
+/// `POST url` with a JSON body, returning the body of a 2xx response.
+pub fn post_json(url: &str, body: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let response = agent()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .send(body)
+        .with_context(|| format!("POST {url}"))?;
+    self::body("POST", url, response)
+}
+
+fn agent() -> ureq::Agent {
+    ureq::Agent::config_builder()
+        .timeout_global(Some(TIMEOUT))
+        // the body of an error response is part of the error
+        .http_status_as_error(false)
+        .build()
+        .into()
+}
+
+fn body(
+    method: &str,
+    url: &str,
+    mut response: ureq::http::Response<ureq::Body>,
+) -> anyhow::Result<Vec<u8>> {
+    let status = response.status();
+    let body = response
+        .body_mut()
+        .read_to_vec()
+        .with_context(|| format!("{method} {url}: cannot read the body"))?;
+    ensure!(
+        status.is_success(),
+        "{method} {url}: HTTP {}: {}",
+        status.as_u16(),
+        String::from_utf8_lossy(&body)
+    );
+    Ok(body)
+}
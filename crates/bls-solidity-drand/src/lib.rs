@@ -0,0 +1,9 @@
+//! drand beacon submission to `QuicknetRegistry` and `EvmnetRegistry`, and registry generation
+//! for other chains, behind the `bls-solidity-drand` command, and the routes of `beacon-server`.
+
+pub mod http;
+pub mod prove;
+pub mod registry;
+pub mod rpc;
+pub mod server;
+pub mod tx;
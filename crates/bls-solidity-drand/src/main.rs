@@ -0,0 +1,159 @@
+use bls_solidity_drand::{http, prove, registry};
+use bls_solidity_rs::drand::{Beacon, ChainInfo};
+
+use anyhow::{Context, bail};
+
+use std::env;
+use std::io::Read;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "Usage: bls-solidity-drand <command> <chain> ...
+    info <chain>                    chain parameters
+    round <chain> [unix time]       round emitted at a time, now by default
+    time <chain> <round>            unix time at which a round is emitted
+    verify <chain> <beacon>         verifies a beacon like the registry of the chain
+    prove <chain> <beacon> [--registry <address|broadcast JSON>] [--rpc <url>]
+          [--private-key <hex>] [--from <address>] [--nonce <n>] [--chain-id <id>]
+          [--gas-limit <gas>] [--max-fee-per-gas <wei>] [--max-priority-fee-per-gas <wei>]
+                                    verifies a beacon then submits proveRound to the registry
+                                    and checks its roundRandomness; without --rpc, prints the
+                                    transaction signed with the private key, which needs all
+                                    but the priority fee, or its unsigned to and data
+    registry <chain> [--name <Name>] [--out <dir>]
+                                    generates <Name>Registry and its deploy script, in
+                                    src/demos/ and script/ under --out or on stdout; the name
+                                    defaults to the beacon id
+<chain> is quicknet, evmnet, a chain info JSON file or a URL, e.g. of beacon-server
+<beacon> is a beacon JSON file, - for stdin, a URL, or latest for the latest round of the chain
+on the drand HTTP API at DRAND_URL, https://api.drand.sh by default
+The private key can also be set with PRIVATE_KEY, otherwise the node signs for --from or its
+first account, like anvil does";
+
+const DEFAULT_DRAND_URL: &str = "https://api.drand.sh";
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 {
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    }
+    let chain = chain_info(&args[2])?;
+
+    match (args[1].as_str(), &args[3..]) {
+        ("info", []) => {
+            println!("scheme: {}", chain.scheme);
+            println!("registry: {}", chain.scheme.registry());
+            println!("DST: {}", chain.scheme.dst());
+            println!("public key: {}", hex::encode(&chain.public_key));
+            println!("period: {}s", chain.period);
+            println!("genesis time: {}", chain.genesis_time);
+            println!("chain hash: {}", hex::encode(&chain.chain_hash));
+            if let Some(beacon_id) = &chain.beacon_id {
+                println!("beacon id: {beacon_id}");
+            }
+        }
+        ("round", rest @ ([] | [_])) => {
+            let time = match rest {
+                [time] => time.parse().context("invalid unix time")?,
+                _ => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            };
+            let round = chain.round_at(time);
+            if round == 0 {
+                bail!("{time} is before the genesis time {}", chain.genesis_time);
+            }
+            println!("round {round} emitted at {}", time_of_round(&chain, round)?);
+            let (next, next_time) = chain
+                .next_round(time)
+                .context("the next round is past the range of u64")?;
+            println!("round {next} expected at {next_time}");
+        }
+        ("time", [round]) => {
+            let round: u64 = round.parse().context("invalid round")?;
+            if round == 0 {
+                bail!("rounds start at 1");
+            }
+            println!("{}", time_of_round(&chain, round)?);
+        }
+        ("verify", [source]) => {
+            let beacon = verified_beacon(&chain, source)?;
+            println!("round {} is valid", beacon.round);
+            println!("randomness: {}", hex::encode(beacon.expected_randomness()));
+        }
+        ("prove", [source, options @ ..]) => {
+            let beacon = verified_beacon(&chain, source)?;
+            prove::run(&chain, &beacon, options)?;
+        }
+        ("registry", options) => registry::run(&chain, options)?,
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+    Ok(())
+}
+
+fn chain_info(chain: &str) -> anyhow::Result<ChainInfo> {
+    match chain {
+        "quicknet" => Ok(ChainInfo::quicknet()),
+        "evmnet" => Ok(ChainInfo::evmnet()),
+        url if is_url(url) => {
+            let json = http::get(url)?;
+            Ok(ChainInfo::from_json(
+                std::str::from_utf8(&json).context("chain info is not UTF-8")?,
+            )?)
+        }
+        path => {
+            let json =
+                std::fs::read_to_string(path).with_context(|| format!("cannot read {path}"))?;
+            Ok(ChainInfo::from_json(&json)?)
+        }
+    }
+}
+
+fn is_url(s: &str) -> bool {
+    s.starts_with("http://") || s.starts_with("https://")
+}
+
+/// `/v2/beacons/<beacon id>/rounds/latest` of the drand HTTP API.
+fn latest_url(chain: &ChainInfo) -> String {
+    let drand = env::var("DRAND_URL").unwrap_or_else(|_| DEFAULT_DRAND_URL.to_owned());
+    format!(
+        "{}/v2/beacons/{}/rounds/latest",
+        drand.trim_end_matches('/'),
+        chain.beacon_id.as_deref().unwrap_or("default")
+    )
+}
+
+fn time_of_round(chain: &ChainInfo, round: u64) -> anyhow::Result<u64> {
+    chain
+        .time_of_round(round)
+        .with_context(|| format!("round {round} is emitted past the range of u64"))
+}
+
+/// Reads a beacon from a file, stdin or a URL and verifies it for `chain`.
+fn verified_beacon(chain: &ChainInfo, source: &str) -> anyhow::Result<Beacon> {
+    let json = if source == "-" {
+        let mut json = Vec::new();
+        std::io::stdin().read_to_end(&mut json)?;
+        json
+    } else if source == "latest" {
+        http::get(&latest_url(chain))?
+    } else if is_url(source) {
+        http::get(source)?
+    } else {
+        std::fs::read(source).with_context(|| format!("cannot read {source}"))?
+    };
+    let beacon = Beacon::from_json(std::str::from_utf8(&json).context("beacon is not UTF-8")?)?;
+    if beacon.signature.len() != chain.scheme.signature_length() {
+        bail!(
+            "{} takes {} bytes signatures, got {}",
+            chain.scheme.registry(),
+            chain.scheme.signature_length(),
+            beacon.signature.len()
+        );
+    }
+    chain
+        .verify(&beacon)
+        .with_context(|| format!("round {}", beacon.round))?;
+    Ok(beacon)
+}
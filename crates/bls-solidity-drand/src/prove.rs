@@ -0,0 +1,259 @@
+//! `proveRound` on `QuicknetRegistry` or `EvmnetRegistry`, in place of `cast send`.
+//!
+//! With `--rpc`, the transaction is sent to the node and the registry is read back afterwards.
+//! Without it, the transaction is signed offline when a private key is set, which needs the
+//! nonce, chain id, gas limit and fees as options, otherwise its destination and calldata are
+//! printed.
+//!
+//! The registries hardcode the public key of the chain they verify, and the beacon is verified
+//! against the `<chain>` given on the command line. The mainnet `QuicknetRegistry` and
+//! `EvmnetRegistry` are only used for the pinned mainnet chains: a chain info fetched from
+//! elsewhere, e.g. a beacon server, needs the address of a registry generated for it with the
+//! `registry` command, and the `PUBLIC_KEY()` of the registry is checked before sending.
+
+use crate::rpc::{self, Client};
+use crate::tx::{self, Transaction, Wallet};
+
+use bls_solidity_rs::Curve;
+use bls_solidity_rs::abi::{self, Address, Token};
+use bls_solidity_rs::drand::{Beacon, ChainInfo, DrandScheme};
+use bls_solidity_rs::pubkey::{self, Format};
+
+use anyhow::{Context, bail};
+
+use std::thread;
+use std::time::Duration;
+
+const RECEIPT_POLLS: u32 = 60;
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+struct Options {
+    registry: Option<String>,
+    rpc: Option<String>,
+    private_key: Option<String>,
+    from: Option<String>,
+    nonce: Option<u64>,
+    chain_id: Option<u64>,
+    gas_limit: Option<u64>,
+    max_fee_per_gas: Option<u128>,
+    max_priority_fee_per_gas: Option<u128>,
+}
+
+fn parse_options(args: &[String]) -> anyhow::Result<Options> {
+    let mut options = Options::default();
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .with_context(|| format!("missing value for {flag}"))?
+            .clone();
+        let number = || format!("invalid {flag} {value}");
+        match flag.as_str() {
+            "--registry" => options.registry = Some(value),
+            // --rpc-url like cast
+            "--rpc" | "--rpc-url" => options.rpc = Some(value),
+            "--private-key" => options.private_key = Some(value),
+            "--from" => options.from = Some(value),
+            "--nonce" => options.nonce = Some(value.parse().with_context(number)?),
+            "--chain-id" => options.chain_id = Some(value.parse().with_context(number)?),
+            "--gas-limit" => options.gas_limit = Some(value.parse().with_context(number)?),
+            "--max-fee-per-gas" => {
+                options.max_fee_per_gas = Some(value.parse().with_context(number)?)
+            }
+            "--max-priority-fee-per-gas" => {
+                options.max_priority_fee_per_gas = Some(value.parse().with_context(number)?)
+            }
+            _ => bail!("unknown option {flag}"),
+        }
+    }
+    Ok(options)
+}
+
+/// Submits a verified `beacon` to the registry of `chain`, or prints the transaction.
+pub fn run(chain: &ChainInfo, beacon: &Beacon, args: &[String]) -> anyhow::Result<()> {
+    let options = parse_options(args)?;
+    let registry = match &options.registry {
+        Some(registry) => registry_address(registry)?,
+        None => {
+            let mainnet = match chain.scheme {
+                DrandScheme::UnchainedG1Rfc9380 => ChainInfo::quicknet(),
+                DrandScheme::Bn254UnchainedOnG1 => ChainInfo::evmnet(),
+            };
+            if chain.public_key != mainnet.public_key {
+                bail!(
+                    "{} only verifies the mainnet public key, set --registry to a registry \
+                     generated for this chain",
+                    chain.scheme.registry()
+                );
+            }
+            chain.scheme.registry_address()
+        }
+    };
+    let calldata = beacon.prove_round_calldata();
+    let private_key = options
+        .private_key
+        .clone()
+        .or_else(|| std::env::var("PRIVATE_KEY").ok());
+
+    let Some(url) = &options.rpc else {
+        let Some(private_key) = private_key else {
+            println!("to: {}", rpc::hex_data(&registry));
+            println!("data: {}", rpc::hex_data(&calldata));
+            return Ok(());
+        };
+        let max_fee_per_gas = required(options.max_fee_per_gas, "--max-fee-per-gas")?;
+        let tx = Transaction {
+            chain_id: required(options.chain_id, "--chain-id")?,
+            nonce: required(options.nonce, "--nonce")?,
+            max_priority_fee_per_gas: options.max_priority_fee_per_gas.unwrap_or(max_fee_per_gas),
+            max_fee_per_gas,
+            gas_limit: required(options.gas_limit, "--gas-limit")?,
+            to: registry,
+            data: calldata,
+        };
+        let raw = Wallet::from_hex(&private_key)?.sign(&tx)?;
+        println!("raw: {}", rpc::hex_data(&raw));
+        println!("hash: {}", rpc::hex_data(&tx::hash(&raw)));
+        return Ok(());
+    };
+    let client = Client::new(url.as_str());
+
+    // a transaction to an account without code succeeds without proving anything
+    if client.code(&registry)?.is_empty() {
+        bail!(
+            "no contract at {}, deploy {} first",
+            rpc::hex_data(&registry),
+            chain.scheme.registry()
+        );
+    }
+    let public_key = client.eth_call(&registry, &abi::encode_call("PUBLIC_KEY()", &[]))?;
+    if public_key != registry_public_key(chain)? {
+        bail!(
+            "the registry at {} verifies another public key than the one of the chain",
+            rpc::hex_data(&registry)
+        );
+    }
+    // proveRound can be called again, but that only costs gas
+    if round_randomness(&client, &registry, beacon.round)? == beacon.expected_randomness() {
+        println!(
+            "round {} is already proven on {}",
+            beacon.round,
+            chain.scheme.registry()
+        );
+        return Ok(());
+    }
+
+    let hash = match private_key {
+        Some(private_key) => {
+            let wallet = Wallet::from_hex(&private_key)?;
+            let from = wallet.address();
+            let max_priority_fee_per_gas = match options.max_priority_fee_per_gas {
+                Some(fee) => fee,
+                None => client.max_priority_fee()?,
+            };
+            let max_fee_per_gas = match options.max_fee_per_gas {
+                Some(fee) => fee,
+                // leaves room for the base fee to double before inclusion
+                None => (2 * client.gas_price()?).max(max_priority_fee_per_gas),
+            };
+            let tx = Transaction {
+                chain_id: match options.chain_id {
+                    Some(chain_id) => chain_id,
+                    None => client.chain_id()?,
+                },
+                nonce: match options.nonce {
+                    Some(nonce) => nonce,
+                    None => client.nonce(&from)?,
+                },
+                max_priority_fee_per_gas,
+                max_fee_per_gas,
+                gas_limit: match options.gas_limit {
+                    Some(gas_limit) => gas_limit,
+                    None => client.estimate_gas(&from, &registry, &calldata)? * 6 / 5,
+                },
+                to: registry,
+                data: calldata,
+            };
+            client.send_raw_transaction(&wallet.sign(&tx)?)?
+        }
+        None => {
+            let from = match &options.from {
+                Some(from) => rpc::address(from)?,
+                None => *client
+                    .accounts()?
+                    .first()
+                    .context("the node has no unlocked account, set --private-key")?,
+            };
+            client.send_transaction(&from, &registry, &calldata)?
+        }
+    };
+    println!("sent {hash}");
+
+    for _ in 0..RECEIPT_POLLS {
+        if let Some(receipt) = client.receipt(&hash)? {
+            if receipt["status"] != "0x1" {
+                bail!("{hash} reverted");
+            }
+            if round_randomness(&client, &registry, beacon.round)? != beacon.expected_randomness() {
+                bail!(
+                    "{hash} succeeded but roundRandomness({}) is not the randomness of the beacon",
+                    beacon.round
+                );
+            }
+            let block: u64 = rpc::quantity(&receipt["blockNumber"])?;
+            let gas_used: u64 = rpc::quantity(&receipt["gasUsed"])?;
+            println!(
+                "round {} proven in block {block}, {gas_used} gas",
+                beacon.round
+            );
+            return Ok(());
+        }
+        thread::sleep(RECEIPT_POLL_INTERVAL);
+    }
+    bail!("{hash} not mined after {RECEIPT_POLLS} polls")
+}
+
+/// An option of the transaction, which the node fills in when there is one.
+fn required<T>(value: Option<T>, flag: &str) -> anyhow::Result<T> {
+    value.with_context(|| format!("{flag} is required to sign without --rpc"))
+}
+
+/// `PUBLIC_KEY()` of a registry verifying the beacons of `chain`.
+fn registry_public_key(chain: &ChainInfo) -> anyhow::Result<Vec<u8>> {
+    let (curve, format) = match chain.scheme {
+        DrandScheme::UnchainedG1Rfc9380 => (Curve::Bls12381, Format::Compressed),
+        DrandScheme::Bn254UnchainedOnG1 => (Curve::Bn254, Format::G2Marshal),
+    };
+    Ok(pubkey::convert(
+        curve,
+        format,
+        Format::Solidity,
+        &chain.public_key,
+    )?)
+}
+
+/// `roundRandomness(round)` of the registry, zero until the round is proven.
+fn round_randomness(client: &Client, registry: &Address, round: u64) -> anyhow::Result<Vec<u8>> {
+    client.eth_call(
+        registry,
+        &abi::encode_call("roundRandomness(uint64)", &[Token::Uint(abi::uint(round))]),
+    )
+}
+
+/// An address, or the registry deployed by a forge broadcast, e.g.
+/// `broadcast/DeployQuicknetRegistry.s.sol/31337/run-latest.json`.
+fn registry_address(registry: &str) -> anyhow::Result<Address> {
+    if registry.ends_with(".json") {
+        let broadcast: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(registry)
+                .with_context(|| format!("cannot read {registry}"))?,
+        )?;
+        let address = broadcast["transactions"][0]["contractAddress"]
+            .as_str()
+            .with_context(|| format!("no contract address in {registry}"))?;
+        rpc::address(address)
+    } else {
+        rpc::address(registry)
+    }
+}
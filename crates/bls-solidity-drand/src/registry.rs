@@ -0,0 +1,239 @@
+//! Generates the registry contract of a chain and its deploy script, like `QuicknetRegistry` and
+//! `EvmnetRegistry`.
+//!
+//! The output for the mainnet chain infos is byte for byte the checked in contracts: the sources
+//! are part of the metadata hash of the bytecode, so any difference would also move the CREATE2
+//! address of `DrandScheme::registry_address`.
+
+use bls_solidity_rs::drand::{ChainInfo, DrandScheme};
+use bls_solidity_rs::pubkey::{Format, PublicKey};
+use bls_solidity_rs::{Curve, sol};
+
+use anyhow::{Context, bail};
+
+use std::path::Path;
+
+/// Entry point for the `registry` command: <chain> [--name <Name>] [--out <dir>]
+pub fn run(chain: &ChainInfo, args: &[String]) -> anyhow::Result<()> {
+    let mut name = None;
+    let mut out = None;
+    let mut options = args.iter();
+    while let Some(option) = options.next() {
+        let Some(value) = options.next() else {
+            bail!("missing value for {option}");
+        };
+        match option.as_str() {
+            "--name" => name = Some(value.clone()),
+            "--out" => out = Some(value.clone()),
+            _ => bail!("unknown option {option}"),
+        }
+    }
+    let name = match name.or_else(|| chain.beacon_id.as_deref().map(capitalize)) {
+        Some(name) => name,
+        None => bail!("the chain info has no beacon id, set the contract name with --name"),
+    };
+    if !name.starts_with(|c: char| c.is_ascii_uppercase())
+        || !name.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        bail!("{name} is not a contract name prefix, e.g. Quicknet");
+    }
+
+    let registry = Registry::new(chain, &name)?;
+    let files = [
+        (registry.contract_path(), registry.contract()),
+        (registry.script_path(), registry.script()),
+    ];
+    match out {
+        Some(out) => {
+            for (path, source) in files {
+                let path = Path::new(&out).join(path);
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(&path, source)
+                    .with_context(|| format!("cannot write {}", path.display()))?;
+                println!("{}", path.display());
+            }
+        }
+        None => {
+            for (path, source) in files {
+                println!("// {path}\n{source}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The Solidity of a registry verifying the beacons of a chain.
+pub struct Registry {
+    /// `<name>Registry`
+    pub contract: String,
+    pub scheme: DrandScheme,
+    /// `PUBLIC_KEY()` arguments, one literal per line
+    public_key: Vec<String>,
+}
+
+impl Registry {
+    pub fn new(chain: &ChainInfo, name: &str) -> anyhow::Result<Self> {
+        let public_key = match chain.scheme {
+            DrandScheme::UnchainedG1Rfc9380 => {
+                let PublicKey::Bls12381(pk) =
+                    PublicKey::decode(Curve::Bls12381, Format::Compressed, &chain.public_key)?
+                else {
+                    unreachable!()
+                };
+                let p = sol::bls2::PointG2::from(pk.0);
+                [p.x1_hi, p.x0_hi, p.y1_hi, p.y0_hi]
+                    .iter()
+                    .zip([p.x1_lo, p.x0_lo, p.y1_lo, p.y0_lo])
+                    .flat_map(|(hi, lo)| [format!("0x{hi:032x}"), format!("0x{}", hex::encode(lo))])
+                    .collect()
+            }
+            DrandScheme::Bn254UnchainedOnG1 => {
+                let PublicKey::Bn254(pk) =
+                    PublicKey::decode(Curve::Bn254, Format::G2Marshal, &chain.public_key)?
+                else {
+                    unreachable!()
+                };
+                let p = sol::bls::PointG2::from(pk.0);
+                [p.x, p.y].iter().flatten().map(uint256_literal).collect()
+            }
+        };
+        Ok(Self {
+            contract: format!("{name}Registry"),
+            scheme: chain.scheme,
+            public_key,
+        })
+    }
+
+    pub fn contract_path(&self) -> String {
+        format!("src/demos/{}.sol", self.contract)
+    }
+
+    pub fn script_path(&self) -> String {
+        format!("script/Deploy{}.s.sol", self.contract)
+    }
+
+    /// The registry, formatted like `forge fmt`.
+    pub fn contract(&self) -> String {
+        let (library, path) = self.library();
+        let (hash, unmarshal) = match self.scheme {
+            DrandScheme::UnchainedG1Rfc9380 => ("sha256", "g1UnmarshalCompressed"),
+            DrandScheme::Bn254UnchainedOnG1 => ("keccak256", "g1Unmarshal"),
+        };
+        let public_key = match self.scheme {
+            DrandScheme::UnchainedG1Rfc9380 => {
+                format!(
+                    "\n            {}\n        ",
+                    self.public_key.join(",\n            ")
+                )
+            }
+            DrandScheme::Bn254UnchainedOnG1 => {
+                let [x0, x1, y0, y1] = &self.public_key[..] else {
+                    unreachable!()
+                };
+                format!(
+                    "
+            [
+                {x0},
+                {x1}
+            ],
+            [
+                {y0},
+                {y1}
+            ]
+        "
+                )
+            }
+        };
+        format!(
+            r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8;
+
+import {{{library}}} from "../libraries/{path}";
+
+contract {contract} {{
+    mapping(uint64 => bytes32) public roundRandomness;
+
+    string public constant DST = "{dst}";
+
+    function PUBLIC_KEY() public pure returns ({library}.PointG2 memory) {{
+        return {library}.PointG2({public_key});
+    }}
+
+    event RoundProven(uint64 indexed roundNumber, bytes signature);
+
+    function proveRound(bytes memory signature, uint64 roundNumber) external {{
+        (bool callSuccess, bool pairingSuccess) = {library}.verifySingle(
+            {library}.{unmarshal}(signature),
+            PUBLIC_KEY(),
+            {library}.hashToPoint(bytes(DST), abi.encodePacked({hash}(abi.encodePacked(roundNumber))))
+        );
+        require(callSuccess && pairingSuccess, "Invalid signature");
+
+        roundRandomness[roundNumber] = sha256(signature);
+
+        emit RoundProven(roundNumber, signature);
+    }}
+}}
+"#,
+            contract = self.contract,
+            dst = self.scheme.dst(),
+        )
+    }
+
+    /// The deploy script, run with `forge script`.
+    pub fn script(&self) -> String {
+        let demo = self.contract.trim_end_matches("Registry");
+        format!(
+            r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8;
+
+import {{Script}} from "forge-std-1.10.0/src/Script.sol";
+
+import {{{contract}}} from "{path}";
+
+contract {demo}Demo is Script {{
+    function run() public {{
+        vm.broadcast();
+        // Deploy with CREATE2 deterministic address
+        new {contract}{{salt: ""}}();
+    }}
+}}
+"#,
+            contract = self.contract,
+            path = self.contract_path(),
+        )
+    }
+
+    fn library(&self) -> (&'static str, &'static str) {
+        match self.scheme {
+            DrandScheme::UnchainedG1Rfc9380 => ("BLS2", "BLS2.sol"),
+            DrandScheme::Bn254UnchainedOnG1 => ("BLS", "BLS.sol"),
+        }
+    }
+}
+
+/// forge fmt keeps `uint256` literals without leading zeros, `0x0` for zero.
+fn uint256_literal(word: &[u8; 32]) -> String {
+    let hex = hex::encode(word);
+    match hex.trim_start_matches('0') {
+        "" => "0x0".to_owned(),
+        digits => format!("0x{digits}"),
+    }
+}
+
+/// `quicknet` -> `Quicknet`, `my-testnet` -> `MyTestnet`.
+fn capitalize(beacon_id: &str) -> String {
+    beacon_id
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
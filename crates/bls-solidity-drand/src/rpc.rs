@@ -0,0 +1,134 @@
+//! Ethereum JSON-RPC calls used to submit `proveRound`.
+
+use crate::http;
+
+use bls_solidity_rs::abi::Address;
+
+use anyhow::{Context, anyhow, bail};
+use serde_json::{Value, json};
+
+pub struct Client {
+    url: String,
+}
+
+impl Client {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    pub fn call(&self, method: &str, params: Value) -> anyhow::Result<Value> {
+        let request = json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params});
+        let response: Value =
+            serde_json::from_slice(&http::post_json(&self.url, request.to_string().as_bytes())?)
+                .with_context(|| format!("{method}: invalid JSON response"))?;
+        if let Some(error) = response.get("error") {
+            bail!("{method}: {error}");
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{method}: no result"))
+    }
+
+    pub fn chain_id(&self) -> anyhow::Result<u64> {
+        quantity(&self.call("eth_chainId", json!([]))?)
+    }
+
+    /// Unlocked accounts of the node, e.g. the anvil dev accounts.
+    pub fn accounts(&self) -> anyhow::Result<Vec<Address>> {
+        let accounts = self.call("eth_accounts", json!([]))?;
+        accounts
+            .as_array()
+            .context("eth_accounts: expected an array")?
+            .iter()
+            .map(|a| address(a.as_str().unwrap_or_default()))
+            .collect()
+    }
+
+    pub fn nonce(&self, account: &Address) -> anyhow::Result<u64> {
+        quantity(&self.call(
+            "eth_getTransactionCount",
+            json!([hex_data(account), "pending"]),
+        )?)
+    }
+
+    pub fn gas_price(&self) -> anyhow::Result<u128> {
+        quantity(&self.call("eth_gasPrice", json!([]))?)
+    }
+
+    pub fn max_priority_fee(&self) -> anyhow::Result<u128> {
+        quantity(&self.call("eth_maxPriorityFeePerGas", json!([]))?)
+    }
+
+    /// The code deployed at `account`, empty for an account without a contract.
+    pub fn code(&self, account: &Address) -> anyhow::Result<Vec<u8>> {
+        data_bytes(&self.call("eth_getCode", json!([hex_data(account), "latest"]))?)
+    }
+
+    /// `eth_call` at the latest block.
+    pub fn eth_call(&self, to: &Address, data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let result = self.call(
+            "eth_call",
+            json!([{"to": hex_data(to), "data": hex_data(data)}, "latest"]),
+        )?;
+        data_bytes(&result)
+    }
+
+    pub fn estimate_gas(&self, from: &Address, to: &Address, data: &[u8]) -> anyhow::Result<u64> {
+        quantity(&self.call(
+            "eth_estimateGas",
+            json!([{"from": hex_data(from), "to": hex_data(to), "data": hex_data(data)}]),
+        )?)
+    }
+
+    /// Sends a transaction signed by the node, returning its hash.
+    pub fn send_transaction(
+        &self,
+        from: &Address,
+        to: &Address,
+        data: &[u8],
+    ) -> anyhow::Result<String> {
+        let hash = self.call(
+            "eth_sendTransaction",
+            json!([{"from": hex_data(from), "to": hex_data(to), "data": hex_data(data)}]),
+        )?;
+        Ok(hash.as_str().context("expected a hash")?.to_owned())
+    }
+
+    /// Sends a signed transaction, returning its hash.
+    pub fn send_raw_transaction(&self, raw: &[u8]) -> anyhow::Result<String> {
+        let hash = self.call("eth_sendRawTransaction", json!([hex_data(raw)]))?;
+        Ok(hash.as_str().context("expected a hash")?.to_owned())
+    }
+
+    /// The receipt of a mined transaction, `None` while it is pending.
+    pub fn receipt(&self, hash: &str) -> anyhow::Result<Option<Value>> {
+        let receipt = self.call("eth_getTransactionReceipt", json!([hash]))?;
+        Ok((!receipt.is_null()).then_some(receipt))
+    }
+}
+
+pub fn hex_data(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(data))
+}
+
+pub fn address(s: &str) -> anyhow::Result<Address> {
+    let bytes =
+        hex::decode(s.trim_start_matches("0x")).with_context(|| format!("invalid address {s}"))?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow!("invalid address {s}: expected 20 bytes"))
+}
+
+/// Parses a hex encoded quantity, e.g. `0x1a`.
+pub fn quantity<T: TryFrom<u128>>(value: &Value) -> anyhow::Result<T> {
+    let s = value.as_str().context("expected a hex quantity")?;
+    let n = u128::from_str_radix(s.trim_start_matches("0x"), 16)
+        .with_context(|| format!("invalid quantity {s}"))?;
+    T::try_from(n).map_err(|_| anyhow!("quantity {s} out of range"))
+}
+
+fn data_bytes(value: &Value) -> anyhow::Result<Vec<u8>> {
+    let s = value.as_str().context("expected hex data")?;
+    hex::decode(s.trim_start_matches("0x")).with_context(|| format!("invalid data {s}"))
+}
@@ -0,0 +1,57 @@
+//! Routes of `beacon-server`, a local stand-in for the drand HTTP API serving the beacons of test
+//! chains.
+
+use bls_solidity_rs::drand::TestChain;
+
+/// The status and body of `GET path` at unix time `now`.
+pub fn route(path: &str, chains: &[TestChain], now: u64) -> (u16, String) {
+    let segments: Vec<&str> = path
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    let (chain, rest) = match segments[..] {
+        ["v2", "beacons"] => {
+            let ids: Vec<_> = chains.iter().map(|c| &c.info.beacon_id).collect();
+            return (200, serde_json::to_string(&ids).expect("ids serialize"));
+        }
+        ["v2", "beacons", id, ref rest @ ..] => (
+            chains
+                .iter()
+                .find(|c| c.info.beacon_id.as_deref() == Some(id)),
+            rest,
+        ),
+        ["v2", "chains", hash, ref rest @ ..] => (
+            chains
+                .iter()
+                .find(|c| hex::encode(&c.info.chain_hash) == hash),
+            rest,
+        ),
+        _ => (None, &[][..]),
+    };
+    let Some(chain) = chain else {
+        return (404, "not found".to_owned());
+    };
+
+    let latest = chain.info.round_at(now);
+    let round = match rest {
+        ["info"] => return (200, chain.info.to_json()),
+        ["rounds", "latest"] => latest,
+        ["rounds", round] => match round.parse::<u64>() {
+            Ok(round) if round > 0 => round,
+            _ => return (400, format!("invalid round {round}")),
+        },
+        _ => return (404, "not found".to_owned()),
+    };
+    // like drand, rounds are only served once emitted
+    if round == 0 || round > latest {
+        let body = match chain.info.time_of_round(round) {
+            Some(time) => format!("round {round} is emitted at {time}"),
+            None => format!("round {round} is emitted past the range of u64"),
+        };
+        return (425, body);
+    }
+    (200, chain.beacon(round).to_json())
+}
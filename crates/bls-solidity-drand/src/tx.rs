@@ -0,0 +1,77 @@
+//! EIP-1559 transactions signed with a local secp256k1 key, encoded by alloy-consensus.
+
+use bls_solidity_rs::abi::Address;
+
+use alloy_consensus::{SignableTransaction, TxEip1559, TxEnvelope};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Bytes, Signature, TxKind, U256};
+use anyhow::Context;
+use k256::ecdsa::SigningKey;
+use sha3::{Digest, Keccak256};
+
+pub struct Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: u128,
+    pub max_fee_per_gas: u128,
+    pub gas_limit: u64,
+    pub to: Address,
+    pub data: Vec<u8>,
+}
+
+pub struct Wallet {
+    key: SigningKey,
+}
+
+impl Wallet {
+    pub fn from_hex(private_key: &str) -> anyhow::Result<Self> {
+        let bytes = hex::decode(private_key.trim().trim_start_matches("0x"))
+            .context("private key is not hex encoded")?;
+        let key = SigningKey::from_slice(&bytes).context("invalid private key")?;
+        Ok(Self { key })
+    }
+
+    pub fn address(&self) -> Address {
+        let point = self.key.verifying_key().to_encoded_point(false);
+        // keccak256 of the uncompressed point without its 0x04 prefix
+        Keccak256::digest(&point.as_bytes()[1..])[12..]
+            .try_into()
+            .unwrap()
+    }
+
+    /// Signs `tx`, returning the raw transaction for `eth_sendRawTransaction`.
+    pub fn sign(&self, tx: &Transaction) -> anyhow::Result<Vec<u8>> {
+        let tx = tx.eip1559();
+        let (signature, recovery_id) = self
+            .key
+            .sign_prehash_recoverable(tx.signature_hash().as_slice())?;
+        let (r, s) = signature.split_bytes();
+        let signature = Signature::new(
+            U256::from_be_slice(&r),
+            U256::from_be_slice(&s),
+            recovery_id.is_y_odd(),
+        );
+        Ok(TxEnvelope::from(tx.into_signed(signature)).encoded_2718())
+    }
+}
+
+/// The hash of a raw transaction, as returned by `eth_sendRawTransaction`.
+pub fn hash(raw: &[u8]) -> [u8; 32] {
+    Keccak256::digest(raw).into()
+}
+
+impl Transaction {
+    fn eip1559(&self) -> TxEip1559 {
+        TxEip1559 {
+            chain_id: self.chain_id,
+            nonce: self.nonce,
+            gas_limit: self.gas_limit,
+            max_fee_per_gas: self.max_fee_per_gas,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+            to: TxKind::Call(self.to.into()),
+            value: U256::ZERO,
+            access_list: Default::default(),
+            input: Bytes::copy_from_slice(&self.data),
+        }
+    }
+}
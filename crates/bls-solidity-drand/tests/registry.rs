@@ -0,0 +1,44 @@
+//! The registries generated from the pinned mainnet chain infos are the checked in contracts and
+//! deploy scripts, byte for byte, so that their CREATE2 addresses do not move.
+
+use bls_solidity_drand::registry::Registry;
+use bls_solidity_rs::drand::ChainInfo;
+
+const QUICKNET_REGISTRY: &str = include_str!("../../../src/demos/QuicknetRegistry.sol");
+const DEPLOY_QUICKNET_REGISTRY: &str = include_str!("../../../script/DeployQuicknetRegistry.s.sol");
+const EVMNET_REGISTRY: &str = include_str!("../../../src/demos/EvmnetRegistry.sol");
+const DEPLOY_EVMNET_REGISTRY: &str = include_str!("../../../script/DeployEvmnetRegistry.s.sol");
+
+fn check(chain: &ChainInfo, name: &str, contract: &str, script: &str) {
+    let registry = Registry::new(chain, name).unwrap();
+    assert_eq!(
+        registry.contract_path(),
+        format!("src/demos/{name}Registry.sol")
+    );
+    assert_eq!(
+        registry.script_path(),
+        format!("script/Deploy{name}Registry.s.sol")
+    );
+    assert_eq!(registry.contract(), contract);
+    assert_eq!(registry.script(), script);
+}
+
+#[test]
+fn quicknet_registry() {
+    check(
+        &ChainInfo::quicknet(),
+        "Quicknet",
+        QUICKNET_REGISTRY,
+        DEPLOY_QUICKNET_REGISTRY,
+    );
+}
+
+#[test]
+fn evmnet_registry() {
+    check(
+        &ChainInfo::evmnet(),
+        "Evmnet",
+        EVMNET_REGISTRY,
+        DEPLOY_EVMNET_REGISTRY,
+    );
+}
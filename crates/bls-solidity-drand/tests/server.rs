@@ -0,0 +1,114 @@
+//! The routes of beacon-server serve the chain info and the emitted beacons of its test chains,
+//! with the statuses of the drand HTTP API otherwise.
+
+use bls_solidity_drand::server::route;
+use bls_solidity_rs::drand::{Beacon, ChainInfo, DrandScheme, TestChain};
+
+const GENESIS: u64 = 1_700_000_000;
+const PERIOD: u64 = 3;
+/// Round 11 is the latest.
+const NOW: u64 = GENESIS + 10 * PERIOD + 1;
+
+fn chains() -> Vec<TestChain> {
+    [
+        (DrandScheme::UnchainedG1Rfc9380, "quicknet"),
+        (DrandScheme::Bn254UnchainedOnG1, "evmnet"),
+    ]
+    .into_iter()
+    .map(|(scheme, id)| TestChain::new(scheme, b"seed", PERIOD, GENESIS, Some(id.to_owned())))
+    .collect()
+}
+
+/// The routes of `chain` under its beacon id and under its chain hash.
+fn prefixes(chain: &TestChain) -> [String; 2] {
+    [
+        format!("/v2/beacons/{}", chain.info.beacon_id.as_deref().unwrap()),
+        format!("/v2/chains/{}", hex::encode(&chain.info.chain_hash)),
+    ]
+}
+
+#[test]
+fn info() {
+    let chains = chains();
+    for chain in &chains {
+        for prefix in prefixes(chain) {
+            let (status, body) = route(&format!("{prefix}/info"), &chains, NOW);
+            assert_eq!(status, 200);
+            assert_eq!(body, chain.info.to_json());
+            assert_eq!(ChainInfo::from_json(&body).unwrap(), chain.info);
+        }
+    }
+    assert_eq!(
+        route("/v2/beacons", &chains, NOW),
+        (200, r#"["quicknet","evmnet"]"#.to_owned())
+    );
+}
+
+#[test]
+fn emitted_rounds() {
+    let chains = chains();
+    for chain in &chains {
+        for prefix in prefixes(chain) {
+            for (path, round) in [
+                (format!("{prefix}/rounds/latest"), 11),
+                (format!("{prefix}/rounds/latest?cache=no"), 11),
+                (format!("{prefix}/rounds/11"), 11),
+                (format!("{prefix}/rounds/1"), 1),
+            ] {
+                let (status, body) = route(&path, &chains, NOW);
+                assert_eq!(status, 200, "{path}");
+                let beacon = Beacon::from_json(&body).unwrap();
+                assert_eq!(beacon.round, round);
+                assert_eq!(chain.info.verify(&beacon), Ok(()));
+            }
+        }
+    }
+}
+
+#[test]
+fn errors() {
+    let chains = chains();
+    let status = |path: &str, now| route(path, &chains, now).0;
+    assert_eq!(status("/v2/beacons/quicknet/rounds/12", NOW), 425);
+    assert_eq!(
+        route("/v2/beacons/evmnet/rounds/12", &chains, NOW).1,
+        format!("round 12 is emitted at {}", GENESIS + 11 * PERIOD)
+    );
+    // the time of the round is past the range of u64
+    assert_eq!(
+        route(
+            "/v2/beacons/quicknet/rounds/18446744073709551615",
+            &chains,
+            NOW
+        ),
+        (
+            425,
+            "round 18446744073709551615 is emitted past the range of u64".to_owned()
+        )
+    );
+    // before the genesis, even the latest round is not emitted yet
+    assert_eq!(
+        status("/v2/beacons/quicknet/rounds/latest", GENESIS - 1),
+        425
+    );
+
+    for round in ["0", "-1", "latest1", "0x1", "18446744073709551616"] {
+        assert_eq!(
+            status(&format!("/v2/beacons/quicknet/rounds/{round}"), NOW),
+            400,
+            "{round}"
+        );
+    }
+
+    for path in [
+        "/v2/beacons/default/info",
+        "/v2/chains/00/rounds/latest",
+        "/v2/beacons/quicknet",
+        "/v2/beacons/quicknet/rounds",
+        "/v2/beacons/quicknet/rounds/1/2",
+        "/v1/info",
+        "/",
+    ] {
+        assert_eq!(status(path, NOW), 404, "{path}");
+    }
+}
@@ -0,0 +1,87 @@
+//! EIP-1559 signing of `proveRound` transactions: a raw transaction vector, and transactions
+//! decoded back with their signer recovered.
+
+use bls_solidity_drand::tx::{self, Transaction, Wallet};
+use bls_solidity_rs::drand::DrandScheme;
+
+use alloy_consensus::transaction::SignerRecoverable;
+use alloy_consensus::{Transaction as _, TxEnvelope};
+use alloy_eips::eip2718::Decodable2718;
+
+/// The first anvil dev account.
+const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const ADDRESS: &str = "f39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+
+#[test]
+fn wallet_address() {
+    let wallet = Wallet::from_hex(PRIVATE_KEY).unwrap();
+    assert_eq!(hex::encode(wallet.address()), ADDRESS);
+    assert!(Wallet::from_hex("0x00").is_err());
+}
+
+#[test]
+fn eip1559_vector() {
+    let tx = Transaction {
+        chain_id: 31337,
+        nonce: 7,
+        max_priority_fee_per_gas: 1_000_000_000,
+        max_fee_per_gas: 2_000_000_000,
+        gas_limit: 300_000,
+        to: hex::decode("a50970f475f530fa9a93e0c55ebafef588b07ce3")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+        data: vec![0xc0, 0xff, 0xee],
+    };
+    let raw = Wallet::from_hex(PRIVATE_KEY).unwrap().sign(&tx).unwrap();
+    assert_eq!(
+        hex::encode(&raw),
+        "02f870827a6907843b9aca008477359400830493e094a50970f475f530fa9a93e0c55ebafef588b07ce3\
+         8083c0ffeec001a0c4025c238387ee331b570f5a9d4ca258d8baca25a2bed818cd9d5dc8d0e1edf9a070\
+         48b0166a064458633bca4f0031b7227544b1073ce036258708f7bdfd1abf5d"
+    );
+    assert_eq!(
+        hex::encode(tx::hash(&raw)),
+        "353f662057917aa2d90584fe2705d12898001a74fb153613a83283bbd8aa82b1"
+    );
+}
+
+#[test]
+fn signed_transactions_decode() {
+    let wallet = Wallet::from_hex(PRIVATE_KEY).unwrap();
+    // short and long calldata, zero and single byte quantities, and fees above u64
+    for (nonce, data_length, max_fee_per_gas) in [
+        (0, 0, 1),
+        (1, 55, 0x7f),
+        (0x80, 56, 1 << 64),
+        (u64::MAX, 1024, u128::MAX),
+    ] {
+        let tx = Transaction {
+            chain_id: 1,
+            nonce,
+            max_priority_fee_per_gas: max_fee_per_gas / 2,
+            max_fee_per_gas,
+            gas_limit: 30_000_000,
+            to: DrandScheme::Bn254UnchainedOnG1.registry_address(),
+            data: vec![0xaa; data_length],
+        };
+        let raw = wallet.sign(&tx).unwrap();
+        let decoded = TxEnvelope::decode_2718(&mut raw.as_slice()).unwrap();
+        let TxEnvelope::Eip1559(signed) = &decoded else {
+            panic!("not an EIP-1559 transaction");
+        };
+        assert_eq!(signed.hash().0, tx::hash(&raw));
+        assert_eq!(decoded.recover_signer().unwrap().0.0, wallet.address());
+        assert_eq!(decoded.chain_id(), Some(tx.chain_id));
+        assert_eq!(decoded.nonce(), tx.nonce);
+        assert_eq!(decoded.max_fee_per_gas(), tx.max_fee_per_gas);
+        assert_eq!(
+            decoded.max_priority_fee_per_gas(),
+            Some(tx.max_priority_fee_per_gas)
+        );
+        assert_eq!(decoded.gas_limit(), tx.gas_limit);
+        assert_eq!(decoded.to().unwrap().0.0, tx.to);
+        assert!(decoded.value().is_zero());
+        assert_eq!(decoded.input().as_ref(), tx.data);
+    }
+}
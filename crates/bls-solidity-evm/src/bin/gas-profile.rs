@@ -0,0 +1,373 @@
+//! Profiles the gas used by the BLS and BLS2 libraries and the signature scheme contracts, and
+//! writes it as `BLS.json` and `BLS2.json` in the shape of the forge gas snapshots in `snapshots/`.
+//!
+//! Besides the snapshot entries (`verify_uncompressed`, `verify_compressed`), each step is
+//! measured on its own and `hashToPoint` across message and DST lengths. The gas used by the
+//! precompiles called during a step is reported as `<step>/<precompile>`, so that a regression
+//! can be traced to a step and to the precompile calls it makes.
+
+use bls_solidity_evm::{Artifacts, LocalEvm, Receipt};
+use bls_solidity_rs::abi::{self, Address, Token};
+use bls_solidity_rs::dst::Dst;
+use bls_solidity_rs::{Scheme, bls12_381, bn254};
+
+use anyhow::{Context, ensure};
+use serde::Deserialize;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+const MESSAGE_LENGTHS: [usize; 4] = [0, 32, 128, 1024];
+// 43 is the length of the drand DSTs, 255 the maximum of expand_message_xmd
+const DST_LENGTHS: [usize; 4] = [1, 43, 128, 255];
+
+// the dst and message of the rows of testcases.json used by test_snapshot_verify_* of BLSTest and
+// BLS2Test, which pick them by index
+const BLS_SNAPSHOT_TESTCASE: (&str, &str) = (
+    "BLS_SIG_BN254G1_XMD:KECCAK-256_SVDW_RO_NUL_",
+    "baf09720c37cb921fd8362b1d907232ac0b813ffba768c714aeaace987e7fd6b",
+);
+const BLS2_SNAPSHOT_TESTCASE: (&str, &str) = (
+    "BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_",
+    "b33ffdb664cbfb43d83dc13179ce992b7272e01041df2f4c1ae7b41b4ef000ff",
+);
+
+const APPLICATION: &str = "gas-profile";
+
+#[derive(Deserialize)]
+struct TestCase {
+    dst: String,
+    message: String,
+    pk: String,
+    sig: String,
+    sig_compressed: String,
+}
+
+/// The only test case with `dst` and `message`.
+fn snapshot_testcase<'a>(
+    testcases: &'a [TestCase],
+    (dst, message): (&str, &str),
+) -> anyhow::Result<&'a TestCase> {
+    let mut matching = testcases
+        .iter()
+        .filter(|tc| tc.dst == dst && tc.message == message);
+    let tc = matching
+        .next()
+        .with_context(|| format!("no test case signs {message} with {dst}"))?;
+    ensure!(
+        matching.next().is_none(),
+        "several test cases sign {message} with {dst}"
+    );
+    Ok(tc)
+}
+
+/// Entries of a snapshot file, e.g. `snapshots/BLS.json`.
+#[derive(Default)]
+struct Snapshot(BTreeMap<String, String>);
+
+impl Snapshot {
+    /// Records the gas of a step, and of the precompiles called in `receipt`.
+    fn record(&mut self, name: &str, gas: u64, receipt: &Receipt) {
+        println!("{name}: {gas}");
+        self.0.insert(name.to_owned(), gas.to_string());
+
+        let mut precompiles: BTreeMap<&str, (usize, u64)> = BTreeMap::new();
+        for call in &receipt.precompile_calls {
+            let (calls, gas) = precompiles.entry(call.name).or_default();
+            *calls += 1;
+            *gas += call.gas_used;
+        }
+        for (precompile, (calls, gas)) in precompiles {
+            println!("  {precompile}: {gas} in {calls} calls");
+            self.0
+                .insert(format!("{name}/{precompile}"), gas.to_string());
+        }
+    }
+
+    fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let mut json = serde_json::to_string_pretty(&self.0)?;
+        json.push('\n');
+        std::fs::write(path, json).with_context(|| format!("cannot write {}", path.display()))
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 4 {
+        eprintln!("Usage: gas-profile <forge out dir> <testcases.json> <snapshot dir>");
+        std::process::exit(2);
+    }
+    let artifacts = Artifacts::new(&args[1]);
+    let testcases: Vec<TestCase> = serde_json::from_reader(BufReader::new(
+        File::open(&args[2]).with_context(|| format!("cannot open {}", args[2]))?,
+    ))?;
+    let dir = Path::new(&args[3]);
+    std::fs::create_dir_all(dir)?;
+
+    let mut evm = LocalEvm::new();
+
+    println!("# BLS");
+    let mut snapshot = Snapshot::default();
+    let harness = evm.deploy(&artifacts.bytecode("BLSGasHarness")?, &[])?.0;
+    let tc = snapshot_testcase(&testcases, BLS_SNAPSHOT_TESTCASE)?;
+    verify(
+        &mut evm,
+        harness,
+        "verifyUncompressed",
+        "verify_uncompressed",
+        tc,
+        &tc.sig,
+        &mut snapshot,
+    )?;
+    profile_steps(&mut evm, harness, tc, &mut snapshot)?;
+    profile_hash_to_point(&mut evm, harness, &mut snapshot)?;
+    let sk = bn254::SecretKey::from_be_bytes_mod_order(APPLICATION.as_bytes());
+    let dst = Dst::new(Scheme::Bn254, APPLICATION, bls_solidity_evm::CHAIN_ID).to_bytes();
+    let sig = sk.sign(&dst, APPLICATION.as_bytes()).to_bytes();
+    let pk = sk.public_key().to_bytes();
+    profile_scheme(
+        &mut evm,
+        &artifacts,
+        Scheme::Bn254,
+        &pk,
+        &sig,
+        &mut snapshot,
+    )?;
+    snapshot.write(&dir.join("BLS.json"))?;
+
+    println!("# BLS2");
+    let mut snapshot = Snapshot::default();
+    let harness = evm.deploy(&artifacts.bytecode("BLS2GasHarness")?, &[])?.0;
+    let tc = snapshot_testcase(&testcases, BLS2_SNAPSHOT_TESTCASE)?;
+    verify(
+        &mut evm,
+        harness,
+        "verifyCompressed",
+        "verify_compressed",
+        tc,
+        &tc.sig_compressed,
+        &mut snapshot,
+    )?;
+    verify(
+        &mut evm,
+        harness,
+        "verifyUncompressed",
+        "verify_uncompressed",
+        tc,
+        &tc.sig,
+        &mut snapshot,
+    )?;
+    profile_steps(&mut evm, harness, tc, &mut snapshot)?;
+    profile_unmarshal_compressed(&mut evm, harness, tc, &mut snapshot)?;
+    profile_hash_to_point(&mut evm, harness, &mut snapshot)?;
+    let sk = bls12_381::SecretKey::from_be_bytes_mod_order(APPLICATION.as_bytes());
+    let dst = Dst::new(Scheme::Bls12381, APPLICATION, bls_solidity_evm::CHAIN_ID).to_bytes();
+    let sig = sk.sign(&dst, APPLICATION.as_bytes());
+    let pk = sk.public_key().to_bytes();
+    profile_scheme(
+        &mut evm,
+        &artifacts,
+        Scheme::Bls12381,
+        &pk,
+        &sig.to_bytes(),
+        &mut snapshot,
+    )?;
+    profile_scheme(
+        &mut evm,
+        &artifacts,
+        Scheme::Bls12381Compressed,
+        &pk,
+        &sig.to_compressed_bytes(),
+        &mut snapshot,
+    )?;
+    snapshot.write(&dir.join("BLS2.json"))?;
+
+    Ok(())
+}
+
+/// The measurement of the snapshot tests: unmarshal the signature, hash and verify.
+fn verify(
+    evm: &mut LocalEvm,
+    harness: Address,
+    function: &str,
+    name: &str,
+    tc: &TestCase,
+    sig: &str,
+    snapshot: &mut Snapshot,
+) -> anyhow::Result<()> {
+    let (pk, sig, dst, message) = (
+        hex::decode(&tc.pk)?,
+        hex::decode(sig)?,
+        tc.dst.as_bytes(),
+        hex::decode(&tc.message)?,
+    );
+    let receipt = evm.call(
+        harness,
+        &abi::encode_call(
+            &format!("{function}(bytes,bytes,bytes,bytes)"),
+            &[
+                Token::Bytes(&pk),
+                Token::Bytes(&sig),
+                Token::Bytes(dst),
+                Token::Bytes(&message),
+            ],
+        ),
+    )?;
+    ensure!(word(&receipt.output, 0)? == 1, "{function} failed");
+    snapshot.record(name, word(&receipt.output, 1)?, &receipt);
+    Ok(())
+}
+
+/// Each step of the snapshot measurement on its own.
+fn profile_steps(
+    evm: &mut LocalEvm,
+    harness: Address,
+    tc: &TestCase,
+    snapshot: &mut Snapshot,
+) -> anyhow::Result<()> {
+    let (pk, sig, message) = (
+        hex::decode(&tc.pk)?,
+        hex::decode(&tc.sig)?,
+        hex::decode(&tc.message)?,
+    );
+
+    let receipt = evm.call(
+        harness,
+        &abi::encode_call("g1Unmarshal(bytes)", &[Token::Bytes(&sig)]),
+    )?;
+    snapshot.record("g1Unmarshal", word(&receipt.output, 1)?, &receipt);
+
+    let receipt = evm.call(
+        harness,
+        &abi::encode_call(
+            "hashToPoint(bytes,bytes)",
+            &[Token::Bytes(tc.dst.as_bytes()), Token::Bytes(&message)],
+        ),
+    )?;
+    snapshot.record("hashToPoint", word(&receipt.output, 1)?, &receipt);
+    let m = abi::decode_bytes(&receipt.output).context("hashToPoint")?;
+
+    let receipt = evm.call(
+        harness,
+        &abi::encode_call(
+            "verifySingle(bytes,bytes,bytes)",
+            &[Token::Bytes(&sig), Token::Bytes(&pk), Token::Bytes(&m)],
+        ),
+    )?;
+    ensure!(word(&receipt.output, 0)? == 1, "verifySingle failed");
+    snapshot.record("verifySingle", word(&receipt.output, 1)?, &receipt);
+    Ok(())
+}
+
+/// `hashToPoint` across message and DST lengths: expand_message_xmd hashes both, so its cost
+/// grows with their length while the map to curve steps stay constant.
+fn profile_hash_to_point(
+    evm: &mut LocalEvm,
+    harness: Address,
+    snapshot: &mut Snapshot,
+) -> anyhow::Result<()> {
+    for message_length in MESSAGE_LENGTHS {
+        for dst_length in DST_LENGTHS {
+            let receipt = evm.call(
+                harness,
+                &abi::encode_call(
+                    "hashToPoint(bytes,bytes)",
+                    &[
+                        Token::Bytes(&vec![b'D'; dst_length]),
+                        Token::Bytes(&vec![b'm'; message_length]),
+                    ],
+                ),
+            )?;
+            snapshot.record(
+                &format!("hashToPoint_msg{message_length}_dst{dst_length}"),
+                word(&receipt.output, 1)?,
+                &receipt,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// `g1UnmarshalCompressed` with the sort flag cleared and set, which select either square root.
+fn profile_unmarshal_compressed(
+    evm: &mut LocalEvm,
+    harness: Address,
+    tc: &TestCase,
+    snapshot: &mut Snapshot,
+) -> anyhow::Result<()> {
+    let sig = bls12_381::Signature::from_compressed_bytes(&hex::decode(&tc.sig_compressed)?)?;
+    let negated = bls12_381::Signature(-sig.0);
+    for sig in [sig, negated] {
+        let compressed = sig.to_compressed_bytes();
+        let name = if compressed[0] & bls_solidity_rs::compressed::SORT_FLAG != 0 {
+            "g1UnmarshalCompressed_larger"
+        } else {
+            "g1UnmarshalCompressed_smaller"
+        };
+        let receipt = evm.call(
+            harness,
+            &abi::encode_call("g1UnmarshalCompressed(bytes)", &[Token::Bytes(&compressed)]),
+        )?;
+        ensure!(
+            abi::decode_bytes(&receipt.output).as_deref() == Some(&sig.to_bytes()[..]),
+            "{name} returned another point"
+        );
+        snapshot.record(name, word(&receipt.output, 1)?, &receipt);
+    }
+    Ok(())
+}
+
+/// `verifySignature` of a signature scheme contract, from the ABI decoding of its arguments on:
+/// the gas of the transaction minus its intrinsic gas.
+fn profile_scheme(
+    evm: &mut LocalEvm,
+    artifacts: &Artifacts,
+    scheme: Scheme,
+    pk: &[u8],
+    sig: &[u8],
+    snapshot: &mut Snapshot,
+) -> anyhow::Result<()> {
+    let contract = format!("{scheme}SignatureScheme");
+    let constructor_args = abi::encode(&[Token::Bytes(pk), Token::String(APPLICATION)]);
+    let (address, _) = evm.deploy(&artifacts.bytecode(&contract)?, &constructor_args)?;
+
+    let dst = Dst::new(scheme, APPLICATION, bls_solidity_evm::CHAIN_ID).to_bytes();
+    let m = scheme.hash_to_bytes(&dst, APPLICATION.as_bytes());
+    let calldata = abi::encode_call(
+        "verifySignature(bytes,bytes)",
+        &[Token::Bytes(&m), Token::Bytes(sig)],
+    );
+    let receipt = evm.call(address, &calldata)?;
+    ensure!(
+        abi::decode_bool(&receipt.output) == Some(true),
+        "{contract}.verifySignature failed"
+    );
+    snapshot.record(
+        &format!("{contract}.verifySignature"),
+        receipt.gas_used - intrinsic_gas(&calldata),
+        &receipt,
+    );
+    Ok(())
+}
+
+/// Gas charged before execution for a call with `calldata`, EIP-2028 pricing.
+fn intrinsic_gas(calldata: &[u8]) -> u64 {
+    21_000
+        + calldata
+            .iter()
+            .map(|b| if *b == 0 { 4 } else { 16 })
+            .sum::<u64>()
+}
+
+/// Reads the `i`-th word of return data as a number.
+fn word(data: &[u8], i: usize) -> anyhow::Result<u64> {
+    let word = data
+        .get(32 * i..32 * (i + 1))
+        .context("return data too short")?;
+    let (high, low) = word.split_at(24);
+    ensure!(high.iter().all(|b| *b == 0), "return value overflows u64");
+    Ok(u64::from_be_bytes(low.try_into().unwrap()))
+}
@@ -0,0 +1,217 @@
+//! An in-process EVM running the contracts compiled by `forge build`, without forge.
+//!
+//! Contracts are read from the forge artifacts in `out/`, deployed to an empty state and called
+//! through transactions, with the precompiles of the hardfork in `foundry.toml`, which include
+//! the EIP-2537 BLS12-381 precompiles since Prague. Calls to precompiles are recorded with the gas
+//! they used.
+
+use bls_solidity_rs::abi::Address;
+use bls_solidity_rs::eip2537;
+
+use anyhow::{Context as _, anyhow, bail};
+use revm::context::result::{ExecutionResult, Output};
+use revm::context::{Context, TxEnv};
+use revm::database::{CacheDB, EmptyDB};
+use revm::handler::{MainnetContext, MainnetEvm};
+use revm::interpreter::{CallInputs, CallOutcome, InterpreterTypes};
+use revm::primitives::hardfork::SpecId;
+use revm::primitives::{Bytes, TxKind};
+use revm::{InspectCommitEvm, Inspector, MainBuilder, MainContext};
+
+use std::path::PathBuf;
+
+/// Chain id of anvil and of the forge tests, which the DSTs of the test vectors are bound to.
+pub const CHAIN_ID: u64 = 31337;
+
+/// `evm_version` of `foundry.toml`.
+pub const SPEC: SpecId = SpecId::OSAKA;
+
+/// Transaction gas limit cap of EIP-7825, active since Osaka.
+pub const GAS_LIMIT: u64 = 1 << 24;
+
+/// Sender of all transactions, the default sender of forge.
+pub const SENDER: Address = [
+    0x18, 0x04, 0xc8, 0xab, 0x1f, 0x12, 0xe6, 0xbb, 0xf3, 0x89, 0x4d, 0x40, 0x83, 0xf3, 0x3e, 0x07,
+    0x30, 0x9d, 0x1f, 0x38,
+];
+
+/// Forge output directory, `out` in `foundry.toml`.
+pub struct Artifacts {
+    dir: PathBuf,
+}
+
+impl Artifacts {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Creation bytecode of `contract`, declared in a file of the same name.
+    pub fn bytecode(&self, contract: &str) -> anyhow::Result<Vec<u8>> {
+        let path = self
+            .dir
+            .join(format!("{contract}.sol"))
+            .join(format!("{contract}.json"));
+        let file = std::fs::File::open(&path)
+            .with_context(|| format!("cannot open {}, run forge build", path.display()))?;
+        let artifact: serde_json::Value = serde_json::from_reader(std::io::BufReader::new(file))?;
+        let object = artifact["bytecode"]["object"]
+            .as_str()
+            .with_context(|| format!("no bytecode in {}", path.display()))?;
+        if object.contains("__$") {
+            bail!("{contract} links external libraries");
+        }
+        Ok(hex::decode(object.trim_start_matches("0x"))?)
+    }
+}
+
+/// Name of the precompile at `address`, after the constants of `Precompiles.sol` where they exist.
+pub fn precompile_name(address: &Address) -> Option<&'static str> {
+    let (zeros, last) = address.split_at(19);
+    if zeros.iter().any(|b| *b != 0) {
+        return None;
+    }
+    Some(match last[0] {
+        0x01 => "ECRECOVER",
+        0x02 => "SHA256",
+        0x03 => "RIPEMD160",
+        0x04 => "IDENTITY",
+        0x05 => "MODEXP",
+        0x06 => "ECADD",
+        0x07 => "ECMUL",
+        0x08 => "BN254_ECPAIRING",
+        0x09 => "BLAKE2F",
+        0x0a => "POINT_EVALUATION",
+        eip2537::G1ADD => "BLS12_G1ADD",
+        eip2537::G1MSM => "BLS12_G1MSM",
+        eip2537::G2ADD => "BLS12_G2ADD",
+        eip2537::G2MSM => "BLS12_G2MSM",
+        eip2537::PAIRING_CHECK => "BLS12_PAIRING_CHECK",
+        eip2537::MAP_FP_TO_G1 => "BLS12_MAP_FP_TO_G1",
+        eip2537::MAP_FP2_TO_G2 => "BLS12_MAP_FP2_TO_G2",
+        _ => return None,
+    })
+}
+
+/// A call to a precompile made during a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecompileCall {
+    pub address: Address,
+    pub name: &'static str,
+    pub input_length: usize,
+    pub gas_used: u64,
+}
+
+/// Outcome of a successful transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Receipt {
+    /// return data, or the deployed code when creating a contract
+    pub output: Vec<u8>,
+    pub gas_used: u64,
+    /// in the order they were made
+    pub precompile_calls: Vec<PrecompileCall>,
+}
+
+/// Records the calls to precompiles of the current transaction.
+#[derive(Default)]
+struct PrecompileTracer {
+    calls: Vec<PrecompileCall>,
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for PrecompileTracer {
+    fn call_end(&mut self, _context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+        let address = inputs.bytecode_address.into_array();
+        if let Some(name) = precompile_name(&address) {
+            self.calls.push(PrecompileCall {
+                address,
+                name,
+                input_length: inputs.input.len(),
+                gas_used: outcome.result.gas.spent(),
+            });
+        }
+    }
+}
+
+pub struct LocalEvm {
+    evm: MainnetEvm<MainnetContext<CacheDB<EmptyDB>>, PrecompileTracer>,
+    nonce: u64,
+}
+
+impl Default for LocalEvm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalEvm {
+    pub fn new() -> Self {
+        let evm = Context::mainnet()
+            .with_db(CacheDB::<EmptyDB>::default())
+            .modify_cfg_chained(|cfg| {
+                cfg.spec = SPEC;
+                cfg.chain_id = CHAIN_ID;
+            })
+            .build_mainnet_with_inspector(PrecompileTracer::default());
+        Self { evm, nonce: 0 }
+    }
+
+    /// Deploys `bytecode` with the ABI encoded `constructor_args` appended, like `new Contract(...)`.
+    pub fn deploy(
+        &mut self,
+        bytecode: &[u8],
+        constructor_args: &[u8],
+    ) -> anyhow::Result<(Address, Receipt)> {
+        let data = [bytecode, constructor_args].concat();
+        let (output, mut receipt) = self.transact(TxKind::Create, data)?;
+        match output {
+            Output::Create(code, Some(address)) => {
+                receipt.output = code.to_vec();
+                Ok((address.into_array(), receipt))
+            }
+            output => Err(anyhow!("unexpected output of a creation {output:?}")),
+        }
+    }
+
+    /// Sends a transaction calling `to` with `calldata`, committing its state changes.
+    pub fn call(&mut self, to: Address, calldata: &[u8]) -> anyhow::Result<Receipt> {
+        let (output, mut receipt) = self.transact(TxKind::Call(to.into()), calldata.to_vec())?;
+        receipt.output = output.into_data().to_vec();
+        Ok(receipt)
+    }
+
+    fn transact(&mut self, kind: TxKind, data: Vec<u8>) -> anyhow::Result<(Output, Receipt)> {
+        let tx = TxEnv {
+            caller: SENDER.into(),
+            kind,
+            data: Bytes::from(data),
+            nonce: self.nonce,
+            gas_limit: GAS_LIMIT,
+            chain_id: Some(CHAIN_ID),
+            ..Default::default()
+        };
+        self.evm.inspector.calls.clear();
+        let result = self
+            .evm
+            .inspect_tx_commit(tx)
+            .map_err(|e| anyhow!("transaction rejected: {e}"))?;
+        self.nonce += 1;
+        match result {
+            ExecutionResult::Success {
+                output, gas_used, ..
+            } => Ok((
+                output,
+                Receipt {
+                    output: Vec::new(),
+                    gas_used,
+                    precompile_calls: std::mem::take(&mut self.evm.inspector.calls),
+                },
+            )),
+            ExecutionResult::Revert { output, gas_used } => Err(anyhow!(
+                "reverted after {gas_used} gas: 0x{}",
+                hex::encode(output)
+            )),
+            ExecutionResult::Halt { reason, gas_used } => {
+                Err(anyhow!("halted after {gas_used} gas: {reason:?}"))
+            }
+        }
+    }
+}
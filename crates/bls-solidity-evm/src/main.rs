@@ -0,0 +1,180 @@
+use bls_solidity_evm::{Artifacts, LocalEvm};
+use bls_solidity_rs::Scheme;
+use bls_solidity_rs::abi::{self, Address, Token};
+
+use anyhow::{Context, bail, ensure};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+
+/// The fields of a test vector of `test/data/testcases.json` used by the contracts.
+#[derive(Deserialize)]
+struct TestCase {
+    dst: String,
+    message: String,
+    pk: String,
+    m_expected: String,
+    scheme: String,
+    sig: String,
+    sig_compressed: String,
+    drand_round_number: u64,
+    application: String,
+}
+
+struct Registries {
+    quicknet: Address,
+    evmnet: Address,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: bls-solidity-evm <forge out dir> <testcases.json>");
+        std::process::exit(2);
+    }
+    let artifacts = Artifacts::new(&args[1]);
+    let testcases: Vec<TestCase> = serde_json::from_reader(BufReader::new(
+        File::open(&args[2]).with_context(|| format!("cannot open {}", args[2]))?,
+    ))?;
+
+    let mut evm = LocalEvm::new();
+    let registries = Registries {
+        quicknet: evm.deploy(&artifacts.bytecode("QuicknetRegistry")?, &[])?.0,
+        evmnet: evm.deploy(&artifacts.bytecode("EvmnetRegistry")?, &[])?.0,
+    };
+
+    let mut failures = 0;
+    for (i, tc) in testcases.iter().enumerate() {
+        if let Err(e) = replay(&mut evm, &artifacts, &registries, tc) {
+            eprintln!("testcase {i} ({} {}): {e:#}", tc.scheme, tc.dst);
+            failures += 1;
+        }
+    }
+    println!("{} testcases replayed, {failures} failed", testcases.len());
+    if failures > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Runs the checks of the forge tests reading `testcases.json`.
+fn replay(
+    evm: &mut LocalEvm,
+    artifacts: &Artifacts,
+    registries: &Registries,
+    tc: &TestCase,
+) -> anyhow::Result<()> {
+    let scheme: Scheme = tc.scheme.parse()?;
+    let sig = hex::decode(&tc.sig)?;
+    let sig_compressed = hex::decode(&tc.sig_compressed)?;
+
+    // the DST of the signature scheme contracts is derived from an application name
+    if !tc.application.is_empty() {
+        match scheme {
+            Scheme::Bn254 => verify(evm, artifacts, "BN254SignatureScheme", tc, &sig)?,
+            Scheme::Bls12381 | Scheme::Bls12381Compressed => {
+                verify(evm, artifacts, "BLS12381SignatureScheme", tc, &sig)?;
+                verify(
+                    evm,
+                    artifacts,
+                    "BLS12381CompressedSignatureScheme",
+                    tc,
+                    &sig_compressed,
+                )?;
+            }
+        }
+    }
+
+    if tc.drand_round_number != 0 {
+        match scheme {
+            Scheme::Bn254 => prove_round(evm, registries.evmnet, tc.drand_round_number, &sig)?,
+            Scheme::Bls12381 | Scheme::Bls12381Compressed => prove_round(
+                evm,
+                registries.quicknet,
+                tc.drand_round_number,
+                &sig_compressed,
+            )?,
+        }
+    }
+    Ok(())
+}
+
+fn verify(
+    evm: &mut LocalEvm,
+    artifacts: &Artifacts,
+    contract: &str,
+    tc: &TestCase,
+    signature: &[u8],
+) -> anyhow::Result<()> {
+    let pk = hex::decode(&tc.pk)?;
+    let constructor_args = abi::encode(&[Token::Bytes(&pk), Token::String(&tc.application)]);
+    let (scheme, _) = evm
+        .deploy(&artifacts.bytecode(contract)?, &constructor_args)
+        .with_context(|| format!("deploying {contract}"))?;
+
+    let dst = call_bytes(evm, scheme, abi::encode_call("DST()", &[]))?;
+    ensure!(
+        dst == tc.dst.as_bytes(),
+        "{contract}.DST() is {:?}",
+        String::from_utf8_lossy(&dst)
+    );
+
+    let message = hex::decode(&tc.message)?;
+    let m = call_bytes(
+        evm,
+        scheme,
+        abi::encode_call("hashToBytes(bytes)", &[Token::Bytes(&message)]),
+    )?;
+    ensure!(
+        hex::encode(&m) == tc.m_expected,
+        "{contract}.hashToBytes returned 0x{}",
+        hex::encode(&m)
+    );
+
+    let calldata = abi::encode_call(
+        "verifySignature(bytes,bytes)",
+        &[Token::Bytes(&m), Token::Bytes(signature)],
+    );
+    match abi::decode_bool(&evm.call(scheme, &calldata)?.output) {
+        Some(true) => Ok(()),
+        Some(false) => bail!("{contract}.verifySignature returned false"),
+        None => bail!("{contract}.verifySignature did not return a bool"),
+    }
+}
+
+fn prove_round(
+    evm: &mut LocalEvm,
+    registry: Address,
+    round: u64,
+    signature: &[u8],
+) -> anyhow::Result<()> {
+    evm.call(
+        registry,
+        &abi::encode_call(
+            "proveRound(bytes,uint64)",
+            &[Token::Bytes(signature), Token::Uint(abi::uint(round))],
+        ),
+    )
+    .context("proveRound")?;
+
+    let randomness = evm
+        .call(
+            registry,
+            &abi::encode_call("roundRandomness(uint64)", &[Token::Uint(abi::uint(round))]),
+        )?
+        .output;
+    ensure!(
+        randomness == Sha256::digest(signature).as_slice(),
+        "roundRandomness({round}) is 0x{}",
+        hex::encode(randomness)
+    );
+    Ok(())
+}
+
+fn call_bytes(evm: &mut LocalEvm, to: Address, calldata: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+    let output = evm.call(to, &calldata)?.output;
+    abi::decode_bytes(&output).context("return value is not ABI encoded bytes")
+}
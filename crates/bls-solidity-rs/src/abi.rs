@@ -0,0 +1,88 @@
+//! Minimal Solidity ABI encoding, enough to reproduce the `abi.encode` calls of the contracts and
+//! to call them.
+
+use sha3::{Digest, Keccak256};
+
+pub type Address = [u8; 20];
+pub type Uint256 = [u8; 32];
+
+pub enum Token<'a> {
+    Address(Address),
+    Uint(Uint256),
+    String(&'a str),
+    Bytes(&'a [u8]),
+}
+
+/// Equivalent of `abi.encode(tokens...)`.
+pub fn encode(tokens: &[Token]) -> Vec<u8> {
+    let mut head = Vec::with_capacity(32 * tokens.len());
+    let mut tail = Vec::new();
+    for token in tokens {
+        match token {
+            Token::Address(a) => head.extend_from_slice(&left_pad(a)),
+            Token::Uint(u) => head.extend_from_slice(u),
+            Token::String(s) => encode_tail(s.as_bytes(), tokens.len(), &mut head, &mut tail),
+            Token::Bytes(b) => encode_tail(b, tokens.len(), &mut head, &mut tail),
+        }
+    }
+    head.extend_from_slice(&tail);
+    head
+}
+
+/// Function selector: the first 4 bytes of keccak256 of the canonical signature, e.g.
+/// `verifySignature(bytes,bytes)`.
+pub fn selector(signature: &str) -> [u8; 4] {
+    Keccak256::digest(signature)[..4].try_into().unwrap()
+}
+
+/// Calldata of a call to `signature` with arguments `tokens`.
+pub fn encode_call(signature: &str, tokens: &[Token]) -> Vec<u8> {
+    [&selector(signature)[..], &encode(tokens)].concat()
+}
+
+/// Decodes the return data of a function returning a single `bytes` or `string`.
+pub fn decode_bytes(data: &[u8]) -> Option<Vec<u8>> {
+    let word = |i: usize| -> Option<usize> {
+        let word = data.get(i..i + 32)?;
+        let (high, low) = word.split_at(24);
+        if high.iter().any(|b| *b != 0) {
+            return None;
+        }
+        usize::try_from(u64::from_be_bytes(low.try_into().unwrap())).ok()
+    };
+    let offset = word(0)?;
+    let len = word(offset)?;
+    let start = offset.checked_add(32)?;
+    data.get(start..start.checked_add(len)?).map(<[u8]>::to_vec)
+}
+
+/// Decodes the return data of a function returning a single `bool`.
+pub fn decode_bool(data: &[u8]) -> Option<bool> {
+    match data {
+        [zeros @ .., last] if data.len() == 32 && zeros.iter().all(|b| *b == 0) => match last {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// dynamic values store their offset in the head, and their length and right padded content in the tail
+fn encode_tail(data: &[u8], n_tokens: usize, head: &mut Vec<u8>, tail: &mut Vec<u8>) {
+    head.extend_from_slice(&uint((32 * n_tokens + tail.len()) as u64));
+    tail.extend_from_slice(&uint(data.len() as u64));
+    tail.extend_from_slice(data);
+    tail.resize(tail.len().next_multiple_of(32), 0);
+}
+
+/// Left pads at most 32 bytes to a word, like the ABI encoding of `uintN` and `address`.
+pub fn left_pad(data: &[u8]) -> Uint256 {
+    let mut word = [0u8; 32];
+    word[32 - data.len()..].copy_from_slice(data);
+    word
+}
+
+pub fn uint(value: u64) -> Uint256 {
+    left_pad(&value.to_be_bytes())
+}
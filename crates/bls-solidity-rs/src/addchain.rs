@@ -0,0 +1,516 @@
+//! Addition chains for exponentiations by a constant, emitted as the Yul libraries of `ModExp.sol`.
+//!
+//! `ModexpInverse` and `ModexpSqrt` compute `x^e mod N` with `mulmod` only. A chain starts by
+//! computing the odd powers of `x` that the windows of the exponent need, then consumes the bits of
+//! `e` from the most significant one, squaring for each bit and multiplying by the odd power of
+//! each window (sliding window exponentiation). Every step is one `mulmod`, so the cost of a chain
+//! is its length: the search keeps the window size giving the shortest chain whose registers fit
+//! in the stack of the Yul block.
+//!
+//! `mulmod` works on `uint256`, so the BLS12-381 libraries (`BLS2ModexpInverse` and
+//! `BLS2ModexpSqrt`) take and return field elements as `(uint128 hi, uint256 lo)` like `BLS2.sol`,
+//! and keep their registers in 64 bytes memory slots. A step computes the 768 bits product of two
+//! registers from `uint256` limbs, and reduces it with the MODEXP precompile (exponent 1): a chain
+//! of a few hundred precompile calls costs more gas than a single MODEXP by the exponent, the
+//! libraries only exist to verify and compare the chains of both curves. Moduli of up to 384 bits
+//! are emitted this way, `Exponentiation::library` returns `Error::ModulusTooLarge` above.
+//!
+//! Chains are checked twice: their exponent is recomputed from the steps, and `evaluate` runs the
+//! register program that is emitted, to compare with `modpow` like the `bls_ffi` oracles do.
+
+use crate::Error;
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use std::collections::BTreeMap;
+
+/// Registers of the emitted code, the return value `t0` and the input included. Along with `n`
+/// and the operands of `mulmod`, more would make the Yul block too deep for the stack.
+pub const MAX_REGISTERS: usize = 10;
+
+/// Largest window searched, the precomputation growing as 2^(window - 1).
+pub const MAX_WINDOW: usize = 8;
+
+/// An exponentiation `input^exponent mod modulus` to generate a library for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Exponentiation {
+    /// Name of the Solidity library.
+    pub library: String,
+    /// `@title` of the library.
+    pub title: String,
+    /// The exponent in terms of the modulus `N`, e.g. `N - 2`.
+    pub expression: String,
+    pub modulus: BigUint,
+    pub exponent: BigUint,
+}
+
+impl Exponentiation {
+    /// Names of the exponentiations of `Exponentiation::named`.
+    pub const NAMES: [&str; 5] = [
+        "bn254-inverse",
+        "bn254-sqrt",
+        "bn254-legendre",
+        "bls12-381-inverse",
+        "bls12-381-sqrt",
+    ];
+
+    /// Inversion, square root and Legendre symbol in the base field of BN254, as used by
+    /// `BLS.sol`, and inversion and square root in the base field of BLS12-381, the
+    /// `BLS2ModexpInverse` and `BLS2ModexpSqrt` libraries.
+    pub fn named(name: &str) -> Result<Self, Error> {
+        let (modulus, kind) = match name.split_once('-') {
+            Some(("bn254", kind)) => (BigUint::from(ark_bn254::Fq::MODULUS), kind),
+            _ => match name.strip_prefix("bls12-381-") {
+                Some(kind) => (BigUint::from(ark_bls12_381::Fq::MODULUS), kind),
+                None => return Err(Error::UnknownExponentiation(name.to_owned())),
+            },
+        };
+        let one = BigUint::from(1u32);
+        let (library, title, expression, exponent) = match kind {
+            "inverse" => (
+                "ModexpInverse",
+                "Compute Inverse by Modular Exponentiation",
+                "N - 2",
+                &modulus - 2u32,
+            ),
+            // both moduli are 3 mod 4
+            "sqrt" => (
+                "ModexpSqrt",
+                "Compute Square Root by Modular Exponentiation",
+                "(N + 1) / 4",
+                (&modulus + &one) / 4u32,
+            ),
+            "legendre" if name.starts_with("bn254") => (
+                "ModexpLegendre",
+                "Compute Legendre Symbol by Modular Exponentiation",
+                "(N - 1) / 2",
+                (&modulus - &one) / 2u32,
+            ),
+            _ => return Err(Error::UnknownExponentiation(name.to_owned())),
+        };
+        // named after BLS2.sol, whose (uint128, uint256) field elements they take
+        let library = if name.starts_with("bls12-381") {
+            format!("BLS2{library}")
+        } else {
+            library.to_owned()
+        };
+        Ok(Self {
+            library,
+            title: title.to_owned(),
+            expression: expression.to_owned(),
+            modulus,
+            exponent,
+        })
+    }
+
+    /// `input^exponent mod modulus`, for any other exponent.
+    pub fn custom(library: &str, modulus: BigUint, exponent: BigUint) -> Self {
+        Self {
+            library: library.to_owned(),
+            title: "Compute Modular Exponentiation by a Constant".to_owned(),
+            expression: "E".to_owned(),
+            modulus,
+            exponent,
+        }
+    }
+
+    /// The library computing the exponentiation with `chain`, formatted like `forge fmt`. Moduli
+    /// of up to 256 bits are reduced by `mulmod`, larger ones of up to 384 bits by MODEXP.
+    pub fn library(&self, chain: &Chain, command: &str) -> Result<String, Error> {
+        if self.modulus.bits() > 384 {
+            return Err(Error::ModulusTooLarge);
+        }
+        assert_eq!(chain.exponent(), self.exponent, "chain of another exponent");
+
+        let width = self.expression.len().max(1);
+        let mut lines = vec![
+            format!("/// @title {}", self.title),
+            format!(
+                "/// @notice Compute $input^{} mod N$ using Addition Chain method.",
+                notation(&self.expression)
+            ),
+            format!("/// Where {:>width$} = 0x{:x}", "N", self.modulus),
+            format!(
+                "/// and   {:>width$} = 0x{:x}",
+                self.expression, self.exponent
+            ),
+            format!(
+                "/// @dev the function body is generated with `{command}`: {} squarings and {} \
+                 multiplications",
+                chain.squarings(),
+                chain.multiplications()
+            ),
+        ];
+        if self.modulus.bits() > 256 {
+            lines.push(
+                "/// Field elements are (uint128, uint256) pairs like in BLS2.sol, each product \
+                 being reduced by the MODEXP precompile."
+                    .to_owned(),
+            );
+        }
+        lines.push(format!("library {} {{", self.library));
+        let program = chain.program();
+        if self.modulus.bits() > 256 {
+            self.modexp_run(&program, &mut lines);
+        } else {
+            self.mulmod_run(&program, &mut lines);
+        }
+        lines.push("}".to_owned());
+        Ok(lines.join("\n") + "\n")
+    }
+
+    /// `run` on the `uint256` registers `t0`, `t1`, ... of the Yul block.
+    fn mulmod_run(&self, program: &Program, lines: &mut Vec<String>) {
+        lines.push(format!(
+            "    function run(uint256 t{}) internal pure returns (uint256 t0) {{",
+            program.input
+        ));
+        lines.push("        assembly {".to_owned());
+        lines.push(format!("            let n := 0x{:x}", self.modulus));
+        for instruction in &program.instructions {
+            let declaration = if instruction.declares { "let " } else { "" };
+            lines.push(match instruction.operands {
+                Some((a, b)) => format!(
+                    "            {declaration}t{} := mulmod(t{a}, t{b}, n)",
+                    instruction.register
+                ),
+                None => format!("            t0 := t{}", instruction.register),
+            });
+        }
+        lines.extend(["        }", "    }"].map(str::to_owned));
+    }
+
+    /// `run` on registers in memory, the (hi, lo) words of register `r` being at
+    /// `REGISTERS + 0x40 * r` past the MODEXP input. `mulmodn` computes the 768 bits product of two
+    /// registers from their words, and MODEXP reduces it into the destination register.
+    fn modexp_run(&self, program: &Program, lines: &mut Vec<String>) {
+        let hi = &self.modulus >> 256u32;
+        let lo = &self.modulus - (&hi << 256u32);
+        let slot = |r: usize| REGISTERS + 0x40 * r;
+        lines.extend(
+            [
+                "    function run(uint128 x_hi, uint256 x_lo) internal view returns (uint128 hi, uint256 lo) {",
+                "        assembly {",
+                "            // t_r := t_a * t_b mod N, for registers of at most 384 bits",
+                "            function mulmodn(m, r, a, b) {",
+                &format!("                let x := add(m, add(0x{REGISTERS:x}, shl(6, a)))"),
+                &format!("                let y := add(m, add(0x{REGISTERS:x}, shl(6, b)))"),
+                "                let w1, w0 := mul512(mload(add(x, 0x20)), mload(add(y, 0x20)))",
+                "                let w2, c := mul512(mload(x), mload(add(y, 0x20)))",
+                "                w1 := add(w1, c)",
+                "                w2 := add(w2, lt(w1, c))",
+                "                let h, d := mul512(mload(add(x, 0x20)), mload(y))",
+                "                w1 := add(w1, d)",
+                "                w2 := add(add(w2, h), add(lt(w1, d), mul(mload(x), mload(y))))",
+                "                mstore(add(m, 0x60), w2)",
+                "                mstore(add(m, 0x80), w1)",
+                "                mstore(add(m, 0xa0), w0)",
+                &format!(
+                    "                if iszero(staticcall(gas(), 0x05, m, 0x{MODEXP_INPUT:x}, \
+                     add(m, add(0x{REGISTERS:x}, shl(6, r))), 0x40)) {{"
+                ),
+                "                    revert(0, 0)",
+                "                }",
+                "            }",
+                "            // the 512 bits product of two words",
+                "            function mul512(x, y) -> h, l {",
+                "                l := mul(x, y)",
+                "                let mm := mulmod(x, y, not(0))",
+                "                h := sub(sub(mm, l), lt(mm, l))",
+                "            }",
+                "            let m := mload(0x40)",
+                "            mstore(m, 0x60) // length of base, the product",
+                "            mstore(add(m, 0x20), 0x01) // length of exponent",
+                "            mstore(add(m, 0x40), 0x40) // length of modulus",
+                "            mstore8(add(m, 0xc0), 0x01) // exponent",
+                &format!("            mstore(add(m, 0xc1), 0x{hi:x})"),
+                &format!("            mstore(add(m, 0xe1), 0x{lo:x})"),
+                &format!("            mstore(add(m, 0x{:x}), x_hi)", slot(program.input)),
+                &format!("            mstore(add(m, 0x{:x}), x_lo)", slot(program.input) + 0x20),
+            ]
+            .map(str::to_owned),
+        );
+        let mut result = 0;
+        for instruction in &program.instructions {
+            match instruction.operands {
+                Some((a, b)) => lines.push(format!(
+                    "            mulmodn(m, {}, {a}, {b})",
+                    instruction.register
+                )),
+                None => result = instruction.register,
+            }
+        }
+        lines.extend(
+            [
+                &format!("            hi := mload(add(m, 0x{:x}))", slot(result)),
+                &format!(
+                    "            lo := mload(add(m, 0x{:x}))",
+                    slot(result) + 0x20
+                ),
+                "        }",
+                "    }",
+            ]
+            .map(str::to_owned),
+        );
+    }
+}
+
+/// Size of the MODEXP input of `mulmodn`: three lengths, the 96 bytes product, the exponent byte and
+/// the 64 bytes modulus.
+const MODEXP_INPUT: usize = 0x101;
+
+/// Offset of the registers of the MODEXP libraries, past their 32 bytes aligned MODEXP input.
+const REGISTERS: usize = 0x120;
+
+/// `(N + 1) / 4` is written `{(N + 1) / 4}` in the notices, `N - 2` as `(N - 2)`.
+fn notation(expression: &str) -> String {
+    if expression.contains('/') {
+        format!("{{{expression}}}")
+    } else {
+        format!("({expression})")
+    }
+}
+
+/// Solidity source with `libraries`, like `ModExp.sol`.
+pub fn solidity(libraries: &[String]) -> String {
+    format!(
+        "// SPDX-License-Identifier: MIT\npragma solidity ^0.8;\n\n{}",
+        libraries.join("\n")
+    )
+}
+
+/// An addition chain: value 0 is the input, each step multiplies two earlier values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chain {
+    steps: Vec<(usize, usize)>,
+    /// Number of steps computing the odd powers, before the bits of the exponent are consumed.
+    precomputed: usize,
+    window: usize,
+}
+
+impl Chain {
+    /// The shortest sliding window chain computing `exponent`, which must not be zero.
+    pub fn search(exponent: &BigUint) -> Self {
+        assert!(exponent.bits() > 0, "x^0 needs no multiplication");
+        (1..=MAX_WINDOW)
+            .map(|window| Self::sliding_window(exponent, window))
+            .filter(|chain| chain.registers() <= MAX_REGISTERS)
+            .min_by_key(Chain::len)
+            .expect("a window of 1 needs two registers")
+    }
+
+    /// Sliding window exponentiation with windows of at most `window` bits.
+    pub fn sliding_window(exponent: &BigUint, window: usize) -> Self {
+        // (squarings before the window, value of the window)
+        let bits: Vec<bool> = (0..exponent.bits())
+            .rev()
+            .map(|i| exponent.bit(i))
+            .collect();
+        let mut windows = Vec::new();
+        let mut squarings = 0;
+        let mut i = 0;
+        while i < bits.len() {
+            if !bits[i] {
+                squarings += 1;
+                i += 1;
+                continue;
+            }
+            let mut end = (i + window).min(bits.len());
+            while !bits[end - 1] {
+                end -= 1;
+            }
+            let value = bits[i..end]
+                .iter()
+                .fold(0usize, |value, bit| 2 * value + usize::from(*bit));
+            windows.push((squarings + end - i, value));
+            squarings = 0;
+            i = end;
+        }
+
+        let mut chain = Chain {
+            steps: Vec::new(),
+            precomputed: 0,
+            window,
+        };
+        // exponents of the precomputed values, and their index
+        let mut powers = BTreeMap::from([(1, 0)]);
+        if windows.iter().any(|(_, value)| *value > 1) {
+            powers.insert(2, chain.push(0, 0));
+        }
+        let mut needed: Vec<usize> = windows.iter().map(|(_, value)| *value).collect();
+        needed.sort();
+        for value in needed {
+            chain.power(&mut powers, value);
+        }
+        chain.precomputed = chain.steps.len();
+
+        let mut accumulator = powers[&windows[0].1];
+        for (shift, value) in &windows[1..] {
+            for _ in 0..*shift {
+                accumulator = chain.push(accumulator, accumulator);
+            }
+            accumulator = chain.push(accumulator, powers[value]);
+        }
+        for _ in 0..squarings {
+            accumulator = chain.push(accumulator, accumulator);
+        }
+        chain
+    }
+
+    /// Index of x^value, computing it from the known powers: in one step if two of them add up to
+    /// it, from x^(value - 2) otherwise.
+    fn power(&mut self, powers: &mut BTreeMap<usize, usize>, value: usize) -> usize {
+        if let Some(index) = powers.get(&value) {
+            return *index;
+        }
+        let sum = powers
+            .iter()
+            .find_map(|(a, i)| powers.get(&value.checked_sub(*a)?).map(|j| (*i, *j)));
+        let index = match sum {
+            Some((i, j)) => self.push(i, j),
+            None => {
+                let previous = self.power(powers, value - 2);
+                self.push(previous, powers[&2])
+            }
+        };
+        powers.insert(value, index);
+        index
+    }
+
+    fn push(&mut self, a: usize, b: usize) -> usize {
+        self.steps.push((a, b));
+        self.steps.len()
+    }
+
+    /// Number of `mulmod`.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn squarings(&self) -> usize {
+        self.steps.iter().filter(|(a, b)| a == b).count()
+    }
+
+    pub fn multiplications(&self) -> usize {
+        self.len() - self.squarings()
+    }
+
+    /// Size of the sliding window.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// The exponent computed by the chain.
+    pub fn exponent(&self) -> BigUint {
+        let mut exponents = vec![BigUint::from(1u32)];
+        for (a, b) in &self.steps {
+            exponents.push(&exponents[*a] + &exponents[*b]);
+        }
+        exponents.pop().expect("the input is the first value")
+    }
+
+    /// Registers used by the emitted code.
+    pub fn registers(&self) -> usize {
+        self.program().registers
+    }
+
+    /// Runs the emitted code, `mulmod` by `mulmod`.
+    pub fn evaluate(&self, input: &BigUint, modulus: &BigUint) -> BigUint {
+        let program = self.program();
+        let mut registers = vec![BigUint::default(); program.registers];
+        registers[program.input] = input.clone();
+        for instruction in &program.instructions {
+            match instruction.operands {
+                Some((a, b)) => {
+                    registers[instruction.register] = (&registers[a] * &registers[b]) % modulus
+                }
+                None => registers[0] = registers[instruction.register].clone(),
+            }
+        }
+        registers[0].clone()
+    }
+
+    /// Assigns the values to registers, reusing the ones of values that are no longer needed.
+    /// The result is computed in `t0`, the return variable, which is only used by the
+    /// precomputation for values that are not needed afterwards.
+    fn program(&self) -> Program {
+        let values = self.steps.len() + 1;
+        let mut last_use = vec![0; values];
+        for (step, (a, b)) in self.steps.iter().enumerate() {
+            last_use[*a] = step;
+            last_use[*b] = step;
+        }
+        last_use[values - 1] = usize::MAX;
+
+        let mut register = vec![0; values];
+        let mut free = vec![true; values + 1];
+        let mut declared = vec![false; values + 1];
+        let allocate = |free: &mut [bool], last_use: usize| {
+            let first = if last_use > self.precomputed { 1 } else { 0 };
+            let r = (first..free.len())
+                .find(|r| free[*r])
+                .expect("a free register");
+            free[r] = false;
+            r
+        };
+        let input = allocate(&mut free, usize::MAX);
+        register[0] = input;
+        declared[0] = true;
+        declared[input] = true;
+
+        let mut instructions = Vec::new();
+        for (step, (a, b)) in self.steps.iter().enumerate() {
+            for operand in [a, b] {
+                if last_use[*operand] == step {
+                    free[register[*operand]] = true;
+                }
+            }
+            let r = if step >= self.precomputed {
+                assert!(free[0], "t0 is the accumulator");
+                free[0] = false;
+                0
+            } else {
+                allocate(&mut free, last_use[step + 1])
+            };
+            register[step + 1] = r;
+            instructions.push(Instruction {
+                register: r,
+                operands: Some((register[*a], register[*b])),
+                declares: !declared[r],
+            });
+            declared[r] = true;
+        }
+        let result = register[values - 1];
+        if result != 0 {
+            instructions.push(Instruction {
+                register: result,
+                operands: None,
+                declares: false,
+            });
+        }
+        Program {
+            input,
+            registers: declared.iter().rposition(|d| *d).unwrap_or(0) + 1,
+            instructions,
+        }
+    }
+}
+
+/// The chain on registers `t0`, `t1`, ...
+struct Program {
+    input: usize,
+    registers: usize,
+    instructions: Vec<Instruction>,
+}
+
+/// `register := mulmod(a, b, n)`, or `t0 := register` when the result is elsewhere.
+struct Instruction {
+    register: usize,
+    operands: Option<(usize, usize)>,
+    /// first assignment of the register, with `let`
+    declares: bool,
+}
@@ -0,0 +1,188 @@
+//! BLS12-381 keys, signatures and the encodings of `BLS2.sol`.
+//!
+//! Points are marshalled like `BLS2.g1Marshal` and `BLS2.g2Marshal`: big-endian 48 bytes
+//! coordinates without padding, G2 coordinates ordered c1 || c0. The point at infinity is all
+//! zeros. Compressed points use the ZCash format read by `BLS2.g1UnmarshalCompressed`, which is
+//! also the format of drand beacons.
+
+use crate::Error;
+use crate::error::check_length;
+use crate::point::{coordinates, fq_from_be_bytes, fq_to_be_bytes, from_coordinates};
+
+use utils::hash_to_curve::CustomPairingHashToCurve;
+
+use ark_bls12_381::{Bls12_381, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use std::fmt;
+
+/// `SCHEME_ID` of `BLS12381SignatureScheme`.
+pub const SCHEME_ID: &str = "BLS12381";
+
+/// `SCHEME_ID` of `BLS12381CompressedSignatureScheme`.
+pub const COMPRESSED_SCHEME_ID: &str = "BLS12381Compressed";
+
+/// Hash to curve suffix of the DSTs used with `BLS2.hashToPoint`.
+pub const H2C_SUFFIX: &str = "BLS12381G1_XMD:SHA-256_SSWU_RO";
+
+pub const FIELD_ELEMENT_LENGTH: usize = 48;
+pub const G1_LENGTH: usize = 2 * FIELD_ELEMENT_LENGTH;
+pub const G2_LENGTH: usize = 4 * FIELD_ELEMENT_LENGTH;
+pub const G1_COMPRESSED_LENGTH: usize = FIELD_ELEMENT_LENGTH;
+pub const G2_COMPRESSED_LENGTH: usize = 2 * FIELD_ELEMENT_LENGTH;
+
+/// `BLS2.g1Marshal`: x || y.
+pub fn g1_marshal(p: &G1Affine) -> [u8; G1_LENGTH] {
+    let (x, y) = coordinates(p);
+    let mut bytes = [0u8; G1_LENGTH];
+    bytes[..48].copy_from_slice(&fq_to_be_bytes(x));
+    bytes[48..].copy_from_slice(&fq_to_be_bytes(y));
+    bytes
+}
+
+/// `BLS2.g1Unmarshal`, also checking that the point is in the prime order subgroup.
+pub fn g1_unmarshal(bytes: &[u8]) -> Result<G1Affine, Error> {
+    check_length(bytes, G1_LENGTH)?;
+    let x = fq_from_be_bytes(&bytes[..48])?;
+    let y = fq_from_be_bytes(&bytes[48..])?;
+    from_coordinates(x, y)
+}
+
+/// `BLS2.g2Marshal`: x.c1 || x.c0 || y.c1 || y.c0.
+pub fn g2_marshal(p: &G2Affine) -> [u8; G2_LENGTH] {
+    let (x, y) = coordinates(p);
+    let mut bytes = [0u8; G2_LENGTH];
+    for (chunk, f) in bytes.chunks_exact_mut(48).zip([x.c1, x.c0, y.c1, y.c0]) {
+        chunk.copy_from_slice(&fq_to_be_bytes(f));
+    }
+    bytes
+}
+
+/// `BLS2.g2Unmarshal`, also checking that the point is in the prime order subgroup.
+pub fn g2_unmarshal(bytes: &[u8]) -> Result<G2Affine, Error> {
+    check_length(bytes, G2_LENGTH)?;
+    let [x1, x0, y1, y0] = [0, 1, 2, 3].map(|i| fq_from_be_bytes::<Fq>(&bytes[48 * i..][..48]));
+    from_coordinates(Fq2::new(x0?, x1?), Fq2::new(y0?, y1?))
+}
+
+/// Compressed G1 point, as read by `BLS2.g1UnmarshalCompressed`.
+pub fn g1_marshal_compressed(p: &G1Affine) -> [u8; G1_COMPRESSED_LENGTH] {
+    let mut bytes = [0u8; G1_COMPRESSED_LENGTH];
+    p.serialize_compressed(&mut bytes[..])
+        .expect("buffer has the size of a compressed point");
+    bytes
+}
+
+/// Reads a compressed G1 point, checking that it is in the prime order subgroup.
+pub fn g1_unmarshal_compressed(bytes: &[u8]) -> Result<G1Affine, Error> {
+    check_length(bytes, G1_COMPRESSED_LENGTH)?;
+    G1Affine::deserialize_compressed(bytes).map_err(|_| Error::InvalidCompressedPoint)
+}
+
+/// Compressed G2 point, e.g. the drand quicknet public key.
+pub fn g2_marshal_compressed(p: &G2Affine) -> [u8; G2_COMPRESSED_LENGTH] {
+    let mut bytes = [0u8; G2_COMPRESSED_LENGTH];
+    p.serialize_compressed(&mut bytes[..])
+        .expect("buffer has the size of a compressed point");
+    bytes
+}
+
+/// Reads a compressed G2 point, checking that it is in the prime order subgroup.
+pub fn g2_unmarshal_compressed(bytes: &[u8]) -> Result<G2Affine, Error> {
+    check_length(bytes, G2_COMPRESSED_LENGTH)?;
+    G2Affine::deserialize_compressed(bytes).map_err(|_| Error::InvalidCompressedPoint)
+}
+
+/// `BLS2.hashToPoint(dst, message)`.
+pub fn hash_to_point(dst: &[u8], message: &[u8]) -> G1Affine {
+    Bls12_381::hash_to_g1_custom::<sha2::Sha256>(message, dst).into_affine()
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretKey(Fr);
+
+impl SecretKey {
+    pub fn new(scalar: Fr) -> Self {
+        Self(scalar)
+    }
+
+    pub fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        Self(Fr::from_be_bytes_mod_order(bytes))
+    }
+
+    pub fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+        Self(Fr::from_le_bytes_mod_order(bytes))
+    }
+
+    pub fn scalar(&self) -> Fr {
+        self.0
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey((G2Affine::generator() * self.0).into_affine())
+    }
+
+    /// Signs `hashToPoint(dst, message)`.
+    pub fn sign(&self, dst: &[u8], message: &[u8]) -> Signature {
+        Signature((hash_to_point(dst, message) * self.0).into_affine())
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(..)")
+    }
+}
+
+/// Public key on G2, as taken by `BLS12381SignatureScheme` and
+/// `BLS12381CompressedSignatureScheme`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub G2Affine);
+
+impl PublicKey {
+    pub fn to_bytes(&self) -> [u8; G2_LENGTH] {
+        g2_marshal(&self.0)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        g2_unmarshal(bytes).map(Self)
+    }
+
+    pub fn to_compressed_bytes(&self) -> [u8; G2_COMPRESSED_LENGTH] {
+        g2_marshal_compressed(&self.0)
+    }
+
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        g2_unmarshal_compressed(bytes).map(Self)
+    }
+
+    /// Pairing check of `BLS2.verifySingle(signature, pubkey, hashToPoint(dst, message))`.
+    pub fn verify(&self, dst: &[u8], message: &[u8], signature: &Signature) -> bool {
+        let m = hash_to_point(dst, message);
+        Bls12_381::multi_pairing([m, signature.0], [self.0, -G2Affine::generator()]).is_zero()
+    }
+}
+
+/// Signature on G1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature(pub G1Affine);
+
+impl Signature {
+    pub fn to_bytes(&self) -> [u8; G1_LENGTH] {
+        g1_marshal(&self.0)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        g1_unmarshal(bytes).map(Self)
+    }
+
+    pub fn to_compressed_bytes(&self) -> [u8; G1_COMPRESSED_LENGTH] {
+        g1_marshal_compressed(&self.0)
+    }
+
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        g1_unmarshal_compressed(bytes).map(Self)
+    }
+}
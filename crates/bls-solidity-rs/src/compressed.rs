@@ -0,0 +1,131 @@
+//! BLS12-381 point compression with the flag semantics of `BLS2.g1UnmarshalCompressed`.
+//!
+//! The three most significant bits of the encoding are flags, as in the ZCash format: 0x80 marks
+//! a compressed point, 0x40 the point at infinity and 0x20 selects the lexicographically largest
+//! of the two possible y-coordinates. The contract does not support the point at infinity, so it
+//! is rejected here as well, unlike `bls12_381::g1_unmarshal_compressed`.
+//!
+//! `g1_decompress` and `g2_decompress` only accept the unique encoding of a point in the prime
+//! order subgroup, the encodings for which the pairing check of `BLS2.verifySingle` can succeed.
+//! The contract is more lenient: `g1_unmarshal_compressed` reproduces what it returns for any
+//! input, including x limbs that are not reduced and points that are not on the curve.
+//!
+//! G2 points follow the same rules, with x encoded as x.c1 || x.c0 and the flags on x.c1. There
+//! is no G2 decompression in the contracts.
+
+use crate::error::check_length;
+use crate::point::{fq_from_be_bytes, fq_to_be_bytes};
+use crate::{Error, sol};
+
+use ark_bls12_381::{Fq, Fq2, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+pub const COMPRESSION_FLAG: u8 = 0x80;
+pub const INFINITY_FLAG: u8 = 0x40;
+pub const SORT_FLAG: u8 = 0x20;
+
+pub const G1_LENGTH: usize = 48;
+pub const G2_LENGTH: usize = 96;
+
+pub fn g1_compress(p: &G1Affine) -> Result<[u8; G1_LENGTH], Error> {
+    let (x, y) = p.xy().ok_or(Error::InfinityUnsupported)?;
+    let mut bytes = [0u8; G1_LENGTH];
+    bytes.copy_from_slice(&fq_to_be_bytes(x));
+    bytes[0] |= flags(y > -y);
+    Ok(bytes)
+}
+
+pub fn g1_decompress(bytes: &[u8]) -> Result<G1Affine, Error> {
+    check_length(bytes, G1_LENGTH)?;
+    let (greatest, x) = read_flags(bytes)?;
+    decompress(fq_from_be_bytes(&x)?, greatest)
+}
+
+/// The point `BLS2.g1UnmarshalCompressed` returns, or the reason it reverts. Only the flags are
+/// checked: x is returned as encoded, possibly not smaller than p, and y is
+/// `(x^3 + 4)^((p + 1) / 4) mod p` computed with the ModExp precompile, or `p - y` depending on
+/// the sort flag, whether or not x^3 + 4 has a square root.
+pub fn g1_unmarshal_compressed(bytes: &[u8]) -> Result<sol::bls2::PointG1, Error> {
+    check_length(bytes, G1_LENGTH)?;
+    let (greatest, x) = read_flags(bytes)?;
+    let p = BigUint::from(Fq::MODULUS);
+    // x^3 mod p, then 4 is added without reducing the result
+    let c = BigUint::from_bytes_be(&x).modpow(&3u32.into(), &p) + 4u32;
+    let y = c.modpow(&((&p + 1u32) / 4u32), &p);
+    let alternative = &p - &y;
+    // p - 0 when y is zero and the sort flag is set
+    let y = if (y > alternative) == greatest {
+        y
+    } else {
+        alternative
+    };
+
+    let (x_hi, x_lo) = limbs(&x);
+    let (y_hi, y_lo) = limbs(&y.to_bytes_be());
+    Ok(sol::bls2::PointG1 {
+        x_hi,
+        x_lo,
+        y_hi,
+        y_lo,
+    })
+}
+
+pub fn g2_compress(p: &G2Affine) -> Result<[u8; G2_LENGTH], Error> {
+    let (x, y) = p.xy().ok_or(Error::InfinityUnsupported)?;
+    let mut bytes = [0u8; G2_LENGTH];
+    bytes[..48].copy_from_slice(&fq_to_be_bytes(x.c1));
+    bytes[48..].copy_from_slice(&fq_to_be_bytes(x.c0));
+    bytes[0] |= flags(y > -y);
+    Ok(bytes)
+}
+
+pub fn g2_decompress(bytes: &[u8]) -> Result<G2Affine, Error> {
+    check_length(bytes, G2_LENGTH)?;
+    let (greatest, x) = read_flags(bytes)?;
+    let x = Fq2::new(fq_from_be_bytes(&x[48..])?, fq_from_be_bytes(&x[..48])?);
+    decompress(x, greatest)
+}
+
+fn flags(greatest: bool) -> u8 {
+    if greatest {
+        COMPRESSION_FLAG | SORT_FLAG
+    } else {
+        COMPRESSION_FLAG
+    }
+}
+
+/// Checks the flags, returning the sort flag and the encoding with the flags cleared.
+fn read_flags(bytes: &[u8]) -> Result<(bool, Vec<u8>), Error> {
+    let flags = bytes[0];
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(Error::MissingCompressionFlag);
+    }
+    if flags & INFINITY_FLAG != 0 {
+        return Err(Error::InfinityUnsupported);
+    }
+    let mut x = bytes.to_vec();
+    x[0] &= !(COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG);
+    Ok((flags & SORT_FLAG != 0, x))
+}
+
+/// The `uint128` and `uint256` limbs of a coordinate of at most 48 bytes.
+fn limbs(bytes: &[u8]) -> (u128, sol::Uint256) {
+    let mut padded = [0u8; G1_LENGTH];
+    padded[G1_LENGTH - bytes.len()..].copy_from_slice(bytes);
+    let (hi, lo) = padded.split_at(16);
+    (
+        u128::from_be_bytes(hi.try_into().unwrap()),
+        lo.try_into().unwrap(),
+    )
+}
+
+fn decompress<P: SWCurveConfig>(x: P::BaseField, greatest: bool) -> Result<Affine<P>, Error> {
+    let p = Affine::<P>::get_point_from_x_unchecked(x, greatest).ok_or(Error::NotOnCurve)?;
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(p)
+}
@@ -0,0 +1,341 @@
+//! A simulated Pedersen DKG (joint Feldman VSS) giving the group public key of the signature
+//! scheme contracts.
+//!
+//! Each of the `n` dealers samples a polynomial of degree `t - 1` and commits to its coefficients
+//! on G2, where the public keys live. Node `j` receives `f_i(j)` from dealer `i` and checks it
+//! against the commitments, complaining if it does not match. A dealer answers a complaint by
+//! revealing the share; dealers who do not, or reveal a wrong share, are disqualified. The secret
+//! share of node `j` is the sum of `f_i(j)` over the qualified dealers, and the group public key is
+//! the sum of their constant term commitments, so that any `t` shares sign for the group.
+//!
+//! Everything runs in one process: the point is to produce fixtures with the structure of a real
+//! network's output, not to keep the shares apart.
+
+use crate::{Scheme, bls12_381, bn254};
+
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, One, PrimeField, UniformRand, Zero};
+use rand::RngCore;
+use serde_json::{Value, json};
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Parameters of a run. Nodes and dealers are the same participants, indexed from 1.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    pub n: usize,
+    /// number of shares needed to sign
+    pub t: usize,
+    pub faults: Vec<Fault>,
+}
+
+/// A misbehaving dealer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fault {
+    /// The dealer deals nothing and is left out of the qualified set.
+    Absent { dealer: usize },
+    /// The dealer sends `recipients` shares that do not match its commitments. Each of them
+    /// complains, and the dealer reveals the right shares if `answers`, or is disqualified.
+    WrongShares {
+        dealer: usize,
+        recipients: Vec<usize>,
+        answers: bool,
+    },
+}
+
+/// Reasons a run cannot complete.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `t` must be between 1 and `n`.
+    InvalidThreshold { n: usize, t: usize },
+    /// A fault names a participant outside `1..=n`.
+    UnknownParticipant(usize),
+    /// Fewer than `t` dealers are qualified.
+    NotEnoughQualifiedDealers { qualified: usize, t: usize },
+    /// The share of node `index` is not the group polynomial at `index`, so that `t` shares would
+    /// not sign for the group public key.
+    ShareMismatch { index: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidThreshold { n, t } => {
+                write!(f, "invalid threshold {t} of {n}: expected 1 <= t <= n")
+            }
+            Error::UnknownParticipant(i) => write!(f, "unknown participant {i}"),
+            Error::NotEnoughQualifiedDealers { qualified, t } => write!(
+                f,
+                "{qualified} qualified dealers, the threshold needs at least {t}"
+            ),
+            Error::ShareMismatch { index } => write!(
+                f,
+                "the share of node {index} does not match the group commitments"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A complaint of `recipient` against the share dealt by `dealer`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Complaint {
+    pub dealer: usize,
+    pub recipient: usize,
+    /// whether the dealer revealed a share matching its commitments
+    pub answered: bool,
+}
+
+/// Commitments of a dealer to its polynomial, `g2Marshal` of `a_k * G2`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deal {
+    pub dealer: usize,
+    pub commitments: Vec<Vec<u8>>,
+    pub qualified: bool,
+}
+
+/// The output of node `index`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Share {
+    pub index: usize,
+    /// big-endian scalar, as read by `SecretKey::from_be_bytes_mod_order`
+    pub secret_key: Vec<u8>,
+    /// `g2Marshal` of `secret_key * G2`, the commitment partial signatures are checked against
+    pub public_key: Vec<u8>,
+}
+
+/// Public transcript and outputs of a run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transcript {
+    pub scheme: Scheme,
+    pub n: usize,
+    pub t: usize,
+    pub deals: Vec<Deal>,
+    pub complaints: Vec<Complaint>,
+    /// sum of the commitments of the qualified dealers, the first one being the group public key
+    pub commitments: Vec<Vec<u8>>,
+    pub shares: Vec<Share>,
+    /// `publicKeyBytes` of the signature scheme constructor
+    pub group_public_key: Vec<u8>,
+}
+
+/// Runs the DKG for the public keys of `scheme`.
+pub fn simulate(
+    scheme: Scheme,
+    config: &Config,
+    rng: &mut impl RngCore,
+) -> Result<Transcript, Error> {
+    match scheme {
+        Scheme::Bn254 => {
+            simulate_on::<ark_bn254::Bn254>(scheme, config, rng, |p| bn254::g2_marshal(p).to_vec())
+        }
+        Scheme::Bls12381 | Scheme::Bls12381Compressed => {
+            simulate_on::<ark_bls12_381::Bls12_381>(scheme, config, rng, |p| {
+                bls12_381::g2_marshal(p).to_vec()
+            })
+        }
+    }
+}
+
+fn simulate_on<E: Pairing>(
+    scheme: Scheme,
+    config: &Config,
+    rng: &mut impl RngCore,
+    g2_marshal: impl Fn(&E::G2Affine) -> Vec<u8>,
+) -> Result<Transcript, Error> {
+    let Config { n, t, ref faults } = *config;
+    if t == 0 || t > n {
+        return Err(Error::InvalidThreshold { n, t });
+    }
+    let participants = 1..=n;
+    for fault in faults {
+        let (Fault::Absent { dealer } | Fault::WrongShares { dealer, .. }) = fault;
+        let recipients = match fault {
+            Fault::WrongShares { recipients, .. } => &recipients[..],
+            Fault::Absent { .. } => &[],
+        };
+        if let Some(i) = std::iter::once(dealer)
+            .chain(recipients)
+            .find(|i| !participants.contains(i))
+        {
+            return Err(Error::UnknownParticipant(*i));
+        }
+    }
+    let absent = |dealer: usize| {
+        faults
+            .iter()
+            .any(|f| matches!(f, Fault::Absent { dealer: d } if *d == dealer))
+    };
+    let wrong_share = |dealer: usize, recipient: usize| {
+        faults.iter().find_map(|f| match f {
+            Fault::WrongShares {
+                dealer: d,
+                recipients,
+                answers,
+            } if *d == dealer && recipients.contains(&recipient) => Some(*answers),
+            _ => None,
+        })
+    };
+
+    let g2 = E::G2Affine::generator();
+    let mut deals = Vec::new();
+    let mut complaints = Vec::new();
+    let mut disqualified = BTreeSet::new();
+    // accepted[j - 1] holds the shares node j accepted, with their dealer
+    let mut accepted = vec![Vec::new(); n];
+    for dealer in participants.clone().filter(|i| !absent(*i)) {
+        let coefficients: Vec<E::ScalarField> = (0..t).map(|_| E::ScalarField::rand(rng)).collect();
+        let commitments: Vec<E::G2Affine> =
+            E::G2::normalize_batch(&coefficients.iter().map(|a| g2 * a).collect::<Vec<_>>());
+
+        for recipient in participants.clone() {
+            let share = evaluate(&coefficients, recipient);
+            let sent = match wrong_share(dealer, recipient) {
+                Some(_) => share + E::ScalarField::one(),
+                None => share,
+            };
+            let expected = commitment_at::<E>(&commitments, recipient);
+            if g2 * sent == expected {
+                accepted[recipient - 1].push((dealer, sent));
+                continue;
+            }
+            // the dealer reveals f(recipient), which everyone checks against the commitments
+            let answered = wrong_share(dealer, recipient) == Some(true) && g2 * share == expected;
+            complaints.push(Complaint {
+                dealer,
+                recipient,
+                answered,
+            });
+            if answered {
+                accepted[recipient - 1].push((dealer, share));
+            } else {
+                disqualified.insert(dealer);
+            }
+        }
+        deals.push((dealer, commitments));
+    }
+
+    let qualified: Vec<usize> = deals
+        .iter()
+        .map(|(dealer, _)| *dealer)
+        .filter(|d| !disqualified.contains(d))
+        .collect();
+    if qualified.len() < t {
+        return Err(Error::NotEnoughQualifiedDealers {
+            qualified: qualified.len(),
+            t,
+        });
+    }
+
+    let mut group_commitments = vec![E::G2::zero(); t];
+    for (dealer, commitments) in &deals {
+        if qualified.contains(dealer) {
+            for (sum, c) in group_commitments.iter_mut().zip(commitments) {
+                *sum += c;
+            }
+        }
+    }
+    let group_commitments = E::G2::normalize_batch(&group_commitments);
+
+    let shares: Vec<Share> = participants
+        .map(|index| {
+            let secret: E::ScalarField = accepted[index - 1]
+                .iter()
+                .filter(|(dealer, _)| qualified.contains(dealer))
+                .map(|(_, share)| *share)
+                .sum();
+            let public_key = (g2 * secret).into_affine();
+            if public_key != commitment_at::<E>(&group_commitments, index).into_affine() {
+                return Err(Error::ShareMismatch { index });
+            }
+            Ok(Share {
+                index,
+                secret_key: secret.into_bigint().to_bytes_be(),
+                public_key: g2_marshal(&public_key),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Transcript {
+        scheme,
+        n,
+        t,
+        deals: deals
+            .into_iter()
+            .map(|(dealer, commitments)| Deal {
+                dealer,
+                commitments: commitments.iter().map(&g2_marshal).collect(),
+                qualified: qualified.contains(&dealer),
+            })
+            .collect(),
+        complaints,
+        group_public_key: g2_marshal(&group_commitments[0]),
+        commitments: group_commitments.iter().map(&g2_marshal).collect(),
+        shares,
+    })
+}
+
+/// `f(x)` by Horner's rule.
+fn evaluate<F: PrimeField>(coefficients: &[F], x: usize) -> F {
+    let x = F::from(x as u64);
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, a| acc * x + a)
+}
+
+/// `sum_k C_k * x^k`, the commitment to `f(x)`.
+fn commitment_at<E: Pairing>(commitments: &[E::G2Affine], x: usize) -> E::G2 {
+    let x = E::ScalarField::from(x as u64);
+    commitments
+        .iter()
+        .rev()
+        .fold(E::G2::zero(), |acc, c| acc * x + c)
+}
+
+impl Transcript {
+    /// The transcript as a JSON fixture, with hex encoded bytes.
+    pub fn to_json(&self) -> String {
+        let deals: Vec<Value> = self
+            .deals
+            .iter()
+            .map(|d| {
+                json!({
+                    "dealer": d.dealer,
+                    "commitments": d.commitments.iter().map(hex::encode).collect::<Vec<_>>(),
+                    "qualified": d.qualified,
+                })
+            })
+            .collect();
+        let complaints: Vec<Value> = self
+            .complaints
+            .iter()
+            .map(|c| json!({"dealer": c.dealer, "recipient": c.recipient, "answered": c.answered}))
+            .collect();
+        let shares: Vec<Value> = self
+            .shares
+            .iter()
+            .map(|s| {
+                json!({
+                    "index": s.index,
+                    "secret_key": hex::encode(&s.secret_key),
+                    "public_key": hex::encode(&s.public_key),
+                })
+            })
+            .collect();
+        serde_json::to_string_pretty(&json!({
+            "scheme": self.scheme.id(),
+            "n": self.n,
+            "t": self.t,
+            "deals": deals,
+            "complaints": complaints,
+            "commitments": self.commitments.iter().map(hex::encode).collect::<Vec<_>>(),
+            "shares": shares,
+            "group_public_key": hex::encode(&self.group_public_key),
+        }))
+        .expect("transcript serializes")
+    }
+}
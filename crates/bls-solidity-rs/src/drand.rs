@@ -0,0 +1,478 @@
+//! drand chains whose beacons `QuicknetRegistry` and `EvmnetRegistry` verify: chain info, round
+//! timing and offline beacon verification.
+//!
+//! Both chains are unchained: the beacon of round `r` is a signature of `H(r)`, where `r` is
+//! encoded as 8 bytes big-endian and `H` is sha256 on quicknet and keccak256 on evmnet. The
+//! message is hashed to G1 with the DSTs the registries use, and the randomness of a round is
+//! `sha256(signature)`, which is what the registries store in `roundRandomness`.
+//!
+//! Round 1 is emitted at the genesis time, and then one round every `period` seconds.
+//!
+//! [`TestChain`] signs beacons with a local key instead, to stand in for a drand network in
+//! offline integration tests.
+
+use crate::abi::{self, Address, Token};
+use crate::compressed;
+use crate::{bls12_381, bn254};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+use std::fmt;
+
+/// The drand signature schemes with a registry contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DrandScheme {
+    /// `bls-unchained-g1-rfc9380`, used by quicknet and verified by `QuicknetRegistry`.
+    UnchainedG1Rfc9380,
+    /// `bls-bn254-unchained-on-g1`, used by evmnet and verified by `EvmnetRegistry`.
+    Bn254UnchainedOnG1,
+}
+
+impl DrandScheme {
+    /// `schemeID` of the chain info.
+    pub fn id(&self) -> &'static str {
+        match self {
+            DrandScheme::UnchainedG1Rfc9380 => "bls-unchained-g1-rfc9380",
+            DrandScheme::Bn254UnchainedOnG1 => "bls-bn254-unchained-on-g1",
+        }
+    }
+
+    /// `DST` of the registry.
+    pub fn dst(&self) -> &'static str {
+        match self {
+            DrandScheme::UnchainedG1Rfc9380 => "BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_",
+            DrandScheme::Bn254UnchainedOnG1 => "BLS_SIG_BN254G1_XMD:KECCAK-256_SVDW_RO_NUL_",
+        }
+    }
+
+    /// The message signed for `round`, before hashing to G1.
+    pub fn message(&self, round: u64) -> [u8; 32] {
+        match self {
+            DrandScheme::UnchainedG1Rfc9380 => Sha256::digest(round.to_be_bytes()).into(),
+            DrandScheme::Bn254UnchainedOnG1 => Keccak256::digest(round.to_be_bytes()).into(),
+        }
+    }
+
+    /// Name of the registry contract verifying the beacons.
+    pub fn registry(&self) -> &'static str {
+        match self {
+            DrandScheme::UnchainedG1Rfc9380 => "QuicknetRegistry",
+            DrandScheme::Bn254UnchainedOnG1 => "EvmnetRegistry",
+        }
+    }
+
+    /// Address of the registry deployed with `script/Deploy*Registry.s.sol` through Arachnid's
+    /// deterministic deployment proxy, the same on every chain.
+    pub fn registry_address(&self) -> Address {
+        match self {
+            DrandScheme::UnchainedG1Rfc9380 => [
+                0xa5, 0x09, 0x70, 0xf4, 0x75, 0xf5, 0x30, 0xfa, 0x9a, 0x93, 0xe0, 0xc5, 0x5e, 0xba,
+                0xfe, 0xf5, 0x88, 0xb0, 0x7c, 0xe3,
+            ],
+            DrandScheme::Bn254UnchainedOnG1 => [
+                0xbf, 0x73, 0xdf, 0x94, 0xd5, 0xf3, 0xbf, 0x7b, 0x19, 0xe9, 0x6d, 0xeb, 0x61, 0x34,
+                0x34, 0xa9, 0xca, 0x10, 0x6c, 0x09,
+            ],
+        }
+    }
+
+    /// Length of the signatures, which `proveRound` takes as is: compressed on BLS12-381 and
+    /// marshalled with `BLS.g1Marshal` on BN254.
+    pub fn signature_length(&self) -> usize {
+        match self {
+            DrandScheme::UnchainedG1Rfc9380 => compressed::G1_LENGTH,
+            DrandScheme::Bn254UnchainedOnG1 => bn254::G1_LENGTH,
+        }
+    }
+}
+
+impl fmt::Display for DrandScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// Reasons a chain info or beacon is rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The JSON does not have the fields of the drand HTTP API.
+    Json(String),
+    /// The chain uses a scheme no registry verifies.
+    UnsupportedScheme(String),
+    /// A field is not hex encoded.
+    InvalidHex(&'static str),
+    /// The public key or signature is not a valid point encoding.
+    InvalidPoint(crate::Error),
+    /// The signature does not verify against the public key of the chain.
+    InvalidSignature,
+    /// The randomness of the beacon is not the sha256 of its signature.
+    RandomnessMismatch,
+    /// The chain hash is not the one of the other fields of the chain info.
+    ChainHashMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid JSON: {e}"),
+            Error::UnsupportedScheme(id) => write!(f, "unsupported drand scheme: {id}"),
+            Error::InvalidHex(field) => write!(f, "{field} is not hex encoded"),
+            Error::InvalidPoint(e) => write!(f, "invalid point: {e}"),
+            Error::InvalidSignature => write!(f, "invalid signature"),
+            Error::RandomnessMismatch => write!(f, "randomness is not sha256(signature)"),
+            Error::ChainHashMismatch => write!(f, "chain hash does not match the chain info"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Chain info, as served by `/v2/chains/<chain hash>/info` or `/v2/beacons/<beacon id>/info`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainInfo {
+    pub scheme: DrandScheme,
+    /// compressed G2 point on quicknet, `BLS.g2Marshal` on evmnet
+    pub public_key: Vec<u8>,
+    /// seconds between rounds
+    pub period: u64,
+    /// unix time of round 1
+    pub genesis_time: u64,
+    pub chain_hash: Vec<u8>,
+    pub beacon_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ChainInfoJson {
+    public_key: String,
+    period: u64,
+    genesis_time: u64,
+    hash: Option<String>,
+    chain_hash: Option<String>,
+    #[serde(rename = "groupHash", alias = "genesis_seed")]
+    genesis_seed: Option<String>,
+    #[serde(rename = "schemeID", alias = "scheme")]
+    scheme_id: String,
+    metadata: Option<MetadataJson>,
+    beacon_id: Option<String>,
+}
+
+/// Chain info of the v2 API.
+#[derive(Serialize)]
+struct ChainInfoV2Json<'a> {
+    public_key: String,
+    period: u64,
+    genesis_time: u64,
+    chain_hash: String,
+    scheme: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    beacon_id: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct MetadataJson {
+    #[serde(rename = "beaconID")]
+    beacon_id: Option<String>,
+}
+
+impl ChainInfo {
+    /// The drand mainnet quicknet chain, whose public key is `QuicknetRegistry.PUBLIC_KEY()`.
+    pub fn quicknet() -> Self {
+        Self {
+            scheme: DrandScheme::UnchainedG1Rfc9380,
+            public_key: hex_decode(
+                "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a",
+            ),
+            period: 3,
+            genesis_time: 1692803367,
+            chain_hash: hex_decode(
+                "52db9ba70e0cc0f6eaf7803dd07447a1f5477735fd3f661792ba94600c84e971",
+            ),
+            beacon_id: Some("quicknet".to_owned()),
+        }
+    }
+
+    /// The drand mainnet evmnet chain, whose public key is `EvmnetRegistry.PUBLIC_KEY()`.
+    pub fn evmnet() -> Self {
+        Self {
+            scheme: DrandScheme::Bn254UnchainedOnG1,
+            public_key: hex_decode(
+                "07e1d1d335df83fa98462005690372c643340060d205306a9aa8106b6bd0b3820557ec32c2ad488e4d4f6008f89a346f18492092ccc0d594610de2732c8b808f0095685ae3a85ba243747b1b2f426049010f6b73a0cf1d389351d5aaaa1047f6297d3a4f9749b33eb2d904c9d9ebf17224150ddd7abd7567a9bec6c74480ee0b",
+            ),
+            period: 3,
+            genesis_time: 1727521075,
+            chain_hash: hex_decode(
+                "04f1e9062b8a81f848fded9c12306733282b2727ecced50032187751166ec8c3",
+            ),
+            beacon_id: Some("evmnet".to_owned()),
+        }
+    }
+
+    /// Parses the chain info JSON of the drand HTTP API, checking the public key, and the chain
+    /// hash if the genesis seed it is computed from is given.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let info: ChainInfoJson =
+            serde_json::from_str(json).map_err(|e| Error::Json(e.to_string()))?;
+        let scheme = match info.scheme_id.as_str() {
+            "bls-unchained-g1-rfc9380" => DrandScheme::UnchainedG1Rfc9380,
+            "bls-bn254-unchained-on-g1" => DrandScheme::Bn254UnchainedOnG1,
+            id => return Err(Error::UnsupportedScheme(id.to_owned())),
+        };
+        if info.period == 0 {
+            return Err(Error::Json("period must not be zero".to_owned()));
+        }
+        // the v1 API calls the chain hash `hash`, the v2 API `chain_hash`, and likewise renames
+        // `groupHash` to `genesis_seed` and moves `metadata.beaconID` to `beacon_id`
+        let hash = info.chain_hash.or(info.hash).unwrap_or_default();
+        let genesis_seed = info
+            .genesis_seed
+            .map(|seed| hex::decode(seed).map_err(|_| Error::InvalidHex("genesis_seed")))
+            .transpose()?;
+        let info = Self {
+            scheme,
+            public_key: hex::decode(&info.public_key)
+                .map_err(|_| Error::InvalidHex("public_key"))?,
+            period: info.period,
+            genesis_time: info.genesis_time,
+            chain_hash: hex::decode(hash).map_err(|_| Error::InvalidHex("chain_hash"))?,
+            beacon_id: info.beacon_id.or(info.metadata.and_then(|m| m.beacon_id)),
+        };
+        info.check_public_key()?;
+        if genesis_seed.is_some_and(|seed| chain_hash(&info, &seed) != info.chain_hash) {
+            return Err(Error::ChainHashMismatch);
+        }
+        Ok(info)
+    }
+
+    /// The chain info JSON of the v2 API.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&ChainInfoV2Json {
+            public_key: hex::encode(&self.public_key),
+            period: self.period,
+            genesis_time: self.genesis_time,
+            chain_hash: hex::encode(&self.chain_hash),
+            scheme: self.scheme.id(),
+            beacon_id: self.beacon_id.as_deref(),
+        })
+        .expect("chain info serializes")
+    }
+
+    /// The round emitted last at unix time `time`, or 0 before the genesis.
+    pub fn round_at(&self, time: u64) -> u64 {
+        match time.checked_sub(self.genesis_time) {
+            Some(elapsed) => elapsed / self.period + 1,
+            None => 0,
+        }
+    }
+
+    /// Unix time at which `round` is emitted, or `None` past the range of `u64`. Round 0 does not
+    /// exist and is mapped to the genesis.
+    pub fn time_of_round(&self, round: u64) -> Option<u64> {
+        round
+            .saturating_sub(1)
+            .checked_mul(self.period)?
+            .checked_add(self.genesis_time)
+    }
+
+    /// The round following the one emitted at `time`, and its emission time, or `None` past the
+    /// range of `u64`.
+    pub fn next_round(&self, time: u64) -> Option<(u64, u64)> {
+        let round = self.round_at(time).checked_add(1)?;
+        Some((round, self.time_of_round(round)?))
+    }
+
+    /// Verifies `beacon` like `proveRound` of the registry of the chain would.
+    pub fn verify(&self, beacon: &Beacon) -> Result<(), Error> {
+        let message = self.scheme.message(beacon.round);
+        let dst = self.scheme.dst().as_bytes();
+        let valid = match self.scheme {
+            DrandScheme::UnchainedG1Rfc9380 => {
+                let pk = bls12_381::PublicKey::from_compressed_bytes(&self.public_key)
+                    .map_err(Error::InvalidPoint)?;
+                // canonical encodings in the subgroup, the only ones the pairing precompile accepts
+                let sig =
+                    compressed::g1_decompress(&beacon.signature).map_err(Error::InvalidPoint)?;
+                pk.verify(dst, &message, &bls12_381::Signature(sig))
+            }
+            DrandScheme::Bn254UnchainedOnG1 => {
+                let pk =
+                    bn254::PublicKey::from_bytes(&self.public_key).map_err(Error::InvalidPoint)?;
+                let sig =
+                    bn254::Signature::from_bytes(&beacon.signature).map_err(Error::InvalidPoint)?;
+                pk.verify(dst, &message, &sig)
+            }
+        };
+        if !valid {
+            return Err(Error::InvalidSignature);
+        }
+        if beacon
+            .randomness
+            .as_ref()
+            .is_some_and(|r| r[..] != beacon.expected_randomness())
+        {
+            return Err(Error::RandomnessMismatch);
+        }
+        Ok(())
+    }
+
+    fn check_public_key(&self) -> Result<(), Error> {
+        match self.scheme {
+            DrandScheme::UnchainedG1Rfc9380 => {
+                bls12_381::PublicKey::from_compressed_bytes(&self.public_key).map(drop)
+            }
+            DrandScheme::Bn254UnchainedOnG1 => {
+                bn254::PublicKey::from_bytes(&self.public_key).map(drop)
+            }
+        }
+        .map_err(Error::InvalidPoint)
+    }
+}
+
+/// A beacon, as served by `/v2/beacons/<beacon id>/rounds/<round>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Beacon {
+    pub round: u64,
+    pub signature: Vec<u8>,
+    /// only served by the v1 API
+    pub randomness: Option<Vec<u8>>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct BeaconJson {
+    round: u64,
+    signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    randomness: Option<String>,
+}
+
+impl Beacon {
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let beacon: BeaconJson =
+            serde_json::from_str(json).map_err(|e| Error::Json(e.to_string()))?;
+        Ok(Self {
+            round: beacon.round,
+            signature: hex::decode(&beacon.signature)
+                .map_err(|_| Error::InvalidHex("signature"))?,
+            randomness: beacon
+                .randomness
+                .map(|r| hex::decode(r).map_err(|_| Error::InvalidHex("randomness")))
+                .transpose()?,
+        })
+    }
+
+    /// The beacon JSON of the drand HTTP API, with `randomness` only if it is set like in v1.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&BeaconJson {
+            round: self.round,
+            signature: hex::encode(&self.signature),
+            randomness: self.randomness.as_ref().map(hex::encode),
+        })
+        .expect("beacon serializes")
+    }
+
+    /// Calldata of `proveRound(signature, round)` on the registry.
+    pub fn prove_round_calldata(&self) -> Vec<u8> {
+        abi::encode_call(
+            "proveRound(bytes,uint64)",
+            &[
+                Token::Bytes(&self.signature),
+                Token::Uint(abi::uint(self.round)),
+            ],
+        )
+    }
+
+    /// `sha256(signature)`, stored by the registries as `roundRandomness(round)`.
+    pub fn expected_randomness(&self) -> [u8; 32] {
+        Sha256::digest(&self.signature).into()
+    }
+}
+
+/// A chain signed with a local key: a stand-in for a drand network that the registries verify once
+/// deployed with its public key.
+#[derive(Clone, Debug)]
+pub struct TestChain {
+    pub info: ChainInfo,
+    secret_key: TestSecretKey,
+}
+
+#[derive(Clone, Debug)]
+enum TestSecretKey {
+    Bls12381(bls12_381::SecretKey),
+    Bn254(bn254::SecretKey),
+}
+
+impl TestChain {
+    /// A chain of `scheme` whose secret key is `sha256(seed || schemeID)`, so that one seed gives
+    /// distinct keys to each scheme.
+    pub fn new(
+        scheme: DrandScheme,
+        seed: &[u8],
+        period: u64,
+        genesis_time: u64,
+        beacon_id: Option<String>,
+    ) -> Self {
+        assert!(period > 0, "period must not be zero");
+        let scalar = Sha256::new()
+            .chain_update(seed)
+            .chain_update(scheme.id())
+            .finalize();
+        let (secret_key, public_key) = match scheme {
+            DrandScheme::UnchainedG1Rfc9380 => {
+                let sk = bls12_381::SecretKey::from_be_bytes_mod_order(&scalar);
+                let pk = sk.public_key().to_compressed_bytes().to_vec();
+                (TestSecretKey::Bls12381(sk), pk)
+            }
+            DrandScheme::Bn254UnchainedOnG1 => {
+                let sk = bn254::SecretKey::from_be_bytes_mod_order(&scalar);
+                let pk = sk.public_key().to_bytes().to_vec();
+                (TestSecretKey::Bn254(sk), pk)
+            }
+        };
+        let mut info = ChainInfo {
+            scheme,
+            public_key,
+            period,
+            genesis_time,
+            chain_hash: Vec::new(),
+            beacon_id,
+        };
+        info.chain_hash = chain_hash(&info, &[]);
+        Self { info, secret_key }
+    }
+
+    /// The beacon of `round`.
+    pub fn beacon(&self, round: u64) -> Beacon {
+        let message = self.info.scheme.message(round);
+        let dst = self.info.scheme.dst().as_bytes();
+        let signature = match &self.secret_key {
+            TestSecretKey::Bls12381(sk) => sk.sign(dst, &message).to_compressed_bytes().to_vec(),
+            TestSecretKey::Bn254(sk) => sk.sign(dst, &message).to_bytes().to_vec(),
+        };
+        Beacon {
+            round,
+            signature,
+            randomness: None,
+        }
+    }
+}
+
+/// The chain hash the way drand computes it:
+/// `sha256(period as u32 || genesis time as i64 || public key || genesis seed || beacon id)`.
+/// Test chains have no group file, and so an empty genesis seed.
+fn chain_hash(info: &ChainInfo, genesis_seed: &[u8]) -> Vec<u8> {
+    let mut hash = Sha256::new()
+        .chain_update((info.period as u32).to_be_bytes())
+        .chain_update((info.genesis_time as i64).to_be_bytes())
+        .chain_update(&info.public_key)
+        .chain_update(genesis_seed);
+    // drand leaves out the default beacon id
+    if let Some(id) = info.beacon_id.as_deref().filter(|id| *id != "default") {
+        hash.update(id);
+    }
+    hash.finalize().to_vec()
+}
+
+fn hex_decode(s: &str) -> Vec<u8> {
+    hex::decode(s).expect("valid hex constant")
+}
@@ -0,0 +1,81 @@
+//! Domain separation tags of the signature scheme contracts.
+//!
+//! `BN254SignatureScheme`, `BLS12381SignatureScheme` and `BLS12381CompressedSignatureScheme` set
+//! their `DST` in the constructor to
+//! `application || "-" || h2c suffix || "_" || toHexString(bytes32(block.chainid)) || "_"`,
+//! e.g. `dcipher-helloworld-v01-BN254G1_XMD:KECCAK-256_SVDW_RO_0x00..7a69_`.
+
+use crate::{Error, Scheme};
+
+use std::fmt;
+
+/// Length of `BytesLib.toHexString(bytes32)`: "0x" and 64 lowercase hex digits.
+const HEX_CHAIN_ID_LENGTH: usize = 2 + 64;
+
+/// The DST a signature scheme contract deployed for `application` on `chain_id` exposes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Dst {
+    pub scheme: Scheme,
+    pub application: String,
+    pub chain_id: u64,
+}
+
+impl Dst {
+    pub fn new(scheme: Scheme, application: impl Into<String>, chain_id: u64) -> Self {
+        Self {
+            scheme,
+            application: application.into(),
+            chain_id,
+        }
+    }
+
+    /// Bytes returned by `DST()`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+
+    /// Splits a DST of a `scheme` contract back into its application and chain id.
+    ///
+    /// Both BLS12-381 schemes use the same DST, so the scheme cannot be derived from it.
+    pub fn parse(scheme: Scheme, dst: &[u8]) -> Result<Self, Error> {
+        let dst = std::str::from_utf8(dst).map_err(|_| Error::InvalidDst)?;
+        let rest = dst.strip_suffix('_').ok_or(Error::InvalidDst)?;
+        let split = rest
+            .len()
+            .checked_sub(HEX_CHAIN_ID_LENGTH)
+            .filter(|i| rest.is_char_boundary(*i))
+            .ok_or(Error::InvalidDst)?;
+        let (rest, hex_chain_id) = rest.split_at(split);
+        let application = rest
+            .strip_suffix(&format!("-{}_", scheme.h2c_suffix()))
+            .ok_or(Error::InvalidDst)?;
+
+        let digits = hex_chain_id.strip_prefix("0x").ok_or(Error::InvalidDst)?;
+        if !digits
+            .bytes()
+            .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        {
+            return Err(Error::InvalidDst);
+        }
+        // chain ids beyond u64 do not exist in practice, see EIP-2294
+        let (high, low) = digits.split_at(64 - 16);
+        if high.bytes().any(|b| b != b'0') {
+            return Err(Error::InvalidDst);
+        }
+        let chain_id = u64::from_str_radix(low, 16).map_err(|_| Error::InvalidDst)?;
+
+        Ok(Self::new(scheme, application, chain_id))
+    }
+}
+
+impl fmt::Display for Dst {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}_0x{:064x}_",
+            self.application,
+            self.scheme.h2c_suffix(),
+            self.chain_id
+        )
+    }
+}
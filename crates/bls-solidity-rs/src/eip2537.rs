@@ -0,0 +1,103 @@
+//! Encodings of the EIP-2537 BLS12-381 precompiles.
+//!
+//! Field elements are 64 bytes big-endian, the 16 most significant bytes being zero. G1 points are
+//! x || y (128 bytes) and G2 points x.c0 || x.c1 || y.c0 || y.c1 (256 bytes): unlike
+//! `BLS2.g2Marshal`, the coefficient of degree 0 comes first. The point at infinity is all zeros.
+//!
+//! Points are decoded like the MSM and pairing precompiles do: they must be on the curve and in the
+//! prime order subgroup.
+
+use crate::Error;
+use crate::error::check_length;
+use crate::point::{coordinates, fq_from_be_bytes, fq_to_be_bytes, from_coordinates};
+
+use ark_bls12_381::{Fq, Fq2, G1Affine, G2Affine};
+
+/// Address of the G1 point addition precompile, `BLS12_G1ADD` in `Precompiles.sol`.
+pub const G1ADD: u8 = 0x0b;
+/// Address of the G1 multi-scalar multiplication precompile.
+pub const G1MSM: u8 = 0x0c;
+/// Address of the G2 point addition precompile.
+pub const G2ADD: u8 = 0x0d;
+/// Address of the G2 multi-scalar multiplication precompile.
+pub const G2MSM: u8 = 0x0e;
+/// Address of the pairing check precompile, `BLS12_PAIRING_CHECK` in `Precompiles.sol`.
+pub const PAIRING_CHECK: u8 = 0x0f;
+/// Address of the base field element to G1 precompile, `BLS12_MAP_FP_TO_G1` in `Precompiles.sol`.
+pub const MAP_FP_TO_G1: u8 = 0x10;
+/// Address of the quadratic extension field element to G2 precompile.
+pub const MAP_FP2_TO_G2: u8 = 0x11;
+
+pub const FP_LENGTH: usize = 64;
+pub const FP2_LENGTH: usize = 2 * FP_LENGTH;
+pub const G1_LENGTH: usize = 2 * FP_LENGTH;
+pub const G2_LENGTH: usize = 2 * FP2_LENGTH;
+
+/// Bytes of padding before each 48 bytes field element.
+const PADDING: usize = FP_LENGTH - 48;
+
+pub fn fp_encode(f: &Fq) -> [u8; FP_LENGTH] {
+    let mut bytes = [0u8; FP_LENGTH];
+    bytes[PADDING..].copy_from_slice(&fq_to_be_bytes(*f));
+    bytes
+}
+
+/// Reads a padded field element, rejecting non-zero padding and values not smaller than p.
+pub fn fp_decode(bytes: &[u8]) -> Result<Fq, Error> {
+    check_length(bytes, FP_LENGTH)?;
+    let (padding, f) = bytes.split_at(PADDING);
+    if padding.iter().any(|b| *b != 0) {
+        return Err(Error::NonZeroPadding);
+    }
+    fq_from_be_bytes(f)
+}
+
+/// c0 || c1.
+pub fn fp2_encode(f: &Fq2) -> [u8; FP2_LENGTH] {
+    let mut bytes = [0u8; FP2_LENGTH];
+    bytes[..FP_LENGTH].copy_from_slice(&fp_encode(&f.c0));
+    bytes[FP_LENGTH..].copy_from_slice(&fp_encode(&f.c1));
+    bytes
+}
+
+pub fn fp2_decode(bytes: &[u8]) -> Result<Fq2, Error> {
+    check_length(bytes, FP2_LENGTH)?;
+    let (c0, c1) = bytes.split_at(FP_LENGTH);
+    Ok(Fq2::new(fp_decode(c0)?, fp_decode(c1)?))
+}
+
+pub fn g1_encode(p: &G1Affine) -> [u8; G1_LENGTH] {
+    let (x, y) = coordinates(p);
+    let mut bytes = [0u8; G1_LENGTH];
+    bytes[..FP_LENGTH].copy_from_slice(&fp_encode(&x));
+    bytes[FP_LENGTH..].copy_from_slice(&fp_encode(&y));
+    bytes
+}
+
+pub fn g1_decode(bytes: &[u8]) -> Result<G1Affine, Error> {
+    check_length(bytes, G1_LENGTH)?;
+    let (x, y) = bytes.split_at(FP_LENGTH);
+    from_coordinates(fp_decode(x)?, fp_decode(y)?)
+}
+
+pub fn g2_encode(p: &G2Affine) -> [u8; G2_LENGTH] {
+    let (x, y) = coordinates(p);
+    let mut bytes = [0u8; G2_LENGTH];
+    bytes[..FP2_LENGTH].copy_from_slice(&fp2_encode(&x));
+    bytes[FP2_LENGTH..].copy_from_slice(&fp2_encode(&y));
+    bytes
+}
+
+pub fn g2_decode(bytes: &[u8]) -> Result<G2Affine, Error> {
+    check_length(bytes, G2_LENGTH)?;
+    let (x, y) = bytes.split_at(FP2_LENGTH);
+    from_coordinates(fp2_decode(x)?, fp2_decode(y)?)
+}
+
+/// Input of the pairing check precompile, as built by `BLS2.verifySingle`.
+pub fn pairing_check_input(pairs: &[(G1Affine, G2Affine)]) -> Vec<u8> {
+    pairs
+        .iter()
+        .flat_map(|(p, q)| [&g1_encode(p)[..], &g2_encode(q)[..]].concat())
+        .collect()
+}
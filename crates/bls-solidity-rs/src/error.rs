@@ -0,0 +1,102 @@
+use std::fmt;
+
+/// Reasons an encoded point is rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The encoding does not have the expected number of bytes.
+    InvalidLength { expected: usize, actual: usize },
+    /// A coordinate is not smaller than the field modulus.
+    NonCanonicalFieldElement,
+    /// The 16 padding bytes of an EIP-2537 field element are not zero.
+    NonZeroPadding,
+    /// The coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// The point is on the curve but not in the prime order subgroup.
+    NotInSubgroup,
+    /// The compression flag (0x80) of a compressed point is not set.
+    MissingCompressionFlag,
+    /// The encoding is the point at infinity, which `BLS2.g1UnmarshalCompressed` does not support.
+    InfinityUnsupported,
+    /// The compressed encoding has invalid flags, or its x-coordinate is not on the curve.
+    InvalidCompressedPoint,
+    /// The `SCHEME_ID` is not one of the signature scheme contracts.
+    UnknownScheme(String),
+    /// The curve is not `bn254` or `bls12-381`.
+    UnknownCurve(String),
+    /// The public key format is not one of `pubkey::Format`.
+    UnknownFormat(String),
+    /// The public key format does not exist on the curve, e.g. compressed BN254 points.
+    UnsupportedFormat {
+        curve: &'static str,
+        format: &'static str,
+    },
+    /// The DST was not built like the constructors of the signature scheme contracts.
+    InvalidDst,
+    /// The bytes are not an arkworks serialization of a point: a coordinate is not canonical, or
+    /// the flags are set or not those arkworks writes.
+    InvalidArkworksEncoding,
+    /// An ABI word does not fit in the type of its field, e.g. a `uint128` limb.
+    InvalidAbiEncoding,
+    /// No precompile of `Precompiles.sol` is emulated at this address.
+    UnknownPrecompile(u8),
+    /// The input of a pairing precompile is not a whole number of (G1, G2) pairs.
+    InvalidPairingLength { pair_length: usize, actual: usize },
+    /// A MODEXP operand is longer than the 1024 bytes allowed since EIP-7823.
+    ModExpLengthTooLarge,
+    /// The exponentiation is not one of `addchain::Exponentiation::NAMES`.
+    UnknownExponentiation(String),
+    /// The modulus is over 384 bits, the `(uint128, uint256)` field elements of the libraries
+    /// emitted for larger moduli than `uint256`.
+    ModulusTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            Error::NonCanonicalFieldElement => write!(f, "field element is not canonical"),
+            Error::NonZeroPadding => write!(f, "field element padding is not zero"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::NotInSubgroup => write!(f, "point is not in the prime order subgroup"),
+            Error::MissingCompressionFlag => write!(f, "compression flag is not set"),
+            Error::InfinityUnsupported => write!(f, "point at infinity is not supported"),
+            Error::InvalidCompressedPoint => write!(f, "invalid compressed point"),
+            Error::UnknownScheme(id) => write!(f, "unknown scheme: {id}"),
+            Error::UnknownCurve(id) => write!(f, "unknown curve: {id}"),
+            Error::UnknownFormat(name) => write!(f, "unknown public key format: {name}"),
+            Error::UnsupportedFormat { curve, format } => {
+                write!(f, "{curve} public keys have no {format} format")
+            }
+            Error::InvalidDst => write!(f, "invalid DST"),
+            Error::InvalidArkworksEncoding => write!(f, "invalid arkworks encoding"),
+            Error::InvalidAbiEncoding => write!(f, "invalid ABI encoding"),
+            Error::UnknownPrecompile(address) => write!(f, "unknown precompile: 0x{address:02x}"),
+            Error::InvalidPairingLength {
+                pair_length,
+                actual,
+            } => write!(
+                f,
+                "invalid pairing input: {actual} bytes is not a multiple of {pair_length}"
+            ),
+            Error::ModExpLengthTooLarge => {
+                write!(f, "MODEXP operand is longer than 1024 bytes")
+            }
+            Error::UnknownExponentiation(name) => write!(f, "unknown exponentiation: {name}"),
+            Error::ModulusTooLarge => write!(f, "modulus does not fit in 384 bits"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub(crate) fn check_length(bytes: &[u8], expected: usize) -> Result<(), Error> {
+    if bytes.len() != expected {
+        return Err(Error::InvalidLength {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok(())
+}
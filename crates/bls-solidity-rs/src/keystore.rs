@@ -0,0 +1,418 @@
+//! Password encrypted secret keys, in the EIP-2335 keystore format.
+//!
+//! The secret is the 32 bytes big-endian scalar, derived with scrypt or PBKDF2-HMAC-SHA256 and
+//! encrypted with AES-128-CTR exactly like EIP-2335, so that the reference test vectors decrypt.
+//! Two fields differ: `curve` tells BN254 keys from BLS12-381 ones, and `pubkey` is the public key
+//! on G2 marshalled like `BLS.g2Marshal`/`BLS2.g2Marshal`, as taken by the signature schemes.
+//!
+//! ```ignore
+//! use bls_solidity_rs::Curve;
+//! use bls_solidity_rs::keystore::{Kdf, Keystore};
+//!
+//! let keystore = Keystore::encrypt(Curve::Bn254, &secret, password, Kdf::scrypt(&mut rng), "", &mut rng)?;
+//! std::fs::write(path, keystore.to_json())?;
+//! let sk = Keystore::from_json(&std::fs::read_to_string(path)?)?.unlock_bn254(password)?;
+//! ```
+
+use crate::{Curve, bls12_381, bn254};
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use ark_ff::{BigInteger, PrimeField};
+use rand::RngCore;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+
+use std::fmt;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+pub const VERSION: u32 = 4;
+pub const SECRET_LENGTH: usize = 32;
+
+const DKLEN: usize = 32;
+const SALT_LENGTH: usize = 32;
+const IV_LENGTH: usize = 16;
+
+/// `g2Marshal` of the public key of a canonical `secret`, or `None` if the scalar is zero or not
+/// smaller than the group order.
+pub fn public_key(curve: Curve, secret: &[u8; SECRET_LENGTH]) -> Option<Vec<u8>> {
+    match curve {
+        Curve::Bn254 => {
+            let scalar = ark_bn254::Fr::from_be_bytes_mod_order(secret);
+            canonical(secret, &scalar.into_bigint().to_bytes_be())?;
+            Some(
+                bn254::SecretKey::new(scalar)
+                    .public_key()
+                    .to_bytes()
+                    .to_vec(),
+            )
+        }
+        Curve::Bls12381 => {
+            let scalar = ark_bls12_381::Fr::from_be_bytes_mod_order(secret);
+            canonical(secret, &scalar.into_bigint().to_bytes_be())?;
+            Some(
+                bls12_381::SecretKey::new(scalar)
+                    .public_key()
+                    .to_bytes()
+                    .to_vec(),
+            )
+        }
+    }
+}
+
+fn canonical(secret: &[u8], reduced: &[u8]) -> Option<()> {
+    let nonzero = secret.iter().any(|b| *b != 0);
+    (nonzero && secret == reduced).then_some(())
+}
+
+/// Key derivation function turning the password into the decryption key and checksum key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Kdf {
+    Scrypt {
+        n: u32,
+        r: u32,
+        p: u32,
+        salt: Vec<u8>,
+    },
+    /// PBKDF2 with HMAC-SHA256, the only PRF of EIP-2335.
+    Pbkdf2 { c: u32, salt: Vec<u8> },
+}
+
+impl Kdf {
+    /// scrypt with the parameters of EIP-2335, n = 2^18, r = 8 and p = 1.
+    pub fn scrypt(rng: &mut impl RngCore) -> Self {
+        Kdf::Scrypt {
+            n: 1 << 18,
+            r: 8,
+            p: 1,
+            salt: random_bytes(rng, SALT_LENGTH),
+        }
+    }
+
+    /// PBKDF2 with the 2^18 iterations of EIP-2335.
+    pub fn pbkdf2(rng: &mut impl RngCore) -> Self {
+        Kdf::Pbkdf2 {
+            c: 1 << 18,
+            salt: random_bytes(rng, SALT_LENGTH),
+        }
+    }
+
+    fn derive(&self, password: &[u8]) -> Result<[u8; DKLEN], Error> {
+        let mut key = [0u8; DKLEN];
+        match self {
+            Kdf::Scrypt { n, r, p, salt } => {
+                if !n.is_power_of_two() || *n < 2 {
+                    return Err(Error::InvalidKdfParams);
+                }
+                let params = scrypt::Params::new(n.trailing_zeros() as u8, *r, *p, DKLEN)
+                    .map_err(|_| Error::InvalidKdfParams)?;
+                scrypt::scrypt(password, salt, &params, &mut key)
+                    .map_err(|_| Error::InvalidKdfParams)?;
+            }
+            Kdf::Pbkdf2 { c, salt } => {
+                pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, *c, &mut key);
+            }
+        }
+        Ok(key)
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            Kdf::Scrypt { n, r, p, salt } => json!({
+                "function": "scrypt",
+                "params": {"dklen": DKLEN, "n": n, "r": r, "p": p, "salt": hex::encode(salt)},
+                "message": "",
+            }),
+            Kdf::Pbkdf2 { c, salt } => json!({
+                "function": "pbkdf2",
+                "params": {"dklen": DKLEN, "c": c, "prf": "hmac-sha256", "salt": hex::encode(salt)},
+                "message": "",
+            }),
+        }
+    }
+
+    fn from_json(module: &ModuleJson) -> Result<Self, Error> {
+        let params = &module.params;
+        let uint = |name: &str| {
+            params[name]
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or(Error::InvalidKdfParams)
+        };
+        let salt = hex::decode(params["salt"].as_str().ok_or(Error::InvalidKdfParams)?)
+            .map_err(|_| Error::InvalidHex("salt"))?;
+        if uint("dklen")? as usize != DKLEN {
+            return Err(Error::InvalidKdfParams);
+        }
+        match module.function.as_str() {
+            "scrypt" => Ok(Kdf::Scrypt {
+                n: uint("n")?,
+                r: uint("r")?,
+                p: uint("p")?,
+                salt,
+            }),
+            "pbkdf2" if params["prf"] == "hmac-sha256" => Ok(Kdf::Pbkdf2 {
+                c: uint("c")?,
+                salt,
+            }),
+            "pbkdf2" => Err(Error::InvalidKdfParams),
+            function => Err(Error::Unsupported(function.to_owned())),
+        }
+    }
+}
+
+/// Reasons a keystore cannot be read, created or unlocked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The JSON does not have the fields of a keystore.
+    Json(String),
+    /// The keystore uses a version, KDF, checksum or cipher function other than EIP-2335's.
+    Unsupported(String),
+    /// A field is not hex encoded.
+    InvalidHex(&'static str),
+    /// The KDF parameters are out of range, or not those of EIP-2335.
+    InvalidKdfParams,
+    /// The checksum does not match: the password is wrong or the keystore was modified.
+    InvalidPassword,
+    /// The secret is zero or not smaller than the group order.
+    InvalidSecretKey,
+    /// The keystore holds a key of another curve.
+    CurveMismatch { expected: Curve, actual: Curve },
+    /// `pubkey` is not the public key of the secret.
+    PublicKeyMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid JSON: {e}"),
+            Error::Unsupported(what) => write!(f, "unsupported keystore: {what}"),
+            Error::InvalidHex(field) => write!(f, "{field} is not hex encoded"),
+            Error::InvalidKdfParams => write!(f, "invalid KDF parameters"),
+            Error::InvalidPassword => write!(f, "invalid password"),
+            Error::InvalidSecretKey => write!(f, "invalid secret key"),
+            Error::CurveMismatch { expected, actual } => {
+                write!(f, "expected a {expected} key, got a {actual} key")
+            }
+            Error::PublicKeyMismatch => write!(f, "pubkey does not match the secret key"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// An encrypted secret key with its public key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keystore {
+    pub curve: Curve,
+    /// `g2Marshal` of the public key, readable without the password
+    pub pubkey: Vec<u8>,
+    /// EIP-2334 derivation path, empty for keys that are not derived
+    pub path: String,
+    pub description: String,
+    pub uuid: String,
+    kdf: Kdf,
+    checksum: [u8; 32],
+    iv: [u8; IV_LENGTH],
+    ciphertext: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct KeystoreJson {
+    crypto: CryptoJson,
+    #[serde(default)]
+    description: String,
+    pubkey: String,
+    #[serde(default)]
+    path: String,
+    uuid: String,
+    version: u32,
+    curve: String,
+}
+
+#[derive(Deserialize)]
+struct CryptoJson {
+    kdf: ModuleJson,
+    checksum: ModuleJson,
+    cipher: ModuleJson,
+}
+
+#[derive(Deserialize)]
+struct ModuleJson {
+    function: String,
+    params: Value,
+    message: String,
+}
+
+impl Keystore {
+    /// Encrypts the big-endian `secret` scalar of `curve` with `password`.
+    pub fn encrypt(
+        curve: Curve,
+        secret: &[u8; SECRET_LENGTH],
+        password: &str,
+        kdf: Kdf,
+        path: &str,
+        rng: &mut impl RngCore,
+    ) -> Result<Self, Error> {
+        let pubkey = public_key(curve, secret).ok_or(Error::InvalidSecretKey)?;
+        let key = kdf.derive(&normalize_password(password))?;
+        let iv: [u8; IV_LENGTH] = random_bytes(rng, IV_LENGTH).try_into().unwrap();
+        let mut ciphertext = secret.to_vec();
+        Aes128Ctr::new((&key[..16]).into(), (&iv).into()).apply_keystream(&mut ciphertext);
+        Ok(Self {
+            curve,
+            pubkey,
+            path: path.to_owned(),
+            description: String::new(),
+            uuid: uuid_v4(rng),
+            checksum: checksum(&key, &ciphertext),
+            kdf,
+            iv,
+            ciphertext,
+        })
+    }
+
+    /// Decrypts the big-endian secret scalar, checking the password against the checksum.
+    pub fn decrypt(&self, password: &str) -> Result<[u8; SECRET_LENGTH], Error> {
+        let key = self.kdf.derive(&normalize_password(password))?;
+        if checksum(&key, &self.ciphertext) != self.checksum {
+            return Err(Error::InvalidPassword);
+        }
+        let mut secret = self.ciphertext.clone();
+        Aes128Ctr::new((&key[..16]).into(), (&self.iv).into()).apply_keystream(&mut secret);
+        secret.try_into().map_err(|_| Error::InvalidSecretKey)
+    }
+
+    /// Decrypts a BN254 secret key whose public key is `pubkey`.
+    pub fn unlock_bn254(&self, password: &str) -> Result<bn254::SecretKey, Error> {
+        let secret = self.unlock(Curve::Bn254, password)?;
+        Ok(bn254::SecretKey::from_be_bytes_mod_order(&secret))
+    }
+
+    /// Decrypts a BLS12-381 secret key whose public key is `pubkey`.
+    pub fn unlock_bls12_381(&self, password: &str) -> Result<bls12_381::SecretKey, Error> {
+        let secret = self.unlock(Curve::Bls12381, password)?;
+        Ok(bls12_381::SecretKey::from_be_bytes_mod_order(&secret))
+    }
+
+    /// Decrypts the secret of a `curve` key, checking that it matches `pubkey`.
+    pub fn unlock(&self, curve: Curve, password: &str) -> Result<[u8; SECRET_LENGTH], Error> {
+        if self.curve != curve {
+            return Err(Error::CurveMismatch {
+                expected: curve,
+                actual: self.curve,
+            });
+        }
+        let secret = self.decrypt(password)?;
+        match public_key(curve, &secret) {
+            Some(pubkey) if pubkey == self.pubkey => Ok(secret),
+            Some(_) => Err(Error::PublicKeyMismatch),
+            None => Err(Error::InvalidSecretKey),
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let keystore: KeystoreJson =
+            serde_json::from_str(json).map_err(|e| Error::Json(e.to_string()))?;
+        if keystore.version != VERSION {
+            return Err(Error::Unsupported(format!("version {}", keystore.version)));
+        }
+        let curve: Curve = keystore
+            .curve
+            .parse()
+            .map_err(|_| Error::Unsupported(format!("curve {}", keystore.curve)))?;
+        let crypto = keystore.crypto;
+        if crypto.checksum.function != "sha256" {
+            return Err(Error::Unsupported(crypto.checksum.function));
+        }
+        if crypto.cipher.function != "aes-128-ctr" {
+            return Err(Error::Unsupported(crypto.cipher.function));
+        }
+        let iv = crypto.cipher.params["iv"].as_str().unwrap_or_default();
+        Ok(Self {
+            curve,
+            pubkey: hex::decode(&keystore.pubkey).map_err(|_| Error::InvalidHex("pubkey"))?,
+            path: keystore.path,
+            description: keystore.description,
+            uuid: keystore.uuid,
+            kdf: Kdf::from_json(&crypto.kdf)?,
+            checksum: hex::decode(&crypto.checksum.message)
+                .ok()
+                .and_then(|c| c.try_into().ok())
+                .ok_or(Error::InvalidHex("checksum"))?,
+            iv: hex::decode(iv)
+                .ok()
+                .and_then(|iv| iv.try_into().ok())
+                .ok_or(Error::InvalidHex("iv"))?,
+            ciphertext: hex::decode(&crypto.cipher.message)
+                .map_err(|_| Error::InvalidHex("cipher message"))?,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        let keystore = json!({
+            "crypto": {
+                "kdf": self.kdf.to_json(),
+                "checksum": {
+                    "function": "sha256",
+                    "params": {},
+                    "message": hex::encode(self.checksum),
+                },
+                "cipher": {
+                    "function": "aes-128-ctr",
+                    "params": {"iv": hex::encode(self.iv)},
+                    "message": hex::encode(&self.ciphertext),
+                },
+            },
+            "description": self.description,
+            "pubkey": hex::encode(&self.pubkey),
+            "path": self.path,
+            "uuid": self.uuid,
+            "version": VERSION,
+            "curve": self.curve.id(),
+        });
+        serde_json::to_string_pretty(&keystore).expect("keystore serializes")
+    }
+}
+
+/// NFKD normalization without the C0, C1 and Delete control codes, as EIP-2335 requires.
+fn normalize_password(password: &str) -> Vec<u8> {
+    password
+        .nfkd()
+        .filter(|c| !matches!(*c as u32, 0x00..=0x1f | 0x7f..=0x9f))
+        .collect::<String>()
+        .into_bytes()
+}
+
+/// `sha256(DK[16..32] || ciphertext)`.
+fn checksum(key: &[u8; DKLEN], ciphertext: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(&key[16..])
+        .chain_update(ciphertext)
+        .finalize()
+        .into()
+}
+
+fn random_bytes(rng: &mut impl RngCore, length: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; length];
+    rng.fill_bytes(&mut bytes);
+    bytes
+}
+
+fn uuid_v4(rng: &mut impl RngCore) -> String {
+    let mut bytes = [0u8; 16];
+    rng.fill_bytes(&mut bytes);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
@@ -0,0 +1,37 @@
+//! Keys, signatures and point encodings compatible with the `BLS.sol` (BN254) and `BLS2.sol`
+//! (BLS12-381) libraries.
+//!
+//! Signatures live on G1 and public keys on G2, as expected by the signature schemes and
+//! `BLS.verifySingle`/`BLS2.verifySingle`. Messages are hashed to G1 with `hashToPoint`.
+//!
+//! ```ignore
+//! use bls_solidity_rs::bn254::SecretKey;
+//!
+//! let sk = SecretKey::from_be_bytes_mod_order(&secret);
+//! let sig = sk.sign(dst, message);
+//! assert!(sk.public_key().verify(dst, message, &sig));
+//! let calldata_sig = sig.to_bytes(); // BLS.g1Marshal
+//! ```
+
+pub mod abi;
+pub mod addchain;
+pub mod bls12_381;
+pub mod bn254;
+pub mod compressed;
+pub mod dkg;
+pub mod drand;
+pub mod dst;
+pub mod eip2537;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod precompiles;
+pub mod pubkey;
+pub mod sol;
+pub mod upgrade;
+
+mod error;
+mod point;
+mod scheme;
+
+pub use error::Error;
+pub use scheme::{Curve, Scheme};
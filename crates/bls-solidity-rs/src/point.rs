@@ -0,0 +1,46 @@
+//! Helpers shared by the BN254 and BLS12-381 encodings.
+
+use crate::Error;
+
+use ark_ec::AffineRepr;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::{BigInteger, PrimeField, Zero};
+
+/// Big-endian bytes of a field element, as many as the modulus needs words.
+pub(crate) fn fq_to_be_bytes<F: PrimeField>(f: F) -> Vec<u8> {
+    f.into_bigint().to_bytes_be()
+}
+
+/// Reads a big-endian field element, rejecting values not smaller than the modulus.
+pub(crate) fn fq_from_be_bytes<F: PrimeField>(bytes: &[u8]) -> Result<F, Error> {
+    let f = F::from_be_bytes_mod_order(bytes);
+    if f.into_bigint().to_bytes_be() != bytes {
+        return Err(Error::NonCanonicalFieldElement);
+    }
+    Ok(f)
+}
+
+/// Coordinates of `p`, with the point at infinity as (0, 0) like the EVM precompiles.
+pub(crate) fn coordinates<P: SWCurveConfig>(p: &Affine<P>) -> (P::BaseField, P::BaseField) {
+    p.xy()
+        .unwrap_or((P::BaseField::zero(), P::BaseField::zero()))
+}
+
+/// Point with coordinates (x, y), checked to be in the prime order subgroup.
+/// (0, 0) is never on the curves of these libraries, so it is read as the point at infinity.
+pub(crate) fn from_coordinates<P: SWCurveConfig>(
+    x: P::BaseField,
+    y: P::BaseField,
+) -> Result<Affine<P>, Error> {
+    if x.is_zero() && y.is_zero() {
+        return Ok(Affine::identity());
+    }
+    let p = Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::NotOnCurve);
+    }
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(p)
+}
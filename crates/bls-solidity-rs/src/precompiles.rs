@@ -0,0 +1,197 @@
+//! The precompiles of `Precompiles.sol`, emulated byte for byte.
+//!
+//! Each function takes the input of the `staticcall` and returns the output of a successful call,
+//! or why the call fails. A failing call consumes all the gas it was given and returns nothing: the
+//! libraries only see `success == false`, the error says which check failed. Input lengths follow
+//! the precompiles: MODEXP, ECADD and ECMUL pad a short input with zeros and ignore the bytes past
+//! the ones they read, e.g. the 0xc0 bytes `BLS.addG1Points` sends, while the pairings and the
+//! EIP-2537 precompiles require exact lengths.
+//!
+//! MODEXP is limited to 1024 bytes operands as of Osaka (EIP-7823), the `evm_version` of
+//! `foundry.toml`. Gas is not emulated, see `just gas-profile` for the gas used by the libraries.
+
+use crate::error::check_length;
+use crate::{Error, bn254, eip2537};
+
+use ark_ec::hashing::curve_maps::wb::WBMap;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+use ark_ec::pairing::Pairing;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use num_bigint::BigUint;
+
+/// Address of the EIP-198 modular exponentiation precompile, `MODEXP_ADDRESS`.
+pub const MODEXP: u8 = 0x05;
+/// Address of the EIP-196 BN254 G1 addition precompile, `ECADD_ADDRESS`.
+pub const ECADD: u8 = 0x06;
+/// Address of the EIP-196 BN254 G1 scalar multiplication precompile, `ECMUL_ADDRESS`.
+pub const ECMUL: u8 = 0x07;
+/// Address of the EIP-197 BN254 pairing check precompile, `BN254_ECPAIRING_ADDRESS`.
+pub const ECPAIRING: u8 = 0x08;
+
+/// Largest base, exponent and modulus of MODEXP since EIP-7823.
+pub const MODEXP_MAX_LENGTH: usize = 1024;
+
+const ECADD_INPUT_LENGTH: usize = 2 * bn254::G1_LENGTH;
+const ECMUL_INPUT_LENGTH: usize = bn254::G1_LENGTH + 32;
+const ECPAIRING_PAIR_LENGTH: usize = bn254::G1_LENGTH + bn254::G2_LENGTH;
+const BLS12_PAIR_LENGTH: usize = eip2537::G1_LENGTH + eip2537::G2_LENGTH;
+
+/// Calls the precompile at `address`, one of the constants of `Precompiles.sol`.
+pub fn call(address: u8, input: &[u8]) -> Result<Vec<u8>, Error> {
+    match address {
+        MODEXP => modexp(input),
+        ECADD => ecadd(input).map(Vec::from),
+        ECMUL => ecmul(input).map(Vec::from),
+        ECPAIRING => ecpairing(input).map(Vec::from),
+        eip2537::G1ADD => bls12_g1add(input).map(Vec::from),
+        eip2537::PAIRING_CHECK => bls12_pairing_check(input).map(Vec::from),
+        eip2537::MAP_FP_TO_G1 => bls12_map_fp_to_g1(input).map(Vec::from),
+        _ => Err(Error::UnknownPrecompile(address)),
+    }
+}
+
+/// `base^exponent % modulus`, left padded to the length of the modulus. The input is the three
+/// lengths as 32 bytes words followed by the operands, read as if it was followed by zeros.
+pub fn modexp(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut lengths = [0; 3];
+    for (i, length) in lengths.iter_mut().enumerate() {
+        let word = padded(input, 32 * i, 32);
+        if word[..30].iter().any(|b| *b != 0) {
+            return Err(Error::ModExpLengthTooLarge);
+        }
+        *length = usize::from(u16::from_be_bytes([word[30], word[31]]));
+        if *length > MODEXP_MAX_LENGTH {
+            return Err(Error::ModExpLengthTooLarge);
+        }
+    }
+    let [base_length, exponent_length, modulus_length] = lengths;
+    let base = padded(input, 96, base_length);
+    let exponent = padded(input, 96 + base_length, exponent_length);
+    let modulus = padded(input, 96 + base_length + exponent_length, modulus_length);
+
+    let modulus = BigUint::from_bytes_be(&modulus);
+    let mut output = vec![0; modulus_length];
+    if modulus.is_zero() {
+        return Ok(output);
+    }
+    let result = BigUint::from_bytes_be(&base)
+        .modpow(&BigUint::from_bytes_be(&exponent), &modulus)
+        .to_bytes_be();
+    output[modulus_length - result.len()..].copy_from_slice(&result);
+    Ok(output)
+}
+
+/// Sum of the two points of the first 128 bytes. The points must be on the curve, (0, 0) being
+/// the point at infinity.
+pub fn ecadd(input: &[u8]) -> Result<[u8; bn254::G1_LENGTH], Error> {
+    let input = padded(input, 0, ECADD_INPUT_LENGTH);
+    let (p, q) = input.split_at(bn254::G1_LENGTH);
+    Ok(bn254::g1_marshal(
+        &(bn254::g1_unmarshal(p)? + bn254::g1_unmarshal(q)?).into_affine(),
+    ))
+}
+
+/// Product of the point and the 256 bits scalar of the first 96 bytes. The scalar is not
+/// required to be smaller than the group order.
+pub fn ecmul(input: &[u8]) -> Result<[u8; bn254::G1_LENGTH], Error> {
+    let input = padded(input, 0, ECMUL_INPUT_LENGTH);
+    let (p, scalar) = input.split_at(bn254::G1_LENGTH);
+    let scalar = ark_bn254::Fr::from_be_bytes_mod_order(scalar);
+    Ok(bn254::g1_marshal(
+        &(bn254::g1_unmarshal(p)? * scalar).into_affine(),
+    ))
+}
+
+/// One as a 32 bytes word if the product of the pairings of the (G1, G2) pairs is one, zero
+/// otherwise. Each pair is 192 bytes, G2 coordinates ordered c1 || c0 like `g2Marshal`, and the
+/// G2 points must be in the prime order subgroup. Empty inputs return one.
+pub fn ecpairing(input: &[u8]) -> Result<[u8; 32], Error> {
+    if !input.len().is_multiple_of(ECPAIRING_PAIR_LENGTH) {
+        return Err(Error::InvalidPairingLength {
+            pair_length: ECPAIRING_PAIR_LENGTH,
+            actual: input.len(),
+        });
+    }
+    let (mut g1, mut g2) = (Vec::new(), Vec::new());
+    for pair in input.chunks(ECPAIRING_PAIR_LENGTH) {
+        let (p, q) = pair.split_at(bn254::G1_LENGTH);
+        g1.push(bn254::g1_unmarshal(p)?);
+        g2.push(bn254::g2_unmarshal(q)?);
+    }
+    Ok(word(ark_bn254::Bn254::multi_pairing(g1, g2).is_zero()))
+}
+
+/// Sum of two G1 points in the EIP-2537 encoding, 256 bytes. The points must be on the curve,
+/// but unlike the MSM and pairing precompiles not necessarily in the prime order subgroup.
+pub fn bls12_g1add(input: &[u8]) -> Result<[u8; eip2537::G1_LENGTH], Error> {
+    check_length(input, 2 * eip2537::G1_LENGTH)?;
+    let (p, q) = input.split_at(eip2537::G1_LENGTH);
+    let sum = on_curve(eip2537::fp_decode(&p[..64])?, eip2537::fp_decode(&p[64..])?)?
+        + on_curve(eip2537::fp_decode(&q[..64])?, eip2537::fp_decode(&q[64..])?)?;
+    Ok(eip2537::g1_encode(&sum.into_affine()))
+}
+
+/// One as a 32 bytes word if the product of the pairings of the (G1, G2) pairs is one, zero
+/// otherwise. Each pair is 384 bytes, both points in the prime order subgroups, and there must be
+/// at least one.
+pub fn bls12_pairing_check(input: &[u8]) -> Result<[u8; 32], Error> {
+    if input.is_empty() {
+        return Err(Error::InvalidLength {
+            expected: BLS12_PAIR_LENGTH,
+            actual: 0,
+        });
+    }
+    if !input.len().is_multiple_of(BLS12_PAIR_LENGTH) {
+        return Err(Error::InvalidPairingLength {
+            pair_length: BLS12_PAIR_LENGTH,
+            actual: input.len(),
+        });
+    }
+    let (mut g1, mut g2) = (Vec::new(), Vec::new());
+    for pair in input.chunks(BLS12_PAIR_LENGTH) {
+        let (p, q) = pair.split_at(eip2537::G1_LENGTH);
+        g1.push(eip2537::g1_decode(p)?);
+        g2.push(eip2537::g2_decode(q)?);
+    }
+    Ok(word(
+        ark_bls12_381::Bls12_381::multi_pairing(g1, g2).is_zero(),
+    ))
+}
+
+/// The SSWU map of RFC 9380 §6.6.3 of a 64 bytes field element, with the cofactor cleared.
+pub fn bls12_map_fp_to_g1(input: &[u8]) -> Result<[u8; eip2537::G1_LENGTH], Error> {
+    let u = eip2537::fp_decode(input)?;
+    let p = WBMap::<ark_bls12_381::g1::Config>::map_to_curve(u)
+        .expect("the SSWU map is defined on all field elements");
+    Ok(eip2537::g1_encode(&p.clear_cofactor()))
+}
+
+/// `length` bytes of `input` from `offset`, the missing ones being zeros.
+fn padded(input: &[u8], offset: usize, length: usize) -> Vec<u8> {
+    let mut bytes = vec![0; length];
+    if let Some(available) = input.get(offset..) {
+        let n = available.len().min(length);
+        bytes[..n].copy_from_slice(&available[..n]);
+    }
+    bytes
+}
+
+/// Point with coordinates (x, y) on the curve, (0, 0) being the point at infinity.
+fn on_curve<P: SWCurveConfig>(x: P::BaseField, y: P::BaseField) -> Result<Affine<P>, Error> {
+    if x.is_zero() && y.is_zero() {
+        return Ok(Affine::identity());
+    }
+    let p = Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::NotOnCurve);
+    }
+    Ok(p)
+}
+
+fn word(value: bool) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[31] = value.into();
+    word
+}
@@ -0,0 +1,196 @@
+//! Conversions of G2 public keys between the encodings they are published in.
+//!
+//! The same key shows up in several layouts: drand serves quicknet's as a compressed point, the
+//! signature scheme constructors and kyber (evmnet) take `g2Marshal` bytes, `QuicknetRegistry`
+//! hardcodes `BLS2.PointG2` limbs, and arkworks serializes BN254 coefficients little-endian.
+//! Decoding always checks that the key is on the curve, in the prime order subgroup and not the
+//! point at infinity, so a converted key is one the contracts can verify signatures against.
+
+use crate::error::check_length;
+use crate::{Curve, Error, bls12_381, bn254, compressed, eip2537, sol};
+
+use ark_ec::AffineRepr;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A public key layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// ZCash compressed point, as served by drand for quicknet: x.c1 || x.c0 with the flags in the
+    /// top bits. BLS12-381 only.
+    Compressed,
+    /// `BLS.g2Marshal`/`BLS2.g2Marshal`, the `publicKeyBytes` of the constructors: big-endian
+    /// x.c1 || x.c0 || y.c1 || y.c0, the Fp2 ordering of EIP-197 and of kyber, e.g. evmnet's key.
+    G2Marshal,
+    /// `serialize_uncompressed` of arkworks: little-endian x.c0 || x.c1 || y.c0 || y.c1 on BN254.
+    /// ark-bls12-381 follows ZCash instead, which gives the same bytes as `G2Marshal`.
+    Arkworks,
+    /// EIP-2537 precompile input: x.c0 || x.c1 || y.c0 || y.c1, each left padded to 64 bytes.
+    /// BLS12-381 only.
+    Eip2537,
+    /// `abi.encode` of `BLS.PointG2` (x[0], x[1], y[0], y[1]) or `BLS2.PointG2` (the eight
+    /// `uint128`/`uint256` limbs of `QuicknetRegistry.PUBLIC_KEY()`).
+    Solidity,
+}
+
+impl Format {
+    pub const ALL: [Format; 5] = [
+        Format::Compressed,
+        Format::G2Marshal,
+        Format::Arkworks,
+        Format::Eip2537,
+        Format::Solidity,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Compressed => "compressed",
+            Format::G2Marshal => "g2marshal",
+            Format::Arkworks => "arkworks",
+            Format::Eip2537 => "eip2537",
+            Format::Solidity => "solidity",
+        }
+    }
+
+    /// Whether keys on `curve` can be encoded in this format.
+    pub fn supports(&self, curve: Curve) -> bool {
+        curve == Curve::Bls12381 || !matches!(self, Format::Compressed | Format::Eip2537)
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Format::ALL
+            .into_iter()
+            .find(|format| format.name() == s)
+            .ok_or_else(|| Error::UnknownFormat(s.to_owned()))
+    }
+}
+
+/// A validated public key on either curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PublicKey {
+    Bn254(bn254::PublicKey),
+    Bls12381(bls12_381::PublicKey),
+}
+
+impl PublicKey {
+    pub fn curve(&self) -> Curve {
+        match self {
+            PublicKey::Bn254(_) => Curve::Bn254,
+            PublicKey::Bls12381(_) => Curve::Bls12381,
+        }
+    }
+
+    pub fn decode(curve: Curve, format: Format, bytes: &[u8]) -> Result<Self, Error> {
+        check_supported(curve, format)?;
+        let key = match curve {
+            Curve::Bn254 => PublicKey::Bn254(bn254::PublicKey(match format {
+                Format::G2Marshal => bn254::g2_unmarshal(bytes)?,
+                Format::Arkworks => arkworks_decode(bytes, 128)?,
+                Format::Solidity => sol::bls::PointG2::abi_decode(bytes)?.try_into()?,
+                Format::Compressed | Format::Eip2537 => unreachable!(),
+            })),
+            Curve::Bls12381 => PublicKey::Bls12381(bls12_381::PublicKey(match format {
+                Format::Compressed => compressed::g2_decompress(bytes)?,
+                Format::G2Marshal => bls12_381::g2_unmarshal(bytes)?,
+                Format::Arkworks => arkworks_decode(bytes, 192)?,
+                Format::Eip2537 => eip2537::g2_decode(bytes)?,
+                Format::Solidity => sol::bls2::PointG2::abi_decode(bytes)?.try_into()?,
+            })),
+        };
+        let infinity = match key {
+            PublicKey::Bn254(pk) => pk.0.is_zero(),
+            PublicKey::Bls12381(pk) => pk.0.is_zero(),
+        };
+        if infinity {
+            return Err(Error::InfinityUnsupported);
+        }
+        Ok(key)
+    }
+
+    pub fn encode(&self, format: Format) -> Result<Vec<u8>, Error> {
+        check_supported(self.curve(), format)?;
+        Ok(match self {
+            PublicKey::Bn254(pk) => match format {
+                Format::G2Marshal => pk.to_bytes().to_vec(),
+                Format::Arkworks => arkworks_encode(&pk.0),
+                Format::Solidity => sol::bls::PointG2::from(pk.0).abi_encode().to_vec(),
+                Format::Compressed | Format::Eip2537 => unreachable!(),
+            },
+            PublicKey::Bls12381(pk) => match format {
+                Format::Compressed => compressed::g2_compress(&pk.0)?.to_vec(),
+                Format::G2Marshal => pk.to_bytes().to_vec(),
+                Format::Arkworks => arkworks_encode(&pk.0),
+                Format::Eip2537 => eip2537::g2_encode(&pk.0).to_vec(),
+                Format::Solidity => sol::bls2::PointG2::from(pk.0).abi_encode().to_vec(),
+            },
+        })
+    }
+
+    /// The curves and formats `bytes` decode as, e.g. to identify a key of unknown origin.
+    pub fn detect(bytes: &[u8]) -> Vec<(Curve, Format)> {
+        [Curve::Bn254, Curve::Bls12381]
+            .into_iter()
+            .flat_map(|curve| Format::ALL.map(|format| (curve, format)))
+            .filter(|(curve, format)| Self::decode(*curve, *format, bytes).is_ok())
+            .collect()
+    }
+}
+
+/// Re-encodes a public key, validating it on the way.
+pub fn convert(curve: Curve, from: Format, to: Format, bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    PublicKey::decode(curve, from, bytes)?.encode(to)
+}
+
+fn check_supported(curve: Curve, format: Format) -> Result<(), Error> {
+    if !format.supports(curve) {
+        return Err(Error::UnsupportedFormat {
+            curve: curve.id(),
+            format: format.name(),
+        });
+    }
+    Ok(())
+}
+
+fn arkworks_encode(p: &impl CanonicalSerialize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    p.serialize_uncompressed(&mut bytes)
+        .expect("serializing to a Vec does not fail");
+    bytes
+}
+
+/// Deserializes without the arkworks validation, which reports every failure as `InvalidData` and
+/// skips the curve equation on BLS12-381, then checks the point like the other formats. Encodings
+/// that do not serialize back to `bytes`, e.g. with the BN254 sign flag set, are rejected.
+fn arkworks_decode<C: SWCurveConfig>(bytes: &[u8], length: usize) -> Result<Affine<C>, Error> {
+    check_length(bytes, length)?;
+    let p = Affine::<C>::deserialize_uncompressed_unchecked(bytes).map_err(|e| match e {
+        SerializationError::NotEnoughSpace => Error::InvalidLength {
+            expected: length,
+            actual: bytes.len(),
+        },
+        _ => Error::InvalidArkworksEncoding,
+    })?;
+    if arkworks_encode(&p) != bytes {
+        return Err(Error::InvalidArkworksEncoding);
+    }
+    if !p.is_on_curve() {
+        return Err(Error::NotOnCurve);
+    }
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(p)
+}
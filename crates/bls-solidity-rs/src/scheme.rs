@@ -0,0 +1,104 @@
+use crate::{Error, bls12_381, bn254};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Signature schemes, named after the `SCHEME_ID` of the signature scheme contracts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scheme {
+    Bn254,
+    Bls12381,
+    Bls12381Compressed,
+}
+
+impl Scheme {
+    /// `SCHEME_ID` of the contract.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Scheme::Bn254 => bn254::SCHEME_ID,
+            Scheme::Bls12381 => bls12_381::SCHEME_ID,
+            Scheme::Bls12381Compressed => bls12_381::COMPRESSED_SCHEME_ID,
+        }
+    }
+
+    /// `hashToBytes(message)` of a contract of this scheme with `DST() == dst`: the marshalled G1
+    /// point that is signed.
+    pub fn hash_to_bytes(&self, dst: &[u8], message: &[u8]) -> Vec<u8> {
+        match self {
+            Scheme::Bn254 => bn254::g1_marshal(&bn254::hash_to_point(dst, message)).to_vec(),
+            Scheme::Bls12381 | Scheme::Bls12381Compressed => {
+                bls12_381::g1_marshal(&bls12_381::hash_to_point(dst, message)).to_vec()
+            }
+        }
+    }
+
+    /// Hash to curve suffix of the DSTs, see [`crate::dst`].
+    pub fn h2c_suffix(&self) -> &'static str {
+        match self {
+            Scheme::Bn254 => bn254::H2C_SUFFIX,
+            Scheme::Bls12381 | Scheme::Bls12381Compressed => bls12_381::H2C_SUFFIX,
+        }
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+impl FromStr for Scheme {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            bn254::SCHEME_ID => Ok(Scheme::Bn254),
+            bls12_381::SCHEME_ID => Ok(Scheme::Bls12381),
+            bls12_381::COMPRESSED_SCHEME_ID => Ok(Scheme::Bls12381Compressed),
+            _ => Err(Error::UnknownScheme(s.to_owned())),
+        }
+    }
+}
+
+/// Curves of the signature schemes, whose keys and signatures are not interchangeable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Curve {
+    Bn254,
+    Bls12381,
+}
+
+impl Curve {
+    pub fn id(&self) -> &'static str {
+        match self {
+            Curve::Bn254 => "bn254",
+            Curve::Bls12381 => "bls12-381",
+        }
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+impl FromStr for Curve {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "bn254" => Ok(Curve::Bn254),
+            "bls12-381" => Ok(Curve::Bls12381),
+            _ => Err(Error::UnknownCurve(s.to_owned())),
+        }
+    }
+}
+
+impl From<Scheme> for Curve {
+    fn from(scheme: Scheme) -> Self {
+        match scheme {
+            Scheme::Bn254 => Curve::Bn254,
+            Scheme::Bls12381 | Scheme::Bls12381Compressed => Curve::Bls12381,
+        }
+    }
+}
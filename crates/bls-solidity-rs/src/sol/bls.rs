@@ -0,0 +1,96 @@
+//! `BLS.PointG1` and `BLS.PointG2`.
+
+use super::{Uint256, words};
+use crate::Error;
+use crate::point::{coordinates, fq_from_be_bytes, fq_to_be_bytes, from_coordinates};
+
+use ark_bn254::{Fq, Fq2, G1Affine, G2Affine};
+
+fn fq_to_word(f: Fq) -> Uint256 {
+    fq_to_be_bytes(f).try_into().unwrap()
+}
+
+/// `BLS.PointG1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PointG1 {
+    pub x: Uint256,
+    pub y: Uint256,
+}
+
+impl PointG1 {
+    pub const ABI_LENGTH: usize = 64;
+
+    /// `abi.encode(point)`.
+    pub fn abi_encode(&self) -> [u8; Self::ABI_LENGTH] {
+        [self.x, self.y].as_flattened().try_into().unwrap()
+    }
+
+    /// `abi.decode(data, (BLS.PointG1))`.
+    pub fn abi_decode(data: &[u8]) -> Result<Self, Error> {
+        let [x, y] = words(data)?;
+        Ok(Self { x, y })
+    }
+}
+
+impl From<G1Affine> for PointG1 {
+    fn from(p: G1Affine) -> Self {
+        let (x, y) = coordinates(&p);
+        Self {
+            x: fq_to_word(x),
+            y: fq_to_word(y),
+        }
+    }
+}
+
+impl TryFrom<PointG1> for G1Affine {
+    type Error = Error;
+
+    fn try_from(p: PointG1) -> Result<Self, Error> {
+        from_coordinates(fq_from_be_bytes(&p.x)?, fq_from_be_bytes(&p.y)?)
+    }
+}
+
+/// `BLS.PointG2`, where `x[0]` and `y[0]` are the coefficients of degree 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PointG2 {
+    pub x: [Uint256; 2],
+    pub y: [Uint256; 2],
+}
+
+impl PointG2 {
+    pub const ABI_LENGTH: usize = 128;
+
+    /// `abi.encode(point)`: x[0] || x[1] || y[0] || y[1], unlike `BLS.g2Marshal`.
+    pub fn abi_encode(&self) -> [u8; Self::ABI_LENGTH] {
+        let words = [self.x[0], self.x[1], self.y[0], self.y[1]];
+        words.as_flattened().try_into().unwrap()
+    }
+
+    /// `abi.decode(data, (BLS.PointG2))`.
+    pub fn abi_decode(data: &[u8]) -> Result<Self, Error> {
+        let [x0, x1, y0, y1] = words(data)?;
+        Ok(Self {
+            x: [x0, x1],
+            y: [y0, y1],
+        })
+    }
+}
+
+impl From<G2Affine> for PointG2 {
+    fn from(p: G2Affine) -> Self {
+        let (x, y) = coordinates(&p);
+        Self {
+            x: [fq_to_word(x.c0), fq_to_word(x.c1)],
+            y: [fq_to_word(y.c0), fq_to_word(y.c1)],
+        }
+    }
+}
+
+impl TryFrom<PointG2> for G2Affine {
+    type Error = Error;
+
+    fn try_from(p: PointG2) -> Result<Self, Error> {
+        let [x0, x1, y0, y1] = [p.x[0], p.x[1], p.y[0], p.y[1]].map(|w| fq_from_be_bytes(&w));
+        from_coordinates(Fq2::new(x0?, x1?), Fq2::new(y0?, y1?))
+    }
+}
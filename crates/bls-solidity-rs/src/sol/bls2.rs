@@ -0,0 +1,157 @@
+//! `BLS2.PointG1` and `BLS2.PointG2`.
+//!
+//! Each 48 bytes coordinate is split in a `uint128` holding its 16 most significant bytes and a
+//! `uint256` holding the other 32, e.g. `x = x_hi << 256 | x_lo`.
+
+use super::{Uint256, decode_uint128, encode_uint128, words};
+use crate::Error;
+use crate::point::{coordinates, fq_from_be_bytes, fq_to_be_bytes, from_coordinates};
+
+use ark_bls12_381::{Fq, Fq2, G1Affine, G2Affine};
+
+fn split(f: Fq) -> (u128, Uint256) {
+    let bytes = fq_to_be_bytes(f);
+    let (hi, lo) = bytes.split_at(16);
+    (
+        u128::from_be_bytes(hi.try_into().unwrap()),
+        lo.try_into().unwrap(),
+    )
+}
+
+fn join(hi: u128, lo: &Uint256) -> Result<Fq, Error> {
+    fq_from_be_bytes(&[&hi.to_be_bytes()[..], lo].concat())
+}
+
+/// `BLS2.PointG1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PointG1 {
+    pub x_hi: u128,
+    pub x_lo: Uint256,
+    pub y_hi: u128,
+    pub y_lo: Uint256,
+}
+
+impl PointG1 {
+    pub const ABI_LENGTH: usize = 128;
+
+    /// `abi.encode(point)`.
+    pub fn abi_encode(&self) -> [u8; Self::ABI_LENGTH] {
+        let words = [
+            encode_uint128(self.x_hi),
+            self.x_lo,
+            encode_uint128(self.y_hi),
+            self.y_lo,
+        ];
+        words.as_flattened().try_into().unwrap()
+    }
+
+    /// `abi.decode(data, (BLS2.PointG1))`.
+    pub fn abi_decode(data: &[u8]) -> Result<Self, Error> {
+        let [x_hi, x_lo, y_hi, y_lo] = words(data)?;
+        Ok(Self {
+            x_hi: decode_uint128(&x_hi)?,
+            x_lo,
+            y_hi: decode_uint128(&y_hi)?,
+            y_lo,
+        })
+    }
+}
+
+impl From<G1Affine> for PointG1 {
+    fn from(p: G1Affine) -> Self {
+        let (x, y) = coordinates(&p);
+        let (x_hi, x_lo) = split(x);
+        let (y_hi, y_lo) = split(y);
+        Self {
+            x_hi,
+            x_lo,
+            y_hi,
+            y_lo,
+        }
+    }
+}
+
+impl TryFrom<PointG1> for G1Affine {
+    type Error = Error;
+
+    fn try_from(p: PointG1) -> Result<Self, Error> {
+        from_coordinates(join(p.x_hi, &p.x_lo)?, join(p.y_hi, &p.y_lo)?)
+    }
+}
+
+/// `BLS2.PointG2`, with the limbs ordered like `BLS2.g2Marshal`: x1, x0, y1, y0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PointG2 {
+    pub x1_hi: u128,
+    pub x1_lo: Uint256,
+    pub x0_hi: u128,
+    pub x0_lo: Uint256,
+    pub y1_hi: u128,
+    pub y1_lo: Uint256,
+    pub y0_hi: u128,
+    pub y0_lo: Uint256,
+}
+
+impl PointG2 {
+    pub const ABI_LENGTH: usize = 256;
+
+    /// `abi.encode(point)`.
+    pub fn abi_encode(&self) -> [u8; Self::ABI_LENGTH] {
+        let words = [
+            encode_uint128(self.x1_hi),
+            self.x1_lo,
+            encode_uint128(self.x0_hi),
+            self.x0_lo,
+            encode_uint128(self.y1_hi),
+            self.y1_lo,
+            encode_uint128(self.y0_hi),
+            self.y0_lo,
+        ];
+        words.as_flattened().try_into().unwrap()
+    }
+
+    /// `abi.decode(data, (BLS2.PointG2))`, e.g. the return data of `QuicknetRegistry.PUBLIC_KEY()`.
+    pub fn abi_decode(data: &[u8]) -> Result<Self, Error> {
+        let [x1_hi, x1_lo, x0_hi, x0_lo, y1_hi, y1_lo, y0_hi, y0_lo] = words(data)?;
+        Ok(Self {
+            x1_hi: decode_uint128(&x1_hi)?,
+            x1_lo,
+            x0_hi: decode_uint128(&x0_hi)?,
+            x0_lo,
+            y1_hi: decode_uint128(&y1_hi)?,
+            y1_lo,
+            y0_hi: decode_uint128(&y0_hi)?,
+            y0_lo,
+        })
+    }
+}
+
+impl From<G2Affine> for PointG2 {
+    fn from(p: G2Affine) -> Self {
+        let (x, y) = coordinates(&p);
+        let (x1_hi, x1_lo) = split(x.c1);
+        let (x0_hi, x0_lo) = split(x.c0);
+        let (y1_hi, y1_lo) = split(y.c1);
+        let (y0_hi, y0_lo) = split(y.c0);
+        Self {
+            x1_hi,
+            x1_lo,
+            x0_hi,
+            x0_lo,
+            y1_hi,
+            y1_lo,
+            y0_hi,
+            y0_lo,
+        }
+    }
+}
+
+impl TryFrom<PointG2> for G2Affine {
+    type Error = Error;
+
+    fn try_from(p: PointG2) -> Result<Self, Error> {
+        let x = Fq2::new(join(p.x0_hi, &p.x0_lo)?, join(p.x1_hi, &p.x1_lo)?);
+        let y = Fq2::new(join(p.y0_hi, &p.y0_lo)?, join(p.y1_hi, &p.y1_lo)?);
+        from_coordinates(x, y)
+    }
+}
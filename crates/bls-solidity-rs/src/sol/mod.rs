@@ -0,0 +1,35 @@
+//! Mirrors of the point structs of `BLS.sol` and `BLS2.sol`, field for field.
+//!
+//! All their fields are static, so `abi.encode(point)` is just the fields in declaration order,
+//! each left padded to 32 bytes. The same layout is used when a point is a function argument or
+//! a return value, e.g. `QuicknetRegistry.PUBLIC_KEY()`.
+
+pub mod bls;
+pub mod bls2;
+
+use crate::Error;
+
+pub use crate::abi::Uint256;
+
+pub(crate) fn encode_uint128(value: u128) -> Uint256 {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Reads a `uint128` word, which `abi.decode` rejects if its upper 16 bytes are not zero.
+pub(crate) fn decode_uint128(word: &Uint256) -> Result<u128, Error> {
+    let (hi, lo) = word.split_at(16);
+    if hi.iter().any(|b| *b != 0) {
+        return Err(Error::InvalidAbiEncoding);
+    }
+    Ok(u128::from_be_bytes(lo.try_into().unwrap()))
+}
+
+/// Splits the ABI encoding of a static struct into its `N` words.
+pub(crate) fn words<const N: usize>(data: &[u8]) -> Result<[Uint256; N], Error> {
+    crate::error::check_length(data, 32 * N)?;
+    Ok(std::array::from_fn(|i| {
+        data[32 * i..][..32].try_into().unwrap()
+    }))
+}
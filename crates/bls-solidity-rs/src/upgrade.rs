@@ -0,0 +1,384 @@
+//! Messages signed to authorize the state changes of `ScheduledUpgradeable`, and a planner
+//! checking a sequence of them offline.
+//!
+//! Messages are built byte-for-byte like `contractUpgradeParamsToBytes`,
+//! `blsValidatorUpdateParamsToBytes` and `minimumContractUpgradeDelayParamsToBytes`, then hashed
+//! to G1 like `hashToBytes` of the validator contract.
+
+use crate::Scheme;
+use crate::abi::{self, Address, Token, Uint256};
+
+use std::fmt;
+
+pub const SCHEDULE: &str = "schedule";
+pub const CANCEL: &str = "cancel";
+pub const CHANGE_BLS_VALIDATOR: &str = "change-contract-upgrade-bls-validator";
+pub const CHANGE_UPGRADE_DELAY: &str = "change-upgrade-delay";
+
+/// `setMinimumContractUpgradeDelay` and the initializer reject delays shorter than 2 days.
+pub const MINIMUM_UPGRADE_DELAY: u64 = 2 * 24 * 60 * 60;
+
+/// The contract and chain an authorization is bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Target {
+    pub contract: Address,
+    pub chain_id: Uint256,
+}
+
+/// Message of `scheduleUpgrade` and `cancelUpgrade`, see `contractUpgradeParamsToBytes`.
+#[allow(clippy::too_many_arguments)]
+pub fn contract_upgrade_message(
+    target: &Target,
+    action: &str,
+    pending_implementation: Address,
+    new_implementation: Address,
+    upgrade_calldata: &[u8],
+    upgrade_time: Uint256,
+    nonce: Uint256,
+) -> Vec<u8> {
+    abi::encode(&[
+        Token::String(action),
+        Token::Address(target.contract),
+        Token::Address(pending_implementation),
+        Token::Address(new_implementation),
+        Token::Bytes(upgrade_calldata),
+        Token::Uint(upgrade_time),
+        Token::Uint(nonce),
+        Token::Uint(target.chain_id),
+    ])
+}
+
+/// Message of `setContractUpgradeBlsValidator`, see `blsValidatorUpdateParamsToBytes`.
+pub fn bls_validator_update_message(
+    target: &Target,
+    action: &str,
+    bls_validator: Address,
+    nonce: Uint256,
+) -> Vec<u8> {
+    abi::encode(&[
+        Token::String(action),
+        Token::Address(target.contract),
+        Token::Address(bls_validator),
+        Token::Uint(nonce),
+        Token::Uint(target.chain_id),
+    ])
+}
+
+/// Message of `setMinimumContractUpgradeDelay`, see `minimumContractUpgradeDelayParamsToBytes`.
+pub fn minimum_contract_upgrade_delay_message(
+    target: &Target,
+    action: &str,
+    minimum_contract_upgrade_delay: Uint256,
+    nonce: Uint256,
+) -> Vec<u8> {
+    abi::encode(&[
+        Token::String(action),
+        Token::Address(target.contract),
+        Token::Uint(minimum_contract_upgrade_delay),
+        Token::Uint(nonce),
+        Token::Uint(target.chain_id),
+    ])
+}
+
+/// A deployed signature scheme contract, used as `contractUpgradeBlsValidator`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Validator {
+    pub address: Address,
+    pub scheme: Scheme,
+    /// `DST()` of the contract
+    pub dst: Vec<u8>,
+}
+
+/// Storage of `ScheduledUpgradeable`, as read from the contract before planning.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeState {
+    pub current_nonce: u64,
+    pub scheduled_implementation: Address,
+    pub scheduled_implementation_calldata: Vec<u8>,
+    pub scheduled_timestamp_for_upgrade: u64,
+    pub minimum_contract_upgrade_delay: u64,
+    pub contract_upgrade_bls_validator: Validator,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    ScheduleUpgrade {
+        new_implementation: Address,
+        upgrade_calldata: Vec<u8>,
+        upgrade_time: u64,
+    },
+    CancelUpgrade,
+    /// `executeUpgrade`, which is not signed but clears the scheduled upgrade.
+    ExecuteUpgrade,
+    SetContractUpgradeBlsValidator(Validator),
+    SetMinimumContractUpgradeDelay(u64),
+}
+
+/// An operation and the timestamp of the block it is expected to be included in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub timestamp: u64,
+    pub operation: Operation,
+}
+
+/// What the validator signs to authorize a step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Authorization {
+    /// `currentNonce()` after the step, which is bound to the message
+    pub nonce: u64,
+    /// output of `*ParamsToBytes`
+    pub message: Vec<u8>,
+    /// `hashToBytes(message)` of the validator, to be signed
+    pub message_g1: Vec<u8>,
+    /// the contract whose key must sign
+    pub validator: Validator,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedStep {
+    pub step: Step,
+    /// `None` for `executeUpgrade`
+    pub authorization: Option<Authorization>,
+    /// `currentNonce()` once the step is included
+    pub nonce_after: u64,
+}
+
+/// Reverts of `ScheduledUpgradeable`, named after `ErrorsLib`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlanError {
+    ZeroAddress,
+    UpgradeDelayTooShort,
+    SameVersionUpgradeNotAllowed,
+    UpgradeTimeMustRespectDelay {
+        minimum_delay: u64,
+    },
+    TooLateToCancelUpgrade {
+        scheduled_timestamp: u64,
+    },
+    NoUpgradePending,
+    UpgradeTooEarly {
+        scheduled_timestamp: u64,
+    },
+    /// Steps must be planned in the order of their timestamps.
+    TimestampNotIncreasing,
+    /// The next nonce does not fit the `u64` the planner tracks it in.
+    NonceOverflow,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::UpgradeTimeMustRespectDelay { minimum_delay } => {
+                write!(f, "UpgradeTimeMustRespectDelay({minimum_delay})")
+            }
+            PlanError::TooLateToCancelUpgrade {
+                scheduled_timestamp,
+            } => write!(f, "TooLateToCancelUpgrade({scheduled_timestamp})"),
+            PlanError::UpgradeTooEarly {
+                scheduled_timestamp,
+            } => write!(f, "UpgradeTooEarly({scheduled_timestamp})"),
+            PlanError::TimestampNotIncreasing => write!(f, "steps are not ordered by timestamp"),
+            PlanError::NonceOverflow => write!(f, "the nonce overflows u64"),
+            error => write!(f, "{error:?}()"),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+/// Applies steps to a copy of the contract state, checking them like the contract would.
+pub struct Planner {
+    target: Target,
+    state: UpgradeState,
+    timestamp: u64,
+}
+
+impl Planner {
+    pub fn new(target: Target, state: UpgradeState) -> Self {
+        Self {
+            target,
+            state,
+            timestamp: 0,
+        }
+    }
+
+    /// State of the contract after the steps planned so far.
+    pub fn state(&self) -> &UpgradeState {
+        &self.state
+    }
+
+    /// Plans `steps` in order, returning the index of the first one that would revert.
+    pub fn plan(
+        &mut self,
+        steps: impl IntoIterator<Item = Step>,
+    ) -> Result<Vec<PlannedStep>, (usize, PlanError)> {
+        steps
+            .into_iter()
+            .enumerate()
+            .map(|(i, step)| self.push(step).map_err(|e| (i, e)))
+            .collect()
+    }
+
+    /// Plans a single step. The state is left unchanged if it would revert.
+    pub fn push(&mut self, step: Step) -> Result<PlannedStep, PlanError> {
+        if step.timestamp < self.timestamp {
+            return Err(PlanError::TimestampNotIncreasing);
+        }
+        let state = &self.state;
+        let now = step.timestamp;
+
+        let next = match &step.operation {
+            Operation::ScheduleUpgrade {
+                new_implementation,
+                upgrade_calldata,
+                upgrade_time,
+            } => {
+                if *new_implementation == Address::default() {
+                    return Err(PlanError::ZeroAddress);
+                }
+                if state.scheduled_implementation == *new_implementation {
+                    return Err(PlanError::SameVersionUpgradeNotAllowed);
+                }
+                if *upgrade_time < now.saturating_add(state.minimum_contract_upgrade_delay) {
+                    return Err(PlanError::UpgradeTimeMustRespectDelay {
+                        minimum_delay: state.minimum_contract_upgrade_delay,
+                    });
+                }
+                UpgradeState {
+                    scheduled_implementation: *new_implementation,
+                    scheduled_implementation_calldata: upgrade_calldata.clone(),
+                    scheduled_timestamp_for_upgrade: *upgrade_time,
+                    ..state.clone()
+                }
+            }
+            Operation::CancelUpgrade => {
+                if now >= state.scheduled_timestamp_for_upgrade {
+                    return Err(PlanError::TooLateToCancelUpgrade {
+                        scheduled_timestamp: state.scheduled_timestamp_for_upgrade,
+                    });
+                }
+                cleared(state)
+            }
+            Operation::ExecuteUpgrade => {
+                if state.scheduled_implementation == Address::default() {
+                    return Err(PlanError::NoUpgradePending);
+                }
+                if now < state.scheduled_timestamp_for_upgrade {
+                    return Err(PlanError::UpgradeTooEarly {
+                        scheduled_timestamp: state.scheduled_timestamp_for_upgrade,
+                    });
+                }
+                cleared(state)
+            }
+            Operation::SetContractUpgradeBlsValidator(validator) => {
+                if validator.address == Address::default() {
+                    return Err(PlanError::ZeroAddress);
+                }
+                UpgradeState {
+                    contract_upgrade_bls_validator: validator.clone(),
+                    ..state.clone()
+                }
+            }
+            Operation::SetMinimumContractUpgradeDelay(delay) => {
+                if *delay < MINIMUM_UPGRADE_DELAY {
+                    return Err(PlanError::UpgradeDelayTooShort);
+                }
+                UpgradeState {
+                    minimum_contract_upgrade_delay: *delay,
+                    ..state.clone()
+                }
+            }
+        };
+
+        let authorization = match step.operation {
+            Operation::ExecuteUpgrade => None,
+            _ => {
+                let nonce = state
+                    .current_nonce
+                    .checked_add(1)
+                    .ok_or(PlanError::NonceOverflow)?;
+                self.authorize(&step.operation, nonce)
+            }
+        };
+        let nonce_after = match &authorization {
+            Some(authorization) => authorization.nonce,
+            None => state.current_nonce,
+        };
+        self.state = UpgradeState {
+            current_nonce: nonce_after,
+            ..next
+        };
+        self.timestamp = step.timestamp;
+        Ok(PlannedStep {
+            step,
+            authorization,
+            nonce_after,
+        })
+    }
+
+    /// What the current validator signs to authorize `operation` with `nonce`, without the checks
+    /// of [`Planner::push`], e.g. to sign with a nonce the contract rejects. `None` for
+    /// `executeUpgrade`.
+    pub fn authorize(&self, operation: &Operation, nonce: u64) -> Option<Authorization> {
+        let state = &self.state;
+        let message = match operation {
+            Operation::ScheduleUpgrade {
+                new_implementation,
+                upgrade_calldata,
+                upgrade_time,
+            } => contract_upgrade_message(
+                &self.target,
+                SCHEDULE,
+                state.scheduled_implementation,
+                *new_implementation,
+                upgrade_calldata,
+                abi::uint(*upgrade_time),
+                abi::uint(nonce),
+            ),
+            // cancelling signs the scheduled implementation as both the pending and new one
+            Operation::CancelUpgrade => contract_upgrade_message(
+                &self.target,
+                CANCEL,
+                state.scheduled_implementation,
+                state.scheduled_implementation,
+                &state.scheduled_implementation_calldata,
+                abi::uint(state.scheduled_timestamp_for_upgrade),
+                abi::uint(nonce),
+            ),
+            Operation::ExecuteUpgrade => return None,
+            Operation::SetContractUpgradeBlsValidator(validator) => bls_validator_update_message(
+                &self.target,
+                CHANGE_BLS_VALIDATOR,
+                validator.address,
+                abi::uint(nonce),
+            ),
+            Operation::SetMinimumContractUpgradeDelay(delay) => {
+                minimum_contract_upgrade_delay_message(
+                    &self.target,
+                    CHANGE_UPGRADE_DELAY,
+                    abi::uint(*delay),
+                    abi::uint(nonce),
+                )
+            }
+        };
+
+        // signed by the validator in place before the step, even when the step replaces it
+        let validator = state.contract_upgrade_bls_validator.clone();
+        Some(Authorization {
+            nonce,
+            message_g1: validator.scheme.hash_to_bytes(&validator.dst, &message),
+            message,
+            validator,
+        })
+    }
+}
+
+/// State once the scheduled upgrade is cancelled or executed.
+fn cleared(state: &UpgradeState) -> UpgradeState {
+    UpgradeState {
+        scheduled_implementation: Address::default(),
+        scheduled_implementation_calldata: Vec::new(),
+        scheduled_timestamp_for_upgrade: 0,
+        ..state.clone()
+    }
+}
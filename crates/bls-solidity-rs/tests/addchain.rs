@@ -0,0 +1,139 @@
+//! Addition chains compute their exponent, the emitted registers agree with `modpow`, and
+//! `test/generated/ModExp.sol` is the output of the generator. `addchain_yul.rs` runs the emitted
+//! libraries.
+
+use bls_solidity_rs::Error;
+use bls_solidity_rs::addchain::{self, Chain, Exponentiation, MAX_REGISTERS, MAX_WINDOW};
+
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+const GENERATED: &str = include_str!("../../../test/generated/ModExp.sol");
+
+fn check(chain: &Chain, exponent: &BigUint, modulus: &BigUint, base: &BigUint) {
+    assert_eq!(&chain.exponent(), exponent);
+    assert!(chain.registers() <= MAX_REGISTERS);
+    assert_eq!(
+        chain.evaluate(base, modulus),
+        base.modpow(exponent, modulus),
+        "x^0x{exponent:x} with a window of {} bits",
+        chain.window()
+    );
+}
+
+#[test]
+fn named_exponentiations() {
+    for name in Exponentiation::NAMES {
+        let exponentiation = Exponentiation::named(name).unwrap();
+        let chain = Chain::search(&exponentiation.exponent);
+        let modulus = &exponentiation.modulus;
+        for base in [BigUint::from(5u32), modulus - 1u32, modulus / 7u32] {
+            check(&chain, &exponentiation.exponent, modulus, &base);
+        }
+        assert_eq!(chain.len(), chain.squarings() + chain.multiplications());
+    }
+    assert_eq!(
+        Exponentiation::named("bls12-381-legendre"),
+        Err(Error::UnknownExponentiation(
+            "bls12-381-legendre".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn bn254_chains_are_not_longer_than_modexp_sol() {
+    // mulmod of src/libraries/ModExp.sol
+    for (name, length) in [("bn254-inverse", 308), ("bn254-sqrt", 305)] {
+        let exponentiation = Exponentiation::named(name).unwrap();
+        assert!(Chain::search(&exponentiation.exponent).len() <= length);
+    }
+}
+
+#[test]
+fn generated_modexp_sol_is_up_to_date() {
+    let libraries: Vec<String> = Exponentiation::NAMES
+        .into_iter()
+        .map(|name| {
+            let exponentiation = Exponentiation::named(name).unwrap();
+            let chain = Chain::search(&exponentiation.exponent);
+            let command = format!("bls_ffi addchain {name}");
+            exponentiation.library(&chain, &command).unwrap()
+        })
+        .collect();
+    assert_eq!(
+        addchain::solidity(&libraries),
+        GENERATED,
+        "run just addchain"
+    );
+}
+
+#[test]
+fn bls12_381_libraries_use_modexp() {
+    // the modulus does not fit in the uint256 operands of mulmod
+    for name in ["bls12-381-inverse", "bls12-381-sqrt"] {
+        let exponentiation = Exponentiation::named(name).unwrap();
+        let chain = Chain::search(&exponentiation.exponent);
+        let library = exponentiation.library(&chain, name).unwrap();
+        assert!(exponentiation.library.starts_with("BLS2Modexp"));
+        assert!(library.contains(&format!("library {} {{", exponentiation.library)));
+        assert!(library.contains("uint128 x_hi, uint256 x_lo) internal view"));
+        assert!(!library.contains(", n)"));
+        assert_eq!(
+            library.matches("            mulmodn(m, ").count(),
+            chain.len()
+        );
+    }
+
+    // 384 bits at most, for (uint128, uint256) field elements
+    let modulus = BigUint::from(1u32) << 384u32;
+    let exponentiation = Exponentiation::custom("TooLarge", modulus + 1u32, BigUint::from(3u32));
+    assert_eq!(
+        exponentiation.library(&Chain::search(&exponentiation.exponent), "bls_ffi addchain"),
+        Err(Error::ModulusTooLarge)
+    );
+}
+
+#[test]
+fn small_exponents() {
+    let modulus = BigUint::from(1_000_003u32);
+    let base = BigUint::from(12345u32);
+    for exponent in 1u32..=64 {
+        let exponent = BigUint::from(exponent);
+        for window in 1..=MAX_WINDOW {
+            check(
+                &Chain::sliding_window(&exponent, window),
+                &exponent,
+                &modulus,
+                &base,
+            );
+        }
+    }
+    // x^1 has no mulmod, the input is copied to the return variable
+    let library = Exponentiation::custom("Identity", modulus, BigUint::from(1u32))
+        .library(&Chain::search(&BigUint::from(1u32)), "bls_ffi addchain")
+        .unwrap();
+    assert!(library.contains("            t0 := t1\n"));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn chains_compute_their_exponent(
+        exponent: [u8; 32],
+        modulus: [u8; 32],
+        base: [u8; 32],
+        window in 1..=MAX_WINDOW,
+    ) {
+        let exponent = BigUint::from_bytes_be(&exponent);
+        let modulus = BigUint::from_bytes_be(&modulus);
+        prop_assume!(exponent.bits() > 0 && modulus.bits() > 1);
+        let base = BigUint::from_bytes_be(&base);
+
+        let chain = Chain::sliding_window(&exponent, window);
+        prop_assert_eq!(chain.exponent(), exponent.clone());
+        prop_assert_eq!(chain.evaluate(&base, &modulus), base.modpow(&exponent, &modulus));
+        let shortest = Chain::search(&exponent);
+        prop_assert!(shortest.len() <= chain.len() || chain.registers() > MAX_REGISTERS);
+    }
+}
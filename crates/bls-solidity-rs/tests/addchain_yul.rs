@@ -0,0 +1,502 @@
+//! The emitted libraries, run by an interpreter of the Yul they are written in with the MODEXP
+//! precompile of `precompiles`, agree with `modpow`: the `mulmod` chains of BN254 and the limb
+//! products reduced by MODEXP of BLS12-381, which solc is needed to run otherwise.
+
+use bls_solidity_rs::addchain::{Chain, Exponentiation};
+use bls_solidity_rs::precompiles;
+
+use num_bigint::BigUint;
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(BigUint),
+    Punct(&'static str),
+}
+
+fn tokenize(source: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if rest.starts_with("//") {
+            rest = rest.find('\n').map_or("", |end| &rest[end..]);
+        } else if let Some(punct) = [":=", "->", "(", ")", "{", "}", ","]
+            .into_iter()
+            .find(|punct| rest.starts_with(punct))
+        {
+            tokens.push(Token::Punct(punct));
+            rest = &rest[punct.len()..];
+        } else {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            assert!(end > 0, "unexpected {c:?}");
+            let word = &rest[..end];
+            tokens.push(match word.strip_prefix("0x") {
+                Some(hex) => Token::Number(BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()),
+                None if c.is_ascii_digit() => Token::Number(word.parse().unwrap()),
+                None => Token::Ident(word.to_owned()),
+            });
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+#[derive(Clone, Debug)]
+enum Expr {
+    Number(BigUint),
+    Variable(String),
+    Call(String, Vec<Expr>),
+}
+
+#[derive(Clone, Debug)]
+enum Statement {
+    Let(Vec<String>, Option<Expr>),
+    Assign(Vec<String>, Expr),
+    Expr(Expr),
+    If(Expr, Vec<Statement>),
+    Function(Function),
+}
+
+#[derive(Clone, Debug)]
+struct Function {
+    name: String,
+    parameters: Vec<String>,
+    returns: Vec<String>,
+    body: Vec<Statement>,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Token {
+        self.position += 1;
+        self.tokens[self.position - 1].clone()
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Punct(p)) if *p == punct);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, punct: &str) {
+        assert!(self.eat(punct), "expected {punct} at {:?}", self.peek());
+    }
+
+    fn ident(&mut self) -> String {
+        match self.next() {
+            Token::Ident(name) => name,
+            token => panic!("expected an identifier, got {token:?}"),
+        }
+    }
+
+    /// Identifiers separated by commas.
+    fn idents(&mut self) -> Vec<String> {
+        let mut names = vec![self.ident()];
+        while self.eat(",") {
+            names.push(self.ident());
+        }
+        names
+    }
+
+    fn block(&mut self) -> Vec<Statement> {
+        self.expect("{");
+        let mut statements = Vec::new();
+        while !self.eat("}") {
+            statements.push(self.statement());
+        }
+        statements
+    }
+
+    fn statement(&mut self) -> Statement {
+        match self.peek() {
+            Some(Token::Ident(word)) if word == "let" => {
+                self.position += 1;
+                let names = self.idents();
+                let value = self.eat(":=").then(|| self.expr());
+                Statement::Let(names, value)
+            }
+            Some(Token::Ident(word)) if word == "if" => {
+                self.position += 1;
+                let condition = self.expr();
+                Statement::If(condition, self.block())
+            }
+            Some(Token::Ident(word)) if word == "function" => {
+                self.position += 1;
+                let name = self.ident();
+                self.expect("(");
+                let parameters = if self.eat(")") {
+                    Vec::new()
+                } else {
+                    let parameters = self.idents();
+                    self.expect(")");
+                    parameters
+                };
+                let returns = if self.eat("->") {
+                    self.idents()
+                } else {
+                    Vec::new()
+                };
+                Statement::Function(Function {
+                    name,
+                    parameters,
+                    returns,
+                    body: self.block(),
+                })
+            }
+            _ => {
+                let expr = self.expr();
+                match expr {
+                    Expr::Variable(name) => {
+                        let mut names = vec![name];
+                        while self.eat(",") {
+                            names.push(self.ident());
+                        }
+                        self.expect(":=");
+                        Statement::Assign(names, self.expr())
+                    }
+                    expr => Statement::Expr(expr),
+                }
+            }
+        }
+    }
+
+    fn expr(&mut self) -> Expr {
+        match self.next() {
+            Token::Number(value) => Expr::Number(value),
+            Token::Ident(name) if self.eat("(") => {
+                let mut arguments = Vec::new();
+                if !self.eat(")") {
+                    arguments.push(self.expr());
+                    while self.eat(",") {
+                        arguments.push(self.expr());
+                    }
+                    self.expect(")");
+                }
+                Expr::Call(name, arguments)
+            }
+            Token::Ident(name) => Expr::Variable(name),
+            token => panic!("expected an expression, got {token:?}"),
+        }
+    }
+}
+
+/// The EVM state the libraries touch: memory, with the free memory pointer of Solidity.
+struct Machine {
+    memory: Vec<u8>,
+    functions: HashMap<String, Function>,
+    /// MODEXP calls
+    calls: usize,
+}
+
+type Scope = HashMap<String, BigUint>;
+
+fn word(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut word = [0; 32];
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+    word
+}
+
+fn usize(value: &BigUint) -> usize {
+    value.try_into().unwrap()
+}
+
+impl Machine {
+    fn new() -> Self {
+        let mut machine = Self {
+            memory: Vec::new(),
+            functions: HashMap::new(),
+            calls: 0,
+        };
+        machine.store(0x40, &word(&BigUint::from(0x80u32)));
+        machine
+    }
+
+    fn load(&mut self, offset: usize, length: usize) -> Vec<u8> {
+        if self.memory.len() < offset + length {
+            self.memory.resize(offset + length, 0);
+        }
+        self.memory[offset..offset + length].to_vec()
+    }
+
+    fn store(&mut self, offset: usize, bytes: &[u8]) {
+        self.load(offset, bytes.len());
+        self.memory[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    fn block(&mut self, statements: &[Statement], scopes: &mut Vec<Scope>) {
+        for statement in statements {
+            if let Statement::Function(function) = statement {
+                self.functions
+                    .insert(function.name.clone(), function.clone());
+            }
+        }
+        scopes.push(Scope::new());
+        for statement in statements {
+            match statement {
+                Statement::Let(names, value) => {
+                    let values = match value {
+                        Some(value) => self.eval(value, scopes),
+                        None => vec![BigUint::default(); names.len()],
+                    };
+                    assert_eq!(values.len(), names.len());
+                    let scope = scopes.last_mut().unwrap();
+                    scope.extend(names.iter().cloned().zip(values));
+                }
+                Statement::Assign(names, value) => {
+                    let values = self.eval(value, scopes);
+                    assert_eq!(values.len(), names.len());
+                    for (name, value) in names.iter().zip(values) {
+                        let scope = scopes
+                            .iter_mut()
+                            .rev()
+                            .find(|scope| scope.contains_key(name))
+                            .unwrap_or_else(|| panic!("undeclared {name}"));
+                        scope.insert(name.clone(), value);
+                    }
+                }
+                Statement::Expr(expr) => assert!(self.eval(expr, scopes).is_empty()),
+                Statement::If(condition, body) => {
+                    if self.eval(condition, scopes)[0] != BigUint::default() {
+                        self.block(body, scopes);
+                    }
+                }
+                Statement::Function(_) => {}
+            }
+        }
+        scopes.pop();
+    }
+
+    fn eval(&mut self, expr: &Expr, scopes: &mut Vec<Scope>) -> Vec<BigUint> {
+        let (name, arguments) = match expr {
+            Expr::Number(value) => return vec![value.clone()],
+            Expr::Variable(name) => {
+                let value = scopes.iter().rev().find_map(|scope| scope.get(name));
+                return vec![value.unwrap_or_else(|| panic!("undeclared {name}")).clone()];
+            }
+            Expr::Call(name, arguments) => (name, arguments),
+        };
+        let arguments: Vec<BigUint> = arguments
+            .iter()
+            .map(|argument| self.eval(argument, scopes).remove(0))
+            .collect();
+        let modulus = BigUint::from(1u32) << 256u32;
+        let max = &modulus - 1u32;
+        let boolean = |condition: bool| vec![BigUint::from(u8::from(condition))];
+        let a = arguments.first().cloned().unwrap_or_default();
+        let b = arguments.get(1).cloned().unwrap_or_default();
+        match name.as_str() {
+            "add" => vec![(a + b) % &modulus],
+            "sub" => vec![(a + &modulus - b) % &modulus],
+            "mul" => vec![(a * b) % &modulus],
+            "mulmod" if arguments[2] == BigUint::default() => vec![BigUint::default()],
+            "mulmod" => vec![(a * b) % &arguments[2]],
+            "lt" => boolean(a < b),
+            "iszero" => boolean(a == BigUint::default()),
+            "not" => vec![max - a],
+            "shl" => vec![(b << usize(&a)) % &modulus],
+            "shr" => vec![b >> usize(&a)],
+            "gas" => vec![BigUint::from(u64::MAX)],
+            "mload" => vec![BigUint::from_bytes_be(&self.load(usize(&a), 32))],
+            "mstore" => {
+                self.store(usize(&a), &word(&b));
+                vec![]
+            }
+            "mstore8" => {
+                self.store(usize(&a), &word(&b)[31..]);
+                vec![]
+            }
+            "staticcall" => {
+                let input = self.load(usize(&arguments[2]), usize(&arguments[3]));
+                self.calls += 1;
+                match precompiles::call(usize(&b) as u8, &input) {
+                    Ok(mut output) => {
+                        output.resize(usize(&arguments[5]), 0);
+                        self.store(usize(&arguments[4]), &output);
+                        boolean(true)
+                    }
+                    Err(_) => boolean(false),
+                }
+            }
+            "revert" => panic!("reverted"),
+            _ => {
+                let function = self.functions[name].clone();
+                let mut scope: Scope = function.parameters.iter().cloned().zip(arguments).collect();
+                for name in &function.returns {
+                    scope.insert(name.clone(), BigUint::default());
+                }
+                let mut scopes = vec![scope];
+                self.block(&function.body, &mut scopes);
+                function
+                    .returns
+                    .iter()
+                    .map(|name| scopes[0][name].clone())
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The names of the parameters of `run` or of its return values, e.g. `(uint128 hi, uint256 lo)`.
+fn names(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|parameter| parameter.split_whitespace().last().unwrap().to_owned())
+        .collect()
+}
+
+/// Runs the `run` function of `library` on `arguments`, returning its results and the number of
+/// precompile calls.
+fn run(library: &str, arguments: &[BigUint]) -> (Vec<BigUint>, usize) {
+    let signature = library
+        .lines()
+        .find(|line| line.trim_start().starts_with("function run("))
+        .unwrap();
+    let (parameters, rest) = signature
+        .split_once("run(")
+        .unwrap()
+        .1
+        .split_once(')')
+        .unwrap();
+    let returns = rest
+        .split_once("returns (")
+        .unwrap()
+        .1
+        .split_once(')')
+        .unwrap()
+        .0;
+    let assembly = &library[library.find("assembly").unwrap() + "assembly".len()..];
+
+    let mut parser = Parser {
+        tokens: tokenize(assembly),
+        position: 0,
+    };
+    let body = parser.block();
+    let mut scope: Scope = names(parameters)
+        .into_iter()
+        .zip(arguments.iter().cloned())
+        .collect();
+    for name in names(returns) {
+        scope.insert(name, BigUint::default());
+    }
+    let mut machine = Machine::new();
+    let mut scopes = vec![scope];
+    machine.block(&body, &mut scopes);
+    let results = names(returns)
+        .iter()
+        .map(|name| scopes[0][name].clone())
+        .collect();
+    (results, machine.calls)
+}
+
+fn library(exponentiation: &Exponentiation) -> String {
+    let chain = Chain::search(&exponentiation.exponent);
+    exponentiation.library(&chain, "bls_ffi addchain").unwrap()
+}
+
+/// `run` of a library over `uint256`, or over (uint128, uint256) pairs past 256 bits.
+fn exponentiate(exponentiation: &Exponentiation, library: &str, base: &BigUint) -> BigUint {
+    let chain_length = Chain::search(&exponentiation.exponent).len();
+    if exponentiation.modulus.bits() <= 256 {
+        let (results, calls) = run(library, std::slice::from_ref(base));
+        assert_eq!(calls, 0);
+        return results[0].clone();
+    }
+    let (hi, lo) = (base >> 256u32, base % (BigUint::from(1u32) << 256u32));
+    let (results, calls) = run(library, &[hi, lo]);
+    assert_eq!(calls, chain_length, "one MODEXP per step");
+    (&results[0] << 256u32) + &results[1]
+}
+
+fn check(exponentiation: &Exponentiation, library: &str, base: &BigUint) {
+    let Exponentiation {
+        modulus, exponent, ..
+    } = exponentiation;
+    assert_eq!(
+        exponentiate(exponentiation, library, base),
+        base.modpow(exponent, modulus),
+        "{} of 0x{base:x}",
+        exponentiation.library
+    );
+}
+
+#[test]
+fn named_libraries_agree_with_modpow() {
+    for name in Exponentiation::NAMES {
+        let exponentiation = Exponentiation::named(name).unwrap();
+        let library = library(&exponentiation);
+        let modulus = &exponentiation.modulus;
+        // inputs past the modulus are reduced by the first step
+        let max = (BigUint::from(1u32) << modulus.bits().next_multiple_of(128)) - 1u32;
+        for base in [
+            BigUint::default(),
+            BigUint::from(1u32),
+            BigUint::from(5u32),
+            modulus - 1u32,
+            modulus / 7u32,
+            modulus + 3u32,
+            max,
+        ] {
+            check(&exponentiation, &library, &base);
+        }
+    }
+}
+
+#[test]
+fn wide_moduli() {
+    // the largest limbs, (hi, lo) = (2^128 - 1, 2^256 - 1), and a modulus just over a uint256
+    let one = BigUint::from(1u32);
+    for modulus in [(&one << 384u32) - 1u32, (&one << 256u32) + 297u32] {
+        let exponentiation = Exponentiation::custom("Wide", modulus.clone(), &modulus - 2u32);
+        let library = library(&exponentiation);
+        assert!(library.contains("uint128 x_hi, uint256 x_lo"));
+        for base in [&modulus - 1u32, &modulus - 2u32, (&one << 383u32) + 5u32] {
+            check(&exponentiation, &library, &(base % &modulus));
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    #[test]
+    fn bls12_381_libraries_agree_with_modpow(base: [u8; 48]) {
+        let base = BigUint::from_bytes_be(&base);
+        for name in ["bls12-381-inverse", "bls12-381-sqrt"] {
+            let exponentiation = Exponentiation::named(name).unwrap();
+            check(&exponentiation, &library(&exponentiation), &base);
+        }
+    }
+
+    #[test]
+    fn custom_libraries_agree_with_modpow(
+        modulus: [u8; 48],
+        exponent: [u8; 8],
+        base: [u8; 48],
+        bits in prop::sample::select(vec![64u64, 256, 300, 384]),
+    ) {
+        let bits_mask = (BigUint::from(1u32) << bits) - 1u32;
+        let modulus = BigUint::from_bytes_be(&modulus) & bits_mask;
+        let exponent = BigUint::from_bytes_be(&exponent);
+        prop_assume!(exponent.bits() > 0 && modulus.bits() > 1);
+        let base = BigUint::from_bytes_be(&base) % &modulus;
+
+        let exponentiation = Exponentiation::custom("Custom", modulus, exponent);
+        check(&exponentiation, &library(&exponentiation), &base);
+    }
+}
@@ -0,0 +1,252 @@
+//! Any `t` shares of a simulated DKG interpolate to the group key and sign for it, dealers of
+//! wrong shares are complained against, and disqualified unless they answer.
+
+use bls_solidity_rs::dkg::{self, Complaint, Config, Error, Fault, Transcript};
+use bls_solidity_rs::{Scheme, bls12_381, bn254};
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+const DST: &[u8] = b"BLS_SIG_DKG_TEST";
+const MESSAGE: &[u8] = b"threshold";
+
+fn run(scheme: Scheme, config: &Config) -> Result<Transcript, Error> {
+    dkg::simulate(scheme, config, &mut StdRng::seed_from_u64(1))
+}
+
+/// Lagrange coefficients at 0 of the nodes `indices`.
+fn lagrange<F: PrimeField>(indices: &[usize]) -> Vec<F> {
+    indices
+        .iter()
+        .map(|i| {
+            indices
+                .iter()
+                .filter(|j| *j != i)
+                .map(|j| {
+                    F::from(*j as u64)
+                        * (F::from(*j as u64) - F::from(*i as u64)).inverse().unwrap()
+                })
+                .product()
+        })
+        .collect()
+}
+
+/// The sets of `size` nodes out of `n`.
+fn subsets(n: usize, size: usize) -> impl Iterator<Item = Vec<usize>> {
+    (0u32..1 << n)
+        .filter(move |mask| mask.count_ones() as usize == size)
+        .map(move |mask| (1..=n).filter(|i| mask & (1 << (i - 1)) != 0).collect())
+}
+
+#[test]
+fn any_t_shares_give_the_group_key() {
+    let config = Config {
+        n: 5,
+        t: 3,
+        faults: Vec::new(),
+    };
+    let transcript = run(Scheme::Bn254, &config).unwrap();
+    let shares: Vec<_> = transcript
+        .shares
+        .iter()
+        .map(|s| bn254::SecretKey::from_be_bytes_mod_order(&s.secret_key))
+        .collect();
+    for share in &transcript.shares {
+        assert_eq!(
+            bn254::SecretKey::from_be_bytes_mod_order(&share.secret_key)
+                .public_key()
+                .to_bytes()
+                .to_vec(),
+            share.public_key
+        );
+    }
+    let interpolate = |indices: &[usize]| {
+        let secret: ark_bn254::Fr = lagrange(indices)
+            .into_iter()
+            .zip(indices)
+            .map(|(l, i): (ark_bn254::Fr, _)| l * shares[i - 1].scalar())
+            .sum();
+        bn254::SecretKey::new(secret)
+            .public_key()
+            .to_bytes()
+            .to_vec()
+    };
+    for indices in subsets(config.n, config.t) {
+        assert_eq!(
+            interpolate(&indices),
+            transcript.group_public_key,
+            "{indices:?}"
+        );
+    }
+    for indices in subsets(config.n, config.t - 1) {
+        assert_ne!(
+            interpolate(&indices),
+            transcript.group_public_key,
+            "{indices:?}"
+        );
+    }
+    assert_eq!(transcript.commitments.len(), config.t);
+    assert_eq!(transcript.commitments[0], transcript.group_public_key);
+}
+
+#[test]
+fn threshold_signatures_verify_under_the_group_key() {
+    let config = Config {
+        n: 4,
+        t: 3,
+        faults: Vec::new(),
+    };
+
+    let transcript = run(Scheme::Bn254, &config).unwrap();
+    let group_key = bn254::PublicKey::from_bytes(&transcript.group_public_key).unwrap();
+    let sign = |indices: &[usize]| {
+        let signature = lagrange::<ark_bn254::Fr>(indices)
+            .into_iter()
+            .zip(indices)
+            .map(|(l, i)| {
+                let share = &transcript.shares[i - 1];
+                let partial =
+                    bn254::SecretKey::from_be_bytes_mod_order(&share.secret_key).sign(DST, MESSAGE);
+                // partial signatures are checked against the share public keys
+                let share_key = bn254::PublicKey::from_bytes(&share.public_key).unwrap();
+                assert!(share_key.verify(DST, MESSAGE, &partial));
+                partial.0 * l
+            })
+            .sum::<ark_bn254::G1Projective>();
+        // BLS.g1Marshal, as passed to the contracts
+        bn254::Signature::from_bytes(&bn254::Signature(signature.into_affine()).to_bytes()).unwrap()
+    };
+    for indices in subsets(config.n, config.t) {
+        assert!(
+            group_key.verify(DST, MESSAGE, &sign(&indices)),
+            "{indices:?}"
+        );
+    }
+    assert!(!group_key.verify(DST, MESSAGE, &sign(&[1, 2])));
+
+    for scheme in [Scheme::Bls12381, Scheme::Bls12381Compressed] {
+        let transcript = run(scheme, &config).unwrap();
+        let group_key = bls12_381::PublicKey::from_bytes(&transcript.group_public_key).unwrap();
+        let indices = [2, 3, 4];
+        let signature = lagrange::<ark_bls12_381::Fr>(&indices)
+            .into_iter()
+            .zip(indices)
+            .map(|(l, i)| {
+                let share = &transcript.shares[i - 1];
+                bls12_381::SecretKey::from_be_bytes_mod_order(&share.secret_key)
+                    .sign(DST, MESSAGE)
+                    .0
+                    * l
+            })
+            .sum::<ark_bls12_381::G1Projective>();
+        let signature = bls12_381::Signature(signature.into_affine());
+        let decoded = if scheme == Scheme::Bls12381Compressed {
+            bls12_381::Signature::from_compressed_bytes(&signature.to_compressed_bytes())
+        } else {
+            bls12_381::Signature::from_bytes(&signature.to_bytes())
+        };
+        assert!(group_key.verify(DST, MESSAGE, &decoded.unwrap()));
+    }
+}
+
+#[test]
+fn wrong_shares() {
+    let config = Config {
+        n: 4,
+        t: 3,
+        faults: vec![
+            Fault::WrongShares {
+                dealer: 2,
+                recipients: vec![1, 3],
+                answers: false,
+            },
+            Fault::WrongShares {
+                dealer: 4,
+                recipients: vec![2],
+                answers: true,
+            },
+        ],
+    };
+    let transcript = run(Scheme::Bn254, &config).unwrap();
+    let complaint = |dealer, recipient, answered| Complaint {
+        dealer,
+        recipient,
+        answered,
+    };
+    assert_eq!(
+        transcript.complaints,
+        [
+            complaint(2, 1, false),
+            complaint(2, 3, false),
+            complaint(4, 2, true)
+        ]
+    );
+    let qualified: Vec<_> = transcript
+        .deals
+        .iter()
+        .map(|d| (d.dealer, d.qualified))
+        .collect();
+    assert_eq!(qualified, [(1, true), (2, false), (3, true), (4, true)]);
+
+    // the group key leaves the disqualified dealer out
+    let group_key = transcript
+        .deals
+        .iter()
+        .filter(|d| d.qualified)
+        .map(|d| bn254::g2_unmarshal(&d.commitments[0]).unwrap().into_group())
+        .sum::<ark_bn254::G2Projective>();
+    assert_eq!(
+        bn254::g2_marshal(&group_key.into_affine()).to_vec(),
+        transcript.group_public_key
+    );
+    let indices = [1, 2, 3];
+    let secret: ark_bn254::Fr = lagrange(&indices)
+        .into_iter()
+        .zip(indices)
+        .map(|(l, i): (ark_bn254::Fr, _)| {
+            l * bn254::SecretKey::from_be_bytes_mod_order(&transcript.shares[i - 1].secret_key)
+                .scalar()
+        })
+        .sum();
+    assert_eq!(
+        bn254::SecretKey::new(secret)
+            .public_key()
+            .to_bytes()
+            .to_vec(),
+        transcript.group_public_key
+    );
+}
+
+#[test]
+fn invalid_runs() {
+    let config = |n, t, faults| Config { n, t, faults };
+    assert_eq!(
+        run(Scheme::Bn254, &config(3, 0, Vec::new())),
+        Err(Error::InvalidThreshold { n: 3, t: 0 })
+    );
+    assert_eq!(
+        run(Scheme::Bn254, &config(3, 4, Vec::new())),
+        Err(Error::InvalidThreshold { n: 3, t: 4 })
+    );
+    assert_eq!(
+        run(
+            Scheme::Bn254,
+            &config(3, 2, vec![Fault::Absent { dealer: 4 }])
+        ),
+        Err(Error::UnknownParticipant(4))
+    );
+    let faults = vec![
+        Fault::Absent { dealer: 1 },
+        Fault::WrongShares {
+            dealer: 2,
+            recipients: vec![3],
+            answers: false,
+        },
+    ];
+    assert_eq!(
+        run(Scheme::Bn254, &config(3, 2, faults)),
+        Err(Error::NotEnoughQualifiedDealers { qualified: 1, t: 2 })
+    );
+}
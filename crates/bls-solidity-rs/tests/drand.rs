@@ -0,0 +1,242 @@
+//! Round timing of the drand chains, their chain info JSON, and beacons verified like the
+//! registries do, against the mainnet beacons of test/data/testcases.json.
+
+use bls_solidity_rs::drand::{Beacon, ChainInfo, DrandScheme, Error, TestChain};
+
+use proptest::prelude::*;
+use serde_json::Value;
+
+const TESTCASES: &str = include_str!("../../../test/data/testcases.json");
+
+/// Quicknet's `groupHash`, which its chain hash is computed from.
+const QUICKNET_GENESIS_SEED: &str =
+    "f477d5c89f21a17c863a7f937c6a6d15859414d2be09cd448d4279af331c5d3e";
+
+/// The beacon of `round` in test/data/testcases.json, with the signature `proveRound` takes.
+fn mainnet_beacon(round: u64) -> Beacon {
+    let testcases: Value = serde_json::from_str(TESTCASES).unwrap();
+    let testcase = testcases
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|tc| tc["drand_round_number"] == round)
+        .unwrap();
+    let signature = match testcase["scheme"].as_str().unwrap() {
+        "BLS12381" => &testcase["sig_compressed"],
+        _ => &testcase["sig"],
+    };
+    Beacon {
+        round,
+        signature: hex::decode(signature.as_str().unwrap()).unwrap(),
+        randomness: None,
+    }
+}
+
+fn quicknet_v1_json(hash: &str) -> String {
+    format!(
+        r#"{{
+            "public_key": "{}",
+            "period": 3,
+            "genesis_time": 1692803367,
+            "hash": "{hash}",
+            "groupHash": "{QUICKNET_GENESIS_SEED}",
+            "schemeID": "bls-unchained-g1-rfc9380",
+            "metadata": {{"beaconID": "quicknet"}}
+        }}"#,
+        hex::encode(ChainInfo::quicknet().public_key)
+    )
+}
+
+#[test]
+fn round_timing() {
+    for chain in [ChainInfo::quicknet(), ChainInfo::evmnet()] {
+        let genesis = chain.genesis_time;
+        let period = chain.period;
+        assert_eq!(chain.round_at(0), 0);
+        assert_eq!(chain.round_at(genesis - 1), 0);
+        assert_eq!(chain.round_at(genesis), 1);
+        assert_eq!(chain.round_at(genesis + period - 1), 1);
+        assert_eq!(chain.round_at(genesis + period), 2);
+
+        assert_eq!(chain.time_of_round(0), Some(genesis));
+        assert_eq!(chain.time_of_round(1), Some(genesis));
+        assert_eq!(chain.time_of_round(2), Some(genesis + period));
+
+        assert_eq!(chain.next_round(genesis - 1), Some((1, genesis)));
+        assert_eq!(chain.next_round(genesis), Some((2, genesis + period)));
+        assert_eq!(
+            chain.next_round(genesis + period - 1),
+            Some((2, genesis + period))
+        );
+
+        assert_eq!(chain.time_of_round(u64::MAX), None);
+        assert_eq!(chain.next_round(u64::MAX), None);
+    }
+
+    let quicknet = ChainInfo::quicknet();
+    assert_eq!(quicknet.time_of_round(20791007), Some(1755176385));
+    assert_eq!(quicknet.round_at(1755176385), 20791007);
+    assert_eq!(quicknet.round_at(1755176387), 20791007);
+    assert_eq!(quicknet.round_at(1755176388), 20791008);
+
+    let evmnet = ChainInfo::evmnet();
+    assert_eq!(evmnet.time_of_round(9337227), Some(1755532753));
+    assert_eq!(evmnet.round_at(1755532753), 9337227);
+    assert_eq!(evmnet.next_round(1755532753), Some((9337228, 1755532756)));
+}
+
+proptest! {
+    #[test]
+    fn rounds_are_emitted_at_their_time(round in 1u64..1 << 40, offset in 0u64..3) {
+        let chain = ChainInfo::quicknet();
+        let time = chain.time_of_round(round).unwrap();
+        prop_assert_eq!(chain.round_at(time + offset), round);
+        prop_assert_eq!(chain.next_round(time + offset), Some((round + 1, time + chain.period)));
+    }
+}
+
+#[test]
+fn chain_info_json() {
+    let quicknet = ChainInfo::quicknet();
+    let hash = hex::encode(&quicknet.chain_hash);
+    assert_eq!(
+        ChainInfo::from_json(&quicknet_v1_json(&hash)).unwrap(),
+        quicknet
+    );
+    let v2 = format!(
+        r#"{{
+            "public_key": "{}",
+            "period": 3,
+            "genesis_time": 1692803367,
+            "genesis_seed": "{QUICKNET_GENESIS_SEED}",
+            "chain_hash": "{hash}",
+            "scheme": "bls-unchained-g1-rfc9380",
+            "beacon_id": "quicknet"
+        }}"#,
+        hex::encode(&quicknet.public_key)
+    );
+    assert_eq!(ChainInfo::from_json(&v2).unwrap(), quicknet);
+
+    for chain in [quicknet, ChainInfo::evmnet()] {
+        assert_eq!(ChainInfo::from_json(&chain.to_json()).unwrap(), chain);
+    }
+}
+
+#[test]
+fn invalid_chain_info_json() {
+    let quicknet = ChainInfo::quicknet();
+    let mut hash = quicknet.chain_hash.clone();
+    hash[0] ^= 1;
+    assert_eq!(
+        ChainInfo::from_json(&quicknet_v1_json(&hex::encode(hash))),
+        Err(Error::ChainHashMismatch)
+    );
+
+    let json = quicknet.to_json();
+    assert!(matches!(
+        ChainInfo::from_json(&json.replace(r#""period":3"#, r#""period":0"#)),
+        Err(Error::Json(_))
+    ));
+    assert_eq!(
+        ChainInfo::from_json(&json.replace("bls-unchained-g1-rfc9380", "pedersen-bls-chained")),
+        Err(Error::UnsupportedScheme("pedersen-bls-chained".to_owned()))
+    );
+    assert!(matches!(
+        ChainInfo::from_json(&json.replace("83cf0f", "03cf0f")),
+        Err(Error::InvalidPoint(_))
+    ));
+    assert_eq!(
+        ChainInfo::from_json(&json.replace(r#""chain_hash":"52"#, r#""chain_hash":"5x"#)),
+        Err(Error::InvalidHex("chain_hash"))
+    );
+}
+
+#[test]
+fn mainnet_beacons_verify() {
+    for (chain, round) in [
+        (ChainInfo::quicknet(), 20791007),
+        (ChainInfo::evmnet(), 9337227),
+    ] {
+        let beacon = mainnet_beacon(round);
+        assert_eq!(beacon.signature.len(), chain.scheme.signature_length());
+        assert_eq!(chain.verify(&beacon), Ok(()));
+        assert_eq!(Beacon::from_json(&beacon.to_json()).unwrap(), beacon);
+
+        // v1 beacons also carry the randomness
+        let mut with_randomness = beacon.clone();
+        with_randomness.randomness = Some(beacon.expected_randomness().to_vec());
+        assert_eq!(chain.verify(&with_randomness), Ok(()));
+        with_randomness.randomness = Some(vec![0; 32]);
+        assert_eq!(
+            chain.verify(&with_randomness),
+            Err(Error::RandomnessMismatch)
+        );
+
+        let wrong_round = Beacon {
+            round: round + 1,
+            ..beacon.clone()
+        };
+        assert_eq!(chain.verify(&wrong_round), Err(Error::InvalidSignature));
+    }
+
+    // the signature of another round is a valid point that does not verify
+    let mut tampered = mainnet_beacon(20905307);
+    tampered.round = 20791007;
+    assert_eq!(
+        ChainInfo::quicknet().verify(&tampered),
+        Err(Error::InvalidSignature)
+    );
+    // a flipped bit gives no point, or a point that does not verify
+    for (chain, round) in [
+        (ChainInfo::quicknet(), 20791007),
+        (ChainInfo::evmnet(), 9337227),
+    ] {
+        let mut tampered = mainnet_beacon(round);
+        let last = tampered.signature.len() - 1;
+        tampered.signature[last] ^= 1;
+        assert!(matches!(
+            chain.verify(&tampered),
+            Err(Error::InvalidPoint(_) | Error::InvalidSignature)
+        ));
+    }
+    // beacons of one chain do not verify on the other
+    assert!(
+        ChainInfo::evmnet()
+            .verify(&mainnet_beacon(20791007))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_chain_beacons_verify() {
+    for scheme in [
+        DrandScheme::UnchainedG1Rfc9380,
+        DrandScheme::Bn254UnchainedOnG1,
+    ] {
+        let chain = TestChain::new(scheme, b"seed", 3, 1_700_000_000, Some("test".to_owned()));
+        assert_eq!(
+            ChainInfo::from_json(&chain.info.to_json()).unwrap(),
+            chain.info
+        );
+        for round in [1, 2, 1000] {
+            let beacon = chain.beacon(round);
+            assert_eq!(beacon.signature.len(), scheme.signature_length());
+            assert_eq!(chain.info.verify(&beacon), Ok(()));
+        }
+
+        // test chains have no genesis seed
+        let json = chain
+            .info
+            .to_json()
+            .replacen('{', r#"{"genesis_seed":"","#, 1);
+        assert_eq!(ChainInfo::from_json(&json).unwrap(), chain.info);
+
+        // the key depends on the scheme and the seed
+        let other = TestChain::new(scheme, b"other", 3, 1_700_000_000, Some("test".to_owned()));
+        assert_ne!(other.info.public_key, chain.info.public_key);
+        assert_eq!(
+            chain.info.verify(&other.beacon(1)),
+            Err(Error::InvalidSignature)
+        );
+    }
+}
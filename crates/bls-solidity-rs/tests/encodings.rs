@@ -0,0 +1,364 @@
+//! Round trips of the point encodings of `BLS.sol`, `BLS2.sol`, the EIP-2537 precompiles and the
+//! public key formats, and agreement between the compressed and uncompressed encodings.
+
+use bls_solidity_rs::compressed;
+use bls_solidity_rs::pubkey::{self, Format, PublicKey};
+use bls_solidity_rs::{Curve, Error, bls12_381, bn254, eip2537, sol};
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField};
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+use std::ops::Range;
+
+fn point<P: AffineRepr>() -> impl Strategy<Value = P> {
+    any::<[u8; 32]>().prop_map(|scalar| {
+        (P::generator() * P::ScalarField::from_be_bytes_mod_order(&scalar)).into_affine()
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn bn254_marshal_round_trips(
+        g1 in point::<ark_bn254::G1Affine>(),
+        g2 in point::<ark_bn254::G2Affine>(),
+    ) {
+        prop_assert_eq!(bn254::g1_unmarshal(&bn254::g1_marshal(&g1)), Ok(g1));
+        prop_assert_eq!(bn254::g2_unmarshal(&bn254::g2_marshal(&g2)), Ok(g2));
+
+        let sol_g1 = sol::bls::PointG1::from(g1);
+        let sol_g2 = sol::bls::PointG2::from(g2);
+        prop_assert_eq!(sol::bls::PointG1::abi_decode(&sol_g1.abi_encode()), Ok(sol_g1));
+        prop_assert_eq!(sol::bls::PointG2::abi_decode(&sol_g2.abi_encode()), Ok(sol_g2));
+        prop_assert_eq!(ark_bn254::G1Affine::try_from(sol_g1), Ok(g1));
+        prop_assert_eq!(ark_bn254::G2Affine::try_from(sol_g2), Ok(g2));
+        // g1Marshal is abi.encode of the point, g2Marshal swaps the Fp2 coefficients
+        prop_assert_eq!(sol_g1.abi_encode(), bn254::g1_marshal(&g1));
+        let [x0, x1, y0, y1] = [sol_g2.x[0], sol_g2.x[1], sol_g2.y[0], sol_g2.y[1]];
+        let swapped = [x1, x0, y1, y0];
+        prop_assert_eq!(swapped.as_flattened(), &bn254::g2_marshal(&g2)[..]);
+    }
+
+    #[test]
+    fn bls12_381_marshal_round_trips(
+        g1 in point::<ark_bls12_381::G1Affine>(),
+        g2 in point::<ark_bls12_381::G2Affine>(),
+    ) {
+        prop_assert_eq!(bls12_381::g1_unmarshal(&bls12_381::g1_marshal(&g1)), Ok(g1));
+        prop_assert_eq!(bls12_381::g2_unmarshal(&bls12_381::g2_marshal(&g2)), Ok(g2));
+
+        let sol_g1 = sol::bls2::PointG1::from(g1);
+        let sol_g2 = sol::bls2::PointG2::from(g2);
+        prop_assert_eq!(sol::bls2::PointG1::abi_decode(&sol_g1.abi_encode()), Ok(sol_g1));
+        prop_assert_eq!(sol::bls2::PointG2::abi_decode(&sol_g2.abi_encode()), Ok(sol_g2));
+        prop_assert_eq!(ark_bls12_381::G1Affine::try_from(sol_g1), Ok(g1));
+        prop_assert_eq!(ark_bls12_381::G2Affine::try_from(sol_g2), Ok(g2));
+    }
+
+    #[test]
+    fn eip2537_round_trips(
+        g1 in point::<ark_bls12_381::G1Affine>(),
+        g2 in point::<ark_bls12_381::G2Affine>(),
+    ) {
+        let encoded_g1 = eip2537::g1_encode(&g1);
+        let encoded_g2 = eip2537::g2_encode(&g2);
+        prop_assert_eq!(eip2537::g1_decode(&encoded_g1), Ok(g1));
+        prop_assert_eq!(eip2537::g2_decode(&encoded_g2), Ok(g2));
+
+        // the same big-endian coordinates as g1Marshal, left padded to 64 bytes
+        let marshalled = bls12_381::g1_marshal(&g1);
+        for (padded, coordinate) in encoded_g1.chunks(64).zip(marshalled.chunks(48)) {
+            prop_assert_eq!(&padded[..16], &[0u8; 16][..]);
+            prop_assert_eq!(&padded[16..], coordinate);
+        }
+
+        let mut padding = encoded_g1;
+        padding[15] = 1;
+        prop_assert_eq!(eip2537::g1_decode(&padding), Err(Error::NonZeroPadding));
+    }
+
+    #[test]
+    fn compressed_g1_matches_uncompressed(p in point::<ark_bls12_381::G1Affine>()) {
+        let compressed = compressed::g1_compress(&p).unwrap();
+        prop_assert_eq!(compressed, bls12_381::g1_marshal_compressed(&p));
+        prop_assert_eq!(compressed::g1_decompress(&compressed), Ok(p));
+        prop_assert_eq!(
+            compressed::g1_unmarshal_compressed(&compressed),
+            Ok(sol::bls2::PointG1::from(p))
+        );
+        prop_assert_eq!(bls12_381::g1_unmarshal_compressed(&compressed), Ok(p));
+        // x is the uncompressed x with the flags
+        let uncompressed = bls12_381::g1_marshal(&p);
+        prop_assert_eq!(compressed[0] & 0x1f, uncompressed[0]);
+        prop_assert_eq!(&compressed[1..], &uncompressed[1..48]);
+        prop_assert_eq!(
+            compressed[0] & compressed::COMPRESSION_FLAG,
+            compressed::COMPRESSION_FLAG
+        );
+    }
+
+    #[test]
+    fn compressed_g2_matches_uncompressed(p in point::<ark_bls12_381::G2Affine>()) {
+        let compressed = compressed::g2_compress(&p).unwrap();
+        prop_assert_eq!(compressed, bls12_381::g2_marshal_compressed(&p));
+        prop_assert_eq!(compressed::g2_decompress(&compressed), Ok(p));
+        prop_assert_eq!(bls12_381::g2_unmarshal_compressed(&compressed), Ok(p));
+        let uncompressed = bls12_381::g2_marshal(&p);
+        prop_assert_eq!(compressed[0] & 0x1f, uncompressed[0]);
+        prop_assert_eq!(&compressed[1..], &uncompressed[1..96]);
+    }
+
+    #[test]
+    fn negated_points_flip_the_sort_flag(p in point::<ark_bls12_381::G1Affine>()) {
+        let compressed = compressed::g1_compress(&p).unwrap();
+        let negated = compressed::g1_compress(&-p).unwrap();
+        prop_assert_eq!(compressed[0] ^ negated[0], compressed::SORT_FLAG);
+        prop_assert_eq!(&compressed[1..], &negated[1..]);
+    }
+
+    #[test]
+    fn public_key_formats_round_trip(
+        bn254_key in point::<ark_bn254::G2Affine>(),
+        bls12_381_key in point::<ark_bls12_381::G2Affine>(),
+    ) {
+        let keys = [
+            PublicKey::Bn254(bn254::PublicKey(bn254_key)),
+            PublicKey::Bls12381(bls12_381::PublicKey(bls12_381_key)),
+        ];
+        for key in keys {
+            let curve = key.curve();
+            for from in Format::ALL {
+                let Ok(bytes) = key.encode(from) else {
+                    prop_assert!(!from.supports(curve));
+                    prop_assert!(PublicKey::decode(curve, from, &[]).is_err());
+                    continue;
+                };
+                prop_assert_eq!(PublicKey::decode(curve, from, &bytes), Ok(key));
+                for to in Format::ALL.into_iter().filter(|to| to.supports(curve)) {
+                    prop_assert_eq!(pubkey::convert(curve, from, to, &bytes), key.encode(to));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn non_canonical_coordinates_are_rejected(bytes: [u8; 64]) {
+        // p < 2^254 on BN254, and the top three bits of a BLS12-381 coordinate are zero
+        let mut bn254_bytes = bytes;
+        bn254_bytes[0] |= 0xc0;
+        prop_assert_eq!(
+            bn254::g1_unmarshal(&bn254_bytes),
+            Err(Error::NonCanonicalFieldElement)
+        );
+
+        let mut bls12_381_bytes = [bytes, bytes].concat();
+        bls12_381_bytes.truncate(bls12_381::G1_LENGTH);
+        bls12_381_bytes[0] |= 0xe0;
+        prop_assert_eq!(
+            bls12_381::g1_unmarshal(&bls12_381_bytes),
+            Err(Error::NonCanonicalFieldElement)
+        );
+    }
+}
+
+#[test]
+fn infinity_is_all_zeros() {
+    let bn254_g2 = ark_bn254::G2Affine::zero();
+    let bls12_381_g1 = ark_bls12_381::G1Affine::zero();
+    assert_eq!(bn254::g2_marshal(&bn254_g2), [0; bn254::G2_LENGTH]);
+    assert_eq!(bn254::g2_unmarshal(&[0; bn254::G2_LENGTH]), Ok(bn254_g2));
+    assert_eq!(
+        bls12_381::g1_marshal(&bls12_381_g1),
+        [0; bls12_381::G1_LENGTH]
+    );
+    assert_eq!(
+        eip2537::g1_decode(&[0; eip2537::G1_LENGTH]),
+        Ok(bls12_381_g1)
+    );
+    // BLS2.g1UnmarshalCompressed has no encoding for it
+    assert_eq!(
+        compressed::g1_compress(&bls12_381_g1),
+        Err(Error::InfinityUnsupported)
+    );
+}
+
+/// A compressed G1 point with x the given field element, without flags.
+fn compressed_x(x: &[u8]) -> [u8; compressed::G1_LENGTH] {
+    let mut bytes = [0u8; compressed::G1_LENGTH];
+    bytes[compressed::G1_LENGTH - x.len()..].copy_from_slice(x);
+    bytes[0] |= compressed::COMPRESSION_FLAG;
+    bytes
+}
+
+#[test]
+fn compressed_flags() {
+    let g = ark_bls12_381::G1Affine::generator();
+    let bytes = compressed::g1_compress(&g).unwrap();
+    for flags in [0x00, compressed::SORT_FLAG, compressed::INFINITY_FLAG] {
+        let mut missing = bytes;
+        missing[0] = bytes[0] & 0x1f | flags;
+        assert_eq!(
+            compressed::g1_decompress(&missing),
+            Err(Error::MissingCompressionFlag)
+        );
+        assert_eq!(
+            compressed::g1_unmarshal_compressed(&missing),
+            Err(Error::MissingCompressionFlag)
+        );
+    }
+    let mut infinity = bytes;
+    infinity[0] |= compressed::INFINITY_FLAG;
+    assert_eq!(
+        compressed::g1_decompress(&infinity),
+        Err(Error::InfinityUnsupported)
+    );
+    assert_eq!(
+        compressed::g1_unmarshal_compressed(&infinity),
+        Err(Error::InfinityUnsupported)
+    );
+
+    // the sort flag selects the largest y, both in Rust and in the contract
+    let mut flipped = bytes;
+    flipped[0] ^= compressed::SORT_FLAG;
+    assert_eq!(compressed::g1_decompress(&flipped), Ok(-g));
+    assert_eq!(
+        compressed::g1_unmarshal_compressed(&flipped),
+        Ok(sol::bls2::PointG1::from(-g))
+    );
+    let (_, y) = g.xy().unwrap();
+    assert_eq!(bytes[0] & compressed::SORT_FLAG != 0, y > -y);
+    assert_eq!(
+        compressed::g1_decompress(&bytes[..47]),
+        Err(Error::InvalidLength {
+            expected: 48,
+            actual: 47
+        })
+    );
+}
+
+#[test]
+fn compressed_x_not_reduced() {
+    // x + p still fits in the 381 bits below the flags for about a quarter of the points
+    let modulus = BigUint::from(ark_bls12_381::Fq::MODULUS);
+    let (p, x) = (1u64..)
+        .map(|k| (ark_bls12_381::G1Affine::generator() * ark_bls12_381::Fr::from(k)).into_affine())
+        .find_map(|p| {
+            let x = BigUint::from(p.x().unwrap().into_bigint()) + &modulus;
+            (x.bits() <= 381).then_some((p, x))
+        })
+        .unwrap();
+    let mut bytes = compressed_x(&x.to_bytes_be());
+    bytes[0] |= compressed::g1_compress(&p).unwrap()[0];
+
+    assert_eq!(
+        compressed::g1_decompress(&bytes),
+        Err(Error::NonCanonicalFieldElement)
+    );
+    // the contract keeps the x limbs as they are, with the y of x - p
+    let point = compressed::g1_unmarshal_compressed(&bytes).unwrap();
+    assert_eq!(point.y_lo, sol::bls2::PointG1::from(p).y_lo);
+    assert_eq!(point.y_hi, sol::bls2::PointG1::from(p).y_hi);
+    assert_eq!(&point.x_lo[..], &bytes[16..]);
+    assert_eq!(point.x_hi.to_be_bytes()[0], bytes[0] & 0x1f);
+    assert_eq!(
+        ark_bls12_381::G1Affine::try_from(point),
+        Err(Error::NonCanonicalFieldElement)
+    );
+}
+
+#[test]
+fn compressed_x_not_on_curve() {
+    let x = (1u64..)
+        .map(ark_bls12_381::Fq::from)
+        .find(|x| {
+            (*x * x * x + ark_bls12_381::Fq::from(4u64))
+                .sqrt()
+                .is_none()
+        })
+        .unwrap();
+    let bytes = compressed_x(&x.into_bigint().to_bytes_be());
+
+    assert_eq!(compressed::g1_decompress(&bytes), Err(Error::NotOnCurve));
+    // the contract returns (x^3 + 4)^((p + 1) / 4), which is not a square root
+    let point = compressed::g1_unmarshal_compressed(&bytes).unwrap();
+    let modulus = BigUint::from(ark_bls12_381::Fq::MODULUS);
+    let c = BigUint::from((x * x * x).into_bigint()) + 4u32;
+    let y = c.modpow(&((&modulus + 1u32) / 4u32), &modulus);
+    let y = y.clone().min(&modulus - y);
+    let limbs = [&point.y_hi.to_be_bytes()[..], &point.y_lo].concat();
+    assert_eq!(BigUint::from_bytes_be(&limbs), y);
+    assert_eq!(
+        ark_bls12_381::G1Affine::try_from(point),
+        Err(Error::NotOnCurve)
+    );
+}
+
+/// Decoding errors of the arkworks format, on a point that is not in the subgroup and on the
+/// encoding of the generator with the byte holding the flags, a byte of y and a coordinate without
+/// flags changed.
+fn check_arkworks_errors<P: AffineRepr>(
+    curve: Curve,
+    not_in_subgroup: P,
+    flags_byte: usize,
+    y_byte: usize,
+    coordinate: Range<usize>,
+) {
+    let encode = |p: &P| {
+        let mut bytes = Vec::new();
+        p.serialize_uncompressed(&mut bytes).unwrap();
+        bytes
+    };
+    let decode = |bytes: &[u8]| PublicKey::decode(curve, Format::Arkworks, bytes);
+    let key = encode(&P::generator());
+    assert!(decode(&key).is_ok());
+    assert_eq!(
+        decode(&key[1..]),
+        Err(Error::InvalidLength {
+            expected: key.len(),
+            actual: key.len() - 1
+        })
+    );
+    assert_eq!(decode(&encode(&not_in_subgroup)), Err(Error::NotInSubgroup));
+
+    let mut off_curve = key.clone();
+    off_curve[y_byte] ^= 1;
+    assert_eq!(decode(&off_curve), Err(Error::NotOnCurve));
+
+    // 0x80 is the sign of y on BN254, which arkworks ignores, and the compression flag on
+    // BLS12-381
+    for flags in [0x80, 0x40, 0xc0] {
+        let mut flagged = key.clone();
+        flagged[flags_byte] |= flags;
+        assert_eq!(
+            decode(&flagged),
+            Err(Error::InvalidArkworksEncoding),
+            "flags {flags:#x}"
+        );
+    }
+    let mut non_canonical = key;
+    non_canonical[coordinate].fill(0xff);
+    assert_eq!(decode(&non_canonical), Err(Error::InvalidArkworksEncoding));
+}
+
+#[test]
+fn arkworks_decoding_errors() {
+    // x such that x^3 + b is a square, the curves have no other points with that x
+    let bn254_x = (1u64..)
+        .map(|c0| ark_bn254::Fq2::new(c0.into(), 1u64.into()))
+        .find_map(|x| ark_bn254::G2Affine::get_point_from_x_unchecked(x, false))
+        .unwrap();
+    let bls12_381_x = (1u64..)
+        .map(|c0| ark_bls12_381::Fq2::new(c0.into(), 1u64.into()))
+        .find_map(|x| ark_bls12_381::G2Affine::get_point_from_x_unchecked(x, false))
+        .unwrap();
+    assert!(!bn254_x.is_in_correct_subgroup_assuming_on_curve());
+    assert!(!bls12_381_x.is_in_correct_subgroup_assuming_on_curve());
+
+    // BN254 is little-endian x.c0 || x.c1 || y.c0 || y.c1 with the flags in the last byte
+    check_arkworks_errors(Curve::Bn254, bn254_x, 127, 64, 0..32);
+    // BLS12-381 is big-endian x.c1 || x.c0 || y.c1 || y.c0 with the flags in the first byte
+    check_arkworks_errors(Curve::Bls12381, bls12_381_x, 0, 191, 48..96);
+}
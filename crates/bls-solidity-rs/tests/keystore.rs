@@ -0,0 +1,205 @@
+//! Keystores decrypt the EIP-2335 test vectors, round trip through JSON and only hold canonical
+//! secrets.
+
+use bls_solidity_rs::keystore::{self, Error, Kdf, Keystore};
+use bls_solidity_rs::{Curve, bls12_381, bn254};
+
+use ark_ff::{BigInteger, PrimeField, Zero};
+use proptest::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Password of the EIP-2335 test vectors, "testpassword🔑" once normalized.
+const PASSWORD: &str = "𝔱𝔢𝔰𝔱𝔭𝔞𝔰𝔰𝔴𝔬𝔯𝔡🔑";
+const SECRET: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+/// The EIP-2335 test vector with `kdf`, `checksum` and `cipher_message`, and the `curve` field
+/// keystores of this crate add.
+fn eip2335_vector(kdf: &str, checksum: &str, cipher_message: &str) -> String {
+    format!(
+        r#"{{
+            "crypto": {{
+                "kdf": {kdf},
+                "checksum": {{"function": "sha256", "params": {{}}, "message": "{checksum}"}},
+                "cipher": {{
+                    "function": "aes-128-ctr",
+                    "params": {{"iv": "264daa3f303d7259501c93d997d84fe6"}},
+                    "message": "{cipher_message}"
+                }}
+            }},
+            "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+            "path": "m/12381/60/0/0",
+            "uuid": "1d85ae20-35c5-4611-98e8-aa14a633906f",
+            "version": 4,
+            "curve": "bls12-381"
+        }}"#
+    )
+}
+
+fn check_vector(json: &str) {
+    let keystore = Keystore::from_json(json).unwrap();
+    assert_eq!(hex::encode(keystore.decrypt(PASSWORD).unwrap()), SECRET);
+    assert_eq!(
+        keystore.decrypt("testpassword"),
+        Err(Error::InvalidPassword)
+    );
+    // the pubkey of EIP-2335 is on G1, the public keys of the signature schemes are on G2
+    assert_eq!(
+        keystore.unlock(Curve::Bls12381, PASSWORD),
+        Err(Error::PublicKeyMismatch)
+    );
+}
+
+#[test]
+fn eip2335_scrypt_vector() {
+    check_vector(&eip2335_vector(
+        r#"{
+            "function": "scrypt",
+            "params": {
+                "dklen": 32, "n": 262144, "p": 1, "r": 8,
+                "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+            },
+            "message": ""
+        }"#,
+        "d2217fe5f3e9a1e34581ef8a78f7c9928e436d36dacc5e846690a5581e8ea484",
+        "06ae90d55fe0a6e9c5c3bc5b170827b2e5cce3929ed3f116c2811e6366dfe20f",
+    ));
+}
+
+#[test]
+fn eip2335_pbkdf2_vector() {
+    check_vector(&eip2335_vector(
+        r#"{
+            "function": "pbkdf2",
+            "params": {
+                "dklen": 32, "c": 262144, "prf": "hmac-sha256",
+                "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+            },
+            "message": ""
+        }"#,
+        "8a9f5d9912ed7e75ea794bc5a89bca5f193721d30868ade6f73043c6ea6febf1",
+        "cee03fde2af33149775b7223e7845e4fb2c8ae1792e5f99fe9ecf474cc8c16ad",
+    ));
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = StdRng::seed_from_u64(2335);
+    let secret: [u8; 32] = hex::decode(SECRET).unwrap().try_into().unwrap();
+    // cheaper parameters than EIP-2335's, which the format allows
+    let kdfs = [
+        Kdf::Scrypt {
+            n: 1 << 10,
+            r: 8,
+            p: 1,
+            salt: vec![1; 32],
+        },
+        Kdf::Pbkdf2 {
+            c: 1 << 10,
+            salt: vec![2; 32],
+        },
+    ];
+    for curve in [Curve::Bn254, Curve::Bls12381] {
+        for kdf in kdfs.clone() {
+            let keystore =
+                Keystore::encrypt(curve, &secret, PASSWORD, kdf, "m/12381/60/0/0", &mut rng)
+                    .unwrap();
+            let read = Keystore::from_json(&keystore.to_json()).unwrap();
+            assert_eq!(read, keystore);
+            assert_eq!(
+                Some(read.pubkey.clone()),
+                keystore::public_key(curve, &secret)
+            );
+            // the password is normalized before the key derivation
+            assert_eq!(read.unlock(curve, "testpassword🔑"), Ok(secret));
+            assert_eq!(
+                read.unlock(curve, "testpassword"),
+                Err(Error::InvalidPassword)
+            );
+        }
+    }
+
+    let keystore = Keystore::encrypt(
+        Curve::Bn254,
+        &secret,
+        PASSWORD,
+        kdfs[1].clone(),
+        "",
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        keystore.unlock_bls12_381(PASSWORD).err(),
+        Some(Error::CurveMismatch {
+            expected: Curve::Bls12381,
+            actual: Curve::Bn254
+        })
+    );
+    assert_eq!(
+        keystore
+            .unlock_bn254(PASSWORD)
+            .unwrap()
+            .public_key()
+            .to_bytes(),
+        bn254::SecretKey::from_be_bytes_mod_order(&secret)
+            .public_key()
+            .to_bytes()
+    );
+    assert_eq!(
+        Keystore::encrypt(
+            Curve::Bn254,
+            &[0; 32],
+            PASSWORD,
+            kdfs[1].clone(),
+            "",
+            &mut rng
+        ),
+        Err(Error::InvalidSecretKey)
+    );
+}
+
+/// Big-endian bytes of `modulus + k`, which the secret keys reduce to `k`.
+fn modulus_plus<F: PrimeField>(k: u64) -> [u8; 32] {
+    let mut n = F::MODULUS;
+    assert!(!n.add_with_carry(&F::BigInt::from(k)));
+    n.to_bytes_be().try_into().unwrap()
+}
+
+#[test]
+fn zero_secret_key() {
+    let zero = [0u8; 32];
+    assert_eq!(keystore::public_key(Curve::Bn254, &zero), None);
+    assert_eq!(keystore::public_key(Curve::Bls12381, &zero), None);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn non_canonical_secrets(k: u64) {
+        // keystores only hold canonical secrets
+        let bn254_secret = modulus_plus::<ark_bn254::Fr>(k);
+        let bls12_381_secret = modulus_plus::<ark_bls12_381::Fr>(k);
+        prop_assert_eq!(keystore::public_key(Curve::Bn254, &bn254_secret), None);
+        prop_assert_eq!(keystore::public_key(Curve::Bls12381, &bls12_381_secret), None);
+    }
+
+    #[test]
+    fn keystore_public_keys_match(secret: [u8; 32]) {
+        let bn254_sk = bn254::SecretKey::from_be_bytes_mod_order(&secret);
+        let canonical = bn254_sk.scalar().into_bigint().to_bytes_be() == secret;
+        prop_assert_eq!(
+            keystore::public_key(Curve::Bn254, &secret),
+            (canonical && !bn254_sk.scalar().is_zero())
+                .then(|| bn254_sk.public_key().to_bytes().to_vec())
+        );
+
+        let bls12_381_sk = bls12_381::SecretKey::from_be_bytes_mod_order(&secret);
+        let canonical = bls12_381_sk.scalar().into_bigint().to_bytes_be() == secret;
+        prop_assert_eq!(
+            keystore::public_key(Curve::Bls12381, &secret),
+            (canonical && !bls12_381_sk.scalar().is_zero())
+                .then(|| bls12_381_sk.public_key().to_bytes().to_vec())
+        );
+    }
+}
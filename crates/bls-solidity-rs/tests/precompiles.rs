@@ -0,0 +1,252 @@
+//! The precompile emulation on the inputs `BLS.sol` and `BLS2.sol` build, and the length, padding
+//! and point checks that make a call fail.
+
+use bls_solidity_rs::precompiles::{self, ECADD, ECMUL, ECPAIRING, MODEXP};
+use bls_solidity_rs::{Error, bls12_381, bn254, eip2537};
+
+use ark_ec::short_weierstrass::Affine;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+use ark_ff::{BigInteger, Field, LegendreSymbol, PrimeField};
+
+const DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+const MESSAGE: &[u8] = b"message";
+
+fn uint(f: impl PrimeField) -> Vec<u8> {
+    f.into_bigint().to_bytes_be()
+}
+
+#[test]
+fn ecadd_vector() {
+    // EIP-196 doubling of the generator (1, 2)
+    let mut input = [0u8; 128];
+    input[31] = 1;
+    input[63] = 2;
+    input[95] = 1;
+    input[127] = 2;
+    assert_eq!(
+        hex::encode(precompiles::ecadd(&input).unwrap()),
+        "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+         15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"
+    );
+}
+
+#[test]
+fn bn254_inputs_are_padded_and_truncated() {
+    let g = ark_bn254::G1Affine::generator();
+    let p = (g * ark_bn254::Fr::from(5u64)).into_affine();
+    let expected = bn254::g1_marshal(&(g + p).into_affine());
+
+    // BLS.addG1Points sends 0xc0 bytes, the last 64 are ignored
+    let input = [bn254::g1_marshal(&g), bn254::g1_marshal(&p), [0xff; 64]].concat();
+    assert_eq!(precompiles::call(ECADD, &input), Ok(expected.to_vec()));
+    // a missing point is the point at infinity
+    let g1 = bn254::g1_marshal(&g);
+    assert_eq!(precompiles::ecadd(&g1), Ok(g1));
+    assert_eq!(precompiles::ecadd(&[]), Ok([0; 64]));
+
+    // BLS.scalarMulG1Point sends 0x80 bytes, the scalar is not reduced
+    let mut scalar = ark_bn254::Fr::MODULUS;
+    scalar.add_with_carry(&5u64.into());
+    let input = [&g1[..], &scalar.to_bytes_be(), &[0xff; 32]].concat();
+    assert_eq!(
+        precompiles::call(ECMUL, &input),
+        Ok(bn254::g1_marshal(&p).to_vec())
+    );
+
+    let mut not_on_curve = g1;
+    not_on_curve[63] = 3;
+    assert_eq!(precompiles::ecadd(&not_on_curve), Err(Error::NotOnCurve));
+    let p = ark_bn254::Fq::MODULUS.to_bytes_be();
+    assert_eq!(
+        precompiles::ecmul(&[&p[..], &g1[32..]].concat()),
+        Err(Error::NonCanonicalFieldElement)
+    );
+}
+
+#[test]
+fn bn254_pairing_of_verify_single() {
+    let sk = bn254::SecretKey::from_be_bytes_mod_order(&[7; 32]);
+    let sig = sk.sign(DST, MESSAGE);
+    let m = bn254::hash_to_point(DST, MESSAGE);
+    let pairs = |sig: &ark_bn254::G1Affine| {
+        [
+            &bn254::g1_marshal(sig)[..],
+            &bn254::g2_marshal(&-ark_bn254::G2Affine::generator()),
+            &bn254::g1_marshal(&m),
+            &bn254::g2_marshal(&sk.public_key().0),
+        ]
+        .concat()
+    };
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    assert_eq!(precompiles::ecpairing(&pairs(&sig.0)), Ok(one));
+    assert_eq!(precompiles::ecpairing(&pairs(&m)), Ok([0; 32]));
+    assert_eq!(precompiles::ecpairing(&[]), Ok(one));
+    assert_eq!(
+        precompiles::call(ECPAIRING, &pairs(&sig.0)[..383]),
+        Err(Error::InvalidPairingLength {
+            pair_length: 192,
+            actual: 383
+        })
+    );
+}
+
+#[test]
+fn modexp_inputs() {
+    // BLS.modexpLegendre: three 32 bytes lengths, u, (N - 1) / 2 and N
+    let n = ark_bn254::Fq::MODULUS;
+    let mut c5 = n;
+    c5.div2();
+    for u in [2u64, 3, 5] {
+        let u = ark_bn254::Fq::from(u);
+        let input = [
+            &[0; 31][..],
+            &[32],
+            &[0; 31],
+            &[32],
+            &[0; 31],
+            &[32],
+            &uint(u),
+            &c5.to_bytes_be(),
+            &n.to_bytes_be(),
+        ]
+        .concat();
+        let expected = match u.legendre() {
+            LegendreSymbol::QuadraticResidue => uint(ark_bn254::Fq::ONE),
+            LegendreSymbol::QuadraticNonResidue => uint(-ark_bn254::Fq::ONE),
+            LegendreSymbol::Zero => unreachable!(),
+        };
+        assert_eq!(precompiles::call(MODEXP, &input), Ok(expected));
+    }
+
+    // BLS2 cubes a 64 bytes x with a 1 byte exponent: 225 bytes
+    let x = -ark_bls12_381::Fq::from(123456789u64);
+    let p = ark_bls12_381::Fq::MODULUS.to_bytes_be();
+    let mut lengths = [0u8; 96];
+    (lengths[31], lengths[63], lengths[95]) = (64, 1, 64);
+    let input = [&lengths[..], &[0; 16], &uint(x), &[3], &[0; 16], &p].concat();
+    assert_eq!(input.len(), 225);
+    let cube = precompiles::modexp(&input).unwrap();
+    assert_eq!(cube, [&[0; 16][..], &uint(x * x * x)].concat());
+    // a missing last byte of the modulus is a zero
+    assert_eq!(
+        precompiles::modexp(&input[..224]),
+        precompiles::modexp(&[&input[..224], &[0]].concat())
+    );
+    assert_ne!(precompiles::modexp(&input[..224]), Ok(cube));
+
+    // a zero modulus gives zeros, 0^0 is 1
+    (lengths[31], lengths[63], lengths[95]) = (1, 1, 2);
+    assert_eq!(
+        precompiles::modexp(&[&lengths[..], &[0, 0, 0, 0]].concat()),
+        Ok(vec![0, 0])
+    );
+    assert_eq!(
+        precompiles::modexp(&[&lengths[..], &[0, 0, 1, 0]].concat()),
+        Ok(vec![0, 1])
+    );
+    lengths[94] = 4;
+    assert_eq!(
+        precompiles::modexp(&lengths),
+        Err(Error::ModExpLengthTooLarge)
+    );
+}
+
+#[test]
+fn bls12_381_hash_to_point() {
+    // BLS2.hashToPoint: MAP_FP_TO_G1 of u0 and u1, then G1ADD
+    let hasher = <DefaultFieldHasher<sha2::Sha256> as HashToField<ark_bls12_381::Fq>>::new(DST);
+    let [u0, u1]: [ark_bls12_381::Fq; 2] = hasher.hash_to_field(MESSAGE);
+    let q0 = precompiles::bls12_map_fp_to_g1(&eip2537::fp_encode(&u0)).unwrap();
+    let q1 = precompiles::call(eip2537::MAP_FP_TO_G1, &eip2537::fp_encode(&u1)).unwrap();
+    let sum = precompiles::call(eip2537::G1ADD, &[&q0[..], &q1].concat()).unwrap();
+    assert_eq!(
+        sum,
+        eip2537::g1_encode(&bls12_381::hash_to_point(DST, MESSAGE))
+    );
+
+    let mut padding = eip2537::fp_encode(&u0);
+    padding[0] = 1;
+    assert_eq!(
+        precompiles::bls12_map_fp_to_g1(&padding),
+        Err(Error::NonZeroPadding)
+    );
+    assert_eq!(
+        precompiles::bls12_map_fp_to_g1(&padding[16..]),
+        Err(Error::InvalidLength {
+            expected: 64,
+            actual: 48
+        })
+    );
+}
+
+#[test]
+fn bls12_381_pairing_of_verify_single() {
+    let sk = bls12_381::SecretKey::from_be_bytes_mod_order(&[7; 32]);
+    let sig = sk.sign(DST, MESSAGE);
+    let m = bls12_381::hash_to_point(DST, MESSAGE);
+    let neg_g2 = -ark_bls12_381::G2Affine::generator();
+    let input = eip2537::pairing_check_input(&[(sig.0, neg_g2), (m, sk.public_key().0)]);
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    assert_eq!(precompiles::bls12_pairing_check(&input), Ok(one));
+    let input = eip2537::pairing_check_input(&[(m, neg_g2), (m, sk.public_key().0)]);
+    assert_eq!(precompiles::bls12_pairing_check(&input), Ok([0; 32]));
+
+    assert_eq!(
+        precompiles::bls12_pairing_check(&input[..384 + 128]),
+        Err(Error::InvalidPairingLength {
+            pair_length: 384,
+            actual: 512
+        })
+    );
+    assert!(precompiles::bls12_pairing_check(&[]).is_err());
+    let mut padding = input.clone();
+    padding[128 + 64] = 1;
+    assert_eq!(
+        precompiles::bls12_pairing_check(&padding),
+        Err(Error::NonZeroPadding)
+    );
+}
+
+#[test]
+fn bls12_381_subgroup_checks() {
+    // a point of G1 outside of the prime order subgroup
+    let p = (1u64..)
+        .find_map(|x| {
+            let x = ark_bls12_381::Fq::from(x);
+            let y = (x * x * x + ark_bls12_381::Fq::from(4u64)).sqrt()?;
+            let p: ark_bls12_381::G1Affine = Affine::new_unchecked(x, y);
+            (!p.is_in_correct_subgroup_assuming_on_curve()).then_some(p)
+        })
+        .unwrap();
+    let g = ark_bls12_381::G1Affine::generator();
+    let encoded = eip2537::g1_encode(&p);
+
+    // G1ADD only checks that the points are on the curve
+    assert_eq!(
+        precompiles::bls12_g1add(&[&encoded[..], &eip2537::g1_encode(&g)].concat()),
+        Ok(eip2537::g1_encode(&(p + g).into_affine()))
+    );
+    let input = [
+        &encoded[..],
+        &eip2537::g2_encode(&ark_bls12_381::G2Affine::generator()),
+    ]
+    .concat();
+    assert_eq!(
+        precompiles::bls12_pairing_check(&input),
+        Err(Error::NotInSubgroup)
+    );
+
+    let mut not_on_curve = encoded;
+    not_on_curve[127] ^= 1;
+    assert_eq!(
+        precompiles::bls12_g1add(&[&not_on_curve[..], &[0; 128]].concat()),
+        Err(Error::NotOnCurve)
+    );
+    assert_eq!(
+        precompiles::call(0x0c, &[]),
+        Err(Error::UnknownPrecompile(0x0c))
+    );
+}
@@ -0,0 +1,196 @@
+//! Signing, verification and hashing to G1 on both curves, and the secret key scalars.
+
+use bls_solidity_rs::dst::Dst;
+use bls_solidity_rs::pubkey::{Format, PublicKey};
+use bls_solidity_rs::{Curve, Error, Scheme, bls12_381, bn254};
+
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use proptest::prelude::*;
+
+fn message() -> impl Strategy<Value = Vec<u8>> {
+    prop::collection::vec(any::<u8>(), 0..96)
+}
+
+fn dst() -> impl Strategy<Value = Vec<u8>> {
+    prop::collection::vec(any::<u8>(), 1..64)
+}
+
+/// Big-endian bytes of `modulus + k`, which the secret keys reduce to `k`.
+fn modulus_plus<F: PrimeField>(k: u64) -> [u8; 32] {
+    let mut n = F::MODULUS;
+    assert!(!n.add_with_carry(&F::BigInt::from(k)));
+    n.to_bytes_be().try_into().unwrap()
+}
+
+// pairings are slow in debug builds
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn bn254_signatures_verify(secret: [u8; 32], dst in dst(), message in message()) {
+        let sk = bn254::SecretKey::from_be_bytes_mod_order(&secret);
+        let sig = sk.sign(&dst, &message);
+        prop_assert!(sk.public_key().verify(&dst, &message, &sig));
+
+        // as received by BN254SignatureScheme
+        let pk = bn254::PublicKey::from_bytes(&sk.public_key().to_bytes()).unwrap();
+        let sig = bn254::Signature::from_bytes(&sig.to_bytes()).unwrap();
+        prop_assert!(pk.verify(&dst, &message, &sig));
+    }
+
+    #[test]
+    fn bls12_381_signatures_verify(secret: [u8; 32], dst in dst(), message in message()) {
+        let sk = bls12_381::SecretKey::from_be_bytes_mod_order(&secret);
+        let sig = sk.sign(&dst, &message);
+        prop_assert!(sk.public_key().verify(&dst, &message, &sig));
+
+        // as received by BLS12381SignatureScheme and BLS12381CompressedSignatureScheme
+        let pk = bls12_381::PublicKey::from_bytes(&sk.public_key().to_bytes()).unwrap();
+        let uncompressed = bls12_381::Signature::from_bytes(&sig.to_bytes()).unwrap();
+        let compressed =
+            bls12_381::Signature::from_compressed_bytes(&sig.to_compressed_bytes());
+        prop_assert!(pk.verify(&dst, &message, &uncompressed));
+        prop_assert_eq!(compressed, Ok(uncompressed));
+    }
+
+    #[test]
+    fn bn254_signatures_do_not_verify_elsewhere(
+        secret: [u8; 32],
+        dst in dst(),
+        other_dst in dst(),
+        message in message(),
+        other_message in message(),
+    ) {
+        let sk = bn254::SecretKey::from_be_bytes_mod_order(&secret);
+        prop_assume!(!sk.scalar().is_zero());
+        let sig = sk.sign(&dst, &message);
+        if other_message != message {
+            prop_assert!(!sk.public_key().verify(&dst, &other_message, &sig));
+        }
+        if other_dst != dst {
+            prop_assert!(!sk.public_key().verify(&other_dst, &message, &sig));
+        }
+        let other_key = bn254::SecretKey::from_le_bytes_mod_order(&secret).public_key();
+        prop_assume!(other_key != sk.public_key());
+        prop_assert!(!other_key.verify(&dst, &message, &sig));
+    }
+
+    #[test]
+    fn bls12_381_signatures_do_not_verify_elsewhere(
+        secret: [u8; 32],
+        dst in dst(),
+        other_dst in dst(),
+        message in message(),
+        other_message in message(),
+    ) {
+        let sk = bls12_381::SecretKey::from_be_bytes_mod_order(&secret);
+        prop_assume!(!sk.scalar().is_zero());
+        let sig = sk.sign(&dst, &message);
+        if other_message != message {
+            prop_assert!(!sk.public_key().verify(&dst, &other_message, &sig));
+        }
+        if other_dst != dst {
+            prop_assert!(!sk.public_key().verify(&other_dst, &message, &sig));
+        }
+        let other_key = bls12_381::SecretKey::from_le_bytes_mod_order(&secret).public_key();
+        prop_assume!(other_key != sk.public_key());
+        prop_assert!(!other_key.verify(&dst, &message, &sig));
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn dsts_separate_hashes(dst in dst(), other in dst(), message in message()) {
+        prop_assume!(dst != other);
+        prop_assert_ne!(
+            bn254::hash_to_point(&dst, &message),
+            bn254::hash_to_point(&other, &message)
+        );
+        prop_assert_ne!(
+            bls12_381::hash_to_point(&dst, &message),
+            bls12_381::hash_to_point(&other, &message)
+        );
+    }
+
+    #[test]
+    fn contract_dsts_round_trip(
+        application in "[ -~]{0,32}",
+        chain_id: u64,
+        other_chain_id: u64,
+    ) {
+        for scheme in [Scheme::Bn254, Scheme::Bls12381, Scheme::Bls12381Compressed] {
+            let dst = Dst::new(scheme, application.clone(), chain_id);
+            prop_assert_eq!(Dst::parse(scheme, &dst.to_bytes()), Ok(dst.clone()));
+
+            // a signature for one chain is not for another
+            let other = Dst::new(scheme, application.clone(), other_chain_id);
+            prop_assert_eq!(dst.to_bytes() == other.to_bytes(), chain_id == other_chain_id);
+        }
+        let bn254_dst = Dst::new(Scheme::Bn254, application.clone(), chain_id).to_bytes();
+        let bls12_381_dst = Dst::new(Scheme::Bls12381, application, chain_id).to_bytes();
+        prop_assert_ne!(bn254_dst, bls12_381_dst);
+    }
+
+    #[test]
+    fn secret_keys_reduce_mod_order(k: u64) {
+        let bn254_secret = modulus_plus::<ark_bn254::Fr>(k);
+        let bls12_381_secret = modulus_plus::<ark_bls12_381::Fr>(k);
+        prop_assert_eq!(
+            bn254::SecretKey::from_be_bytes_mod_order(&bn254_secret).scalar(),
+            ark_bn254::Fr::from(k)
+        );
+        prop_assert_eq!(
+            bls12_381::SecretKey::from_be_bytes_mod_order(&bls12_381_secret).scalar(),
+            ark_bls12_381::Fr::from(k)
+        );
+    }
+}
+
+#[test]
+fn zero_secret_key() {
+    let zero = [0u8; 32];
+    let bn254_pk = bn254::SecretKey::from_be_bytes_mod_order(&zero).public_key();
+    let bls12_381_pk = bls12_381::SecretKey::from_be_bytes_mod_order(&zero).public_key();
+    assert!(bn254_pk.0.is_zero());
+    assert!(bls12_381_pk.0.is_zero());
+    // marshalled as all zeros, which the converter refuses as a public key
+    assert_eq!(bn254_pk.to_bytes(), [0; bn254::G2_LENGTH]);
+    assert_eq!(
+        PublicKey::decode(Curve::Bn254, Format::G2Marshal, &bn254_pk.to_bytes()),
+        Err(Error::InfinityUnsupported)
+    );
+    assert_eq!(
+        PublicKey::decode(Curve::Bls12381, Format::G2Marshal, &bls12_381_pk.to_bytes()),
+        Err(Error::InfinityUnsupported)
+    );
+}
+
+#[test]
+fn unit_secret_keys() {
+    let (dst, message) = (b"BLS_SIG_TEST".as_slice(), b"message".as_slice());
+    let mut one = [0u8; 32];
+    one[31] = 1;
+
+    let sk = bn254::SecretKey::from_be_bytes_mod_order(&one);
+    assert_eq!(sk.public_key().0, ark_bn254::G2Affine::generator());
+    assert_eq!(sk.sign(dst, message).0, bn254::hash_to_point(dst, message));
+    // r - 1 = -1
+    let minus_one = (-ark_bn254::Fr::from(1u64)).into_bigint().to_bytes_be();
+    let sk = bn254::SecretKey::from_be_bytes_mod_order(&minus_one);
+    assert_eq!(sk.public_key().0, -ark_bn254::G2Affine::generator());
+    assert!(sk.public_key().verify(dst, message, &sk.sign(dst, message)));
+
+    let sk = bls12_381::SecretKey::from_be_bytes_mod_order(&one);
+    assert_eq!(sk.public_key().0, ark_bls12_381::G2Affine::generator());
+    assert_eq!(
+        sk.sign(dst, message).0,
+        bls12_381::hash_to_point(dst, message)
+    );
+    let minus_one = (-ark_bls12_381::Fr::from(1u64)).into_bigint().to_bytes_be();
+    let sk = bls12_381::SecretKey::from_be_bytes_mod_order(&minus_one);
+    assert_eq!(sk.public_key().0, -ark_bls12_381::G2Affine::generator());
+    assert!(sk.public_key().verify(dst, message, &sk.sign(dst, message)));
+}
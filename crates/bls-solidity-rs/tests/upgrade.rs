@@ -0,0 +1,295 @@
+//! The upgrade planner reverts like `ScheduledUpgradeable`, binds each authorization to the next
+//! nonce and has it signed by the validator in place.
+
+use bls_solidity_rs::Scheme;
+use bls_solidity_rs::abi::{self, Address};
+use bls_solidity_rs::upgrade::{
+    MINIMUM_UPGRADE_DELAY, Operation, PlanError, Planner, SCHEDULE, Step, Target, UpgradeState,
+    Validator, contract_upgrade_message,
+};
+
+const START: u64 = 1_700_000_000;
+
+fn target() -> Target {
+    Target {
+        contract: [0xaa; 20],
+        chain_id: abi::uint(31337),
+    }
+}
+
+fn validator(address: u8, dst: &[u8]) -> Validator {
+    Validator {
+        address: [address; 20],
+        scheme: Scheme::Bn254,
+        dst: dst.to_vec(),
+    }
+}
+
+/// A contract right after `initialize`.
+fn planner() -> Planner {
+    Planner::new(
+        target(),
+        UpgradeState {
+            current_nonce: 0,
+            scheduled_implementation: Address::default(),
+            scheduled_implementation_calldata: Vec::new(),
+            scheduled_timestamp_for_upgrade: 0,
+            minimum_contract_upgrade_delay: MINIMUM_UPGRADE_DELAY,
+            contract_upgrade_bls_validator: validator(1, b"admin"),
+        },
+    )
+}
+
+fn schedule(timestamp: u64, implementation: u8, upgrade_time: u64) -> Step {
+    Step {
+        timestamp,
+        operation: Operation::ScheduleUpgrade {
+            new_implementation: [implementation; 20],
+            upgrade_calldata: vec![0x81, 0x29, 0xfc, 0x1c],
+            upgrade_time,
+        },
+    }
+}
+
+fn step(timestamp: u64, operation: Operation) -> Step {
+    Step {
+        timestamp,
+        operation,
+    }
+}
+
+#[test]
+fn minimum_delay() {
+    let mut planner = planner();
+    let before = planner.state().clone();
+    assert_eq!(
+        planner.push(step(
+            START,
+            Operation::SetMinimumContractUpgradeDelay(MINIMUM_UPGRADE_DELAY - 1)
+        )),
+        Err(PlanError::UpgradeDelayTooShort)
+    );
+    assert_eq!(
+        planner.push(schedule(START, 2, START + MINIMUM_UPGRADE_DELAY - 1)),
+        Err(PlanError::UpgradeTimeMustRespectDelay {
+            minimum_delay: MINIMUM_UPGRADE_DELAY
+        })
+    );
+    assert_eq!(planner.state(), &before);
+
+    // the delay applies to the steps after the one changing it
+    let delay = 3 * MINIMUM_UPGRADE_DELAY;
+    planner
+        .push(step(
+            START,
+            Operation::SetMinimumContractUpgradeDelay(delay),
+        ))
+        .unwrap();
+    assert_eq!(
+        planner.push(schedule(START, 2, START + MINIMUM_UPGRADE_DELAY)),
+        Err(PlanError::UpgradeTimeMustRespectDelay {
+            minimum_delay: delay
+        })
+    );
+    planner.push(schedule(START, 2, START + delay)).unwrap();
+}
+
+#[test]
+fn cancel() {
+    // nothing scheduled: the scheduled timestamp is 0
+    assert_eq!(
+        planner().push(step(START, Operation::CancelUpgrade)),
+        Err(PlanError::TooLateToCancelUpgrade {
+            scheduled_timestamp: 0
+        })
+    );
+
+    let upgrade_time = START + MINIMUM_UPGRADE_DELAY;
+    let mut late = planner();
+    late.push(schedule(START, 2, upgrade_time)).unwrap();
+    let mut planner = planner();
+    planner.push(schedule(START, 2, upgrade_time)).unwrap();
+    assert_eq!(
+        late.push(step(upgrade_time, Operation::CancelUpgrade)),
+        Err(PlanError::TooLateToCancelUpgrade {
+            scheduled_timestamp: upgrade_time
+        })
+    );
+
+    let cancelled = planner
+        .push(step(upgrade_time - 1, Operation::CancelUpgrade))
+        .unwrap();
+    // the scheduled implementation is signed as both the pending and the new one
+    let message = contract_upgrade_message(
+        &target(),
+        "cancel",
+        [2; 20],
+        [2; 20],
+        &[0x81, 0x29, 0xfc, 0x1c],
+        abi::uint(upgrade_time),
+        abi::uint(2),
+    );
+    assert_eq!(cancelled.authorization.unwrap().message, message);
+    assert_eq!(planner.state().scheduled_implementation, Address::default());
+    assert_eq!(planner.state().scheduled_timestamp_for_upgrade, 0);
+    assert_eq!(
+        planner.push(step(upgrade_time, Operation::ExecuteUpgrade)),
+        Err(PlanError::NoUpgradePending)
+    );
+}
+
+#[test]
+fn execute() {
+    let upgrade_time = START + MINIMUM_UPGRADE_DELAY;
+    let mut planner = planner();
+    planner.push(schedule(START, 2, upgrade_time)).unwrap();
+    assert_eq!(
+        planner.push(step(upgrade_time - 1, Operation::ExecuteUpgrade)),
+        Err(PlanError::UpgradeTooEarly {
+            scheduled_timestamp: upgrade_time
+        })
+    );
+
+    let executed = planner
+        .push(step(upgrade_time, Operation::ExecuteUpgrade))
+        .unwrap();
+    assert_eq!(executed.authorization, None);
+    assert_eq!(executed.nonce_after, 1);
+    assert_eq!(planner.state().scheduled_implementation, Address::default());
+    assert_eq!(
+        planner.push(step(upgrade_time - 1, Operation::CancelUpgrade)),
+        Err(PlanError::TimestampNotIncreasing)
+    );
+}
+
+#[test]
+fn nonce_increments_per_accepted_action() {
+    let upgrade_time = START + MINIMUM_UPGRADE_DELAY;
+    let steps = [
+        schedule(START, 2, upgrade_time),
+        step(START + 1, Operation::CancelUpgrade),
+        schedule(START + 2, 3, upgrade_time + 2),
+        step(
+            START + 3,
+            Operation::SetMinimumContractUpgradeDelay(MINIMUM_UPGRADE_DELAY),
+        ),
+        step(upgrade_time + 2, Operation::ExecuteUpgrade),
+        step(
+            upgrade_time + 3,
+            Operation::SetContractUpgradeBlsValidator(validator(4, b"admin")),
+        ),
+    ];
+    let mut planner = planner();
+    let planned = planner.plan(steps).unwrap();
+    let nonces: Vec<_> = planned.iter().map(|p| p.nonce_after).collect();
+    assert_eq!(nonces, [1, 2, 3, 4, 4, 5]);
+    for planned in &planned {
+        if let Some(authorization) = &planned.authorization {
+            assert_eq!(authorization.nonce, planned.nonce_after);
+        }
+    }
+    assert_eq!(
+        planned[2].authorization.as_ref().unwrap().message,
+        contract_upgrade_message(
+            &target(),
+            SCHEDULE,
+            Address::default(),
+            [3; 20],
+            &[0x81, 0x29, 0xfc, 0x1c],
+            abi::uint(upgrade_time + 2),
+            abi::uint(3),
+        )
+    );
+
+    // a rejected step leaves the nonce alone, and plan reports its index
+    let rejected = [
+        step(
+            upgrade_time + 4,
+            Operation::SetMinimumContractUpgradeDelay(1),
+        ),
+        schedule(
+            upgrade_time + 5,
+            0,
+            upgrade_time + 5 + MINIMUM_UPGRADE_DELAY,
+        ),
+    ];
+    assert_eq!(
+        planner.plan(rejected),
+        Err((0, PlanError::UpgradeDelayTooShort))
+    );
+    assert_eq!(planner.state().current_nonce, 5);
+    let accepted = planner
+        .push(schedule(
+            upgrade_time + 5,
+            2,
+            upgrade_time + 5 + MINIMUM_UPGRADE_DELAY,
+        ))
+        .unwrap();
+    assert_eq!(accepted.nonce_after, 6);
+}
+
+#[test]
+fn nonce_overflow() {
+    let mut state = planner().state().clone();
+    state.current_nonce = u64::MAX - 1;
+    let mut planner = Planner::new(target(), state);
+    let upgrade_time = START + MINIMUM_UPGRADE_DELAY;
+    assert_eq!(
+        planner
+            .push(schedule(START, 2, upgrade_time))
+            .unwrap()
+            .nonce_after,
+        u64::MAX
+    );
+    let before = planner.state().clone();
+    assert_eq!(
+        planner.push(step(START, Operation::CancelUpgrade)),
+        Err(PlanError::NonceOverflow)
+    );
+    assert_eq!(planner.state(), &before);
+    // executing takes no authorization, so the nonce stays in range
+    let executed = planner
+        .push(step(upgrade_time, Operation::ExecuteUpgrade))
+        .unwrap();
+    assert_eq!(executed.nonce_after, u64::MAX);
+}
+
+#[test]
+fn signer_switches_after_rotation() {
+    let mut planner = planner();
+    let rotated = validator(3, b"rotated");
+    assert_eq!(
+        planner.push(step(
+            START,
+            Operation::SetContractUpgradeBlsValidator(validator(0, b"admin"))
+        )),
+        Err(PlanError::ZeroAddress)
+    );
+
+    // the rotation itself is signed by the validator it replaces
+    let rotation = planner
+        .push(step(
+            START,
+            Operation::SetContractUpgradeBlsValidator(rotated.clone()),
+        ))
+        .unwrap()
+        .authorization
+        .unwrap();
+    assert_eq!(rotation.validator, validator(1, b"admin"));
+    assert_eq!(
+        rotation.message_g1,
+        Scheme::Bn254.hash_to_bytes(b"admin", &rotation.message)
+    );
+    assert_eq!(planner.state().contract_upgrade_bls_validator, rotated);
+
+    let next = planner
+        .push(schedule(START, 2, START + MINIMUM_UPGRADE_DELAY))
+        .unwrap()
+        .authorization
+        .unwrap();
+    assert_eq!(next.validator, rotated);
+    assert_eq!(
+        next.message_g1,
+        Scheme::Bn254.hash_to_bytes(b"rotated", &next.message)
+    );
+}
@@ -0,0 +1,201 @@
+//! Feeds the same bytes to every point decoder of the library. None of them may panic, whatever
+//! they accept must encode back to the input, and the BLS12-381 decoders must agree with the
+//! arkworks deserialization of the same ZCash encodings.
+
+#![no_main]
+
+use bls_solidity_rs::precompiles::{self, MODEXP};
+use bls_solidity_rs::{Error, bls12_381, bn254, compressed, eip2537, sol};
+
+use ark_bls12_381::{G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_serialize::CanonicalDeserialize;
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(p) = bn254::g1_unmarshal(data) {
+        assert_eq!(bn254::g1_marshal(&p), data);
+    }
+    if let Ok(p) = bn254::g2_unmarshal(data) {
+        assert_eq!(bn254::g2_marshal(&p), data);
+    }
+
+    uncompressed(data);
+    compressed(data);
+    padded(data);
+});
+
+/// `BLS2.g1Unmarshal` and `BLS2.g2Unmarshal`.
+fn uncompressed(data: &[u8]) {
+    let g1 = bls12_381::g1_unmarshal(data);
+    let g2 = bls12_381::g2_unmarshal(data);
+    if let Ok(p) = g1 {
+        assert_eq!(bls12_381::g1_marshal(&p), data);
+    }
+    if let Ok(p) = g2 {
+        assert_eq!(bls12_381::g2_marshal(&p), data);
+    }
+
+    // arkworks flags the point at infinity instead of using all zeros, and has no flags on the
+    // other points
+    if data.first().is_none_or(|b| b & 0xe0 != 0) || data.iter().all(|b| *b == 0) {
+        return;
+    }
+    match data.len() {
+        bls12_381::G1_LENGTH => assert_eq!(g1.ok(), G1Affine::deserialize_uncompressed(data).ok()),
+        bls12_381::G2_LENGTH => assert_eq!(g2.ok(), G2Affine::deserialize_uncompressed(data).ok()),
+        _ => {}
+    }
+}
+
+/// `BLS2.g1UnmarshalCompressed` and the strict decoding of drand's compressed keys.
+fn compressed(data: &[u8]) {
+    let g1 = compressed::g1_decompress(data);
+    let g2 = compressed::g2_decompress(data);
+    if let Ok(p) = g1 {
+        assert_eq!(compressed::g1_compress(&p).unwrap(), data);
+    }
+    if let Ok(p) = g2 {
+        assert_eq!(compressed::g2_compress(&p).unwrap(), data);
+    }
+
+    // the contract only checks the flags: x is not reduced and the point may not be on the curve,
+    // so the strict decoding accepts a subset of its inputs
+    let unmarshalled = compressed::g1_unmarshal_compressed(data);
+    assert_eq!(unmarshalled, g1_unmarshal_compressed(data));
+    match (&g1, unmarshalled) {
+        (Ok(p), unmarshalled) => assert_eq!(unmarshalled, Ok(sol::bls2::PointG1::from(*p))),
+        (
+            Err(Error::NonCanonicalFieldElement | Error::NotOnCurve | Error::NotInSubgroup),
+            Ok(point),
+        ) => {
+            if let Ok(p) = G1Affine::try_from(point) {
+                assert_eq!(g1, Err(Error::NotInSubgroup), "{p} was rejected");
+            }
+        }
+        (Err(e), unmarshalled) => assert_eq!(unmarshalled, Err(e.clone())),
+    }
+
+    // g1_unmarshal_compressed and g2_unmarshal_compressed deserialize with arkworks
+    agrees(g1, bls12_381::g1_unmarshal_compressed(data));
+    agrees(g2, bls12_381::g2_unmarshal_compressed(data));
+}
+
+/// `BLS2.g1UnmarshalCompressed` step by step, with the ModExp precompile calls it makes.
+fn g1_unmarshal_compressed(m: &[u8]) -> Result<sol::bls2::PointG1, Error> {
+    if m.len() != compressed::G1_LENGTH {
+        return Err(Error::InvalidLength {
+            expected: compressed::G1_LENGTH,
+            actual: m.len(),
+        });
+    }
+    let flags = m[0];
+    if flags & 0x80 == 0 {
+        return Err(Error::MissingCompressionFlag);
+    }
+    if flags & 0x40 != 0 {
+        return Err(Error::InfinityUnsupported);
+    }
+    let larger = flags & 0x20 == 0;
+    let mut x = m.to_vec();
+    x[0] &= 0x1f;
+
+    let p = BigUint::from_bytes_be(&eip2537::fp_encode(&-ark_bls12_381::Fq::from(1u64))) + 1u32;
+    let lengths = |exponent_length: u8| {
+        let mut lengths = [0u8; 96];
+        (lengths[31], lengths[63], lengths[95]) = (64, exponent_length, 64);
+        lengths
+    };
+    let input = [&lengths(1)[..], &[0; 16], &x, &[3], &word64(&p)].concat();
+    let cube = BigUint::from_bytes_be(&precompiles::call(MODEXP, &input).unwrap());
+    let exponent = (&p + 1u32) / 4u32;
+    let input = [
+        &lengths(64)[..],
+        &word64(&(cube + 4u32)),
+        &word64(&exponent),
+        &word64(&p),
+    ]
+    .concat();
+    let mut y = BigUint::from_bytes_be(&precompiles::call(MODEXP, &input).unwrap());
+
+    let alternative = &p - &y;
+    if (y > alternative) == larger {
+        y = alternative;
+    }
+    let y = word64(&y);
+    Ok(sol::bls2::PointG1 {
+        x_hi: u128::from_be_bytes(x[..16].try_into().unwrap()),
+        x_lo: x[16..].try_into().unwrap(),
+        y_hi: u128::from_be_bytes(y[16..32].try_into().unwrap()),
+        y_lo: y[32..].try_into().unwrap(),
+    })
+}
+
+/// A 64 bytes MODEXP operand.
+fn word64(value: &BigUint) -> [u8; 64] {
+    let bytes = value.to_bytes_be();
+    let mut word = [0u8; 64];
+    word[64 - bytes.len()..].copy_from_slice(&bytes);
+    word
+}
+
+fn agrees<P: AffineRepr>(ours: Result<P, Error>, arkworks: Result<P, Error>) {
+    match arkworks {
+        Ok(p) if p.is_zero() => assert_eq!(ours, Err(Error::InfinityUnsupported)),
+        Ok(p) => assert_eq!(ours, Ok(p)),
+        Err(_) => assert!(
+            ours.is_err(),
+            "{ours:?} is not a valid encoding for arkworks"
+        ),
+    }
+}
+
+/// The precompile encoding, the coordinates of `BLS2.g1Unmarshal` left padded.
+fn padded(data: &[u8]) {
+    let padding = data
+        .chunks(eip2537::FP_LENGTH)
+        .any(|fp| fp[..fp.len().min(16)].iter().any(|b| *b != 0));
+    match data.len() {
+        eip2537::G1_LENGTH => {
+            let decoded = eip2537::g1_decode(data);
+            if let Ok(p) = decoded {
+                assert_eq!(eip2537::g1_encode(&p), data);
+            }
+            if padding {
+                // unless an earlier coordinate is rejected first
+                assert!(decoded.is_err());
+            } else {
+                assert_eq!(decoded, bls12_381::g1_unmarshal(&unpad(data)));
+            }
+        }
+        eip2537::G2_LENGTH => {
+            let decoded = eip2537::g2_decode(data);
+            if let Ok(p) = decoded {
+                assert_eq!(eip2537::g2_encode(&p), data);
+            }
+            if padding {
+                // unless an earlier coordinate is rejected first
+                assert!(decoded.is_err());
+            } else {
+                // g2Marshal orders the coefficients c1 || c0, the precompiles c0 || c1
+                let [x0, x1, y0, y1] =
+                    [0, 1, 2, 3].map(|i| &data[eip2537::FP_LENGTH * i..][16..64]);
+                let marshalled = [x1, x0, y1, y0].concat();
+                assert_eq!(decoded, bls12_381::g2_unmarshal(&marshalled));
+            }
+        }
+        _ => {
+            assert!(eip2537::g1_decode(data).is_err());
+            assert!(eip2537::g2_decode(data).is_err());
+        }
+    }
+}
+
+/// Drops the 16 bytes of padding in front of each coordinate.
+fn unpad(data: &[u8]) -> Vec<u8> {
+    data.chunks(eip2537::FP_LENGTH)
+        .flat_map(|fp| &fp[16..])
+        .copied()
+        .collect()
+}
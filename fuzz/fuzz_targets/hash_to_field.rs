@@ -0,0 +1,38 @@
+//! The reference `expand_message_xmd` and hash_to_field of test/data must agree with the
+//! `FieldHasher` of arkworks, for any DST and message. The input is a big-endian u16 DST length,
+//! the DST, then the message.
+
+#![no_main]
+
+use make_test_signature::h2c;
+
+use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+use ark_ff::{BigInteger, PrimeField};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((length, rest)) = data.split_first_chunk::<2>() else {
+        return;
+    };
+    let Some((dst, message)) = rest.split_at_checked(u16::from_be_bytes(*length).into()) else {
+        return;
+    };
+
+    // both also check that Q0 + Q1 is hash_to_point of the library
+    let bn254 = h2c::bn254(message, dst);
+    let bls12 = h2c::bls12(message, dst);
+
+    let hasher = <DefaultFieldHasher<sha3::Keccak256> as HashToField<ark_bn254::Fq>>::new(dst);
+    let [u0, u1]: [ark_bn254::Fq; 2] = hasher.hash_to_field(message);
+    assert_eq!(bn254.u0, be_hex(u0));
+    assert_eq!(bn254.u1, be_hex(u1));
+
+    let hasher = <DefaultFieldHasher<sha2::Sha256> as HashToField<ark_bls12_381::Fq>>::new(dst);
+    let [u0, u1]: [ark_bls12_381::Fq; 2] = hasher.hash_to_field(message);
+    assert_eq!(bls12.u0, be_hex(u0));
+    assert_eq!(bls12.u1, be_hex(u1));
+});
+
+fn be_hex(f: impl PrimeField) -> String {
+    hex::encode(f.into_bigint().to_bytes_be())
+}
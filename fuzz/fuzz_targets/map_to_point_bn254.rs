@@ -0,0 +1,26 @@
+//! `mapToPointBN254` of `bls_ffi`, which `BLSTestFuzz` compares `BLS.mapToPoint` against, must
+//! agree with the arkworks SvdW map behind `q0` and `q1` of testcases.json and revert like the
+//! contract on values that are not field elements.
+
+#![no_main]
+
+use bls_ffi::svdw;
+use make_test_signature::h2c;
+
+use ark_ff::PrimeField;
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+
+fuzz_target!(|data: &[u8]| {
+    let u = BigUint::from_bytes_be(data);
+    match svdw::map_to_point(&u) {
+        Ok((x, y)) => {
+            let p = h2c::svdw(ark_bn254::Fq::from_be_bytes_mod_order(data));
+            let (px, py) = (p.x, p.y);
+            assert_eq!(x, BigUint::from(px.into_bigint()), "x for u = {u:x}");
+            assert_eq!(y, BigUint::from(py.into_bigint()), "y for u = {u:x}");
+        }
+        Err(svdw::Error::InvalidFieldElement) => assert!(u >= svdw::modulus()),
+        Err(e) => panic!("{e} for u = {u:x}"),
+    }
+});
@@ -5,6 +5,7 @@ import {Test} from "forge-std-1.10.0/src/Test.sol";
 import {BLS2} from "src/libraries/BLS2.sol";
 
 import {Common} from "test/Common.sol";
+import {Fixtures} from "test/data/Fixtures.sol";
 
 contract BLS2Test is Test, Common {
     function table_marshal_unmarshal(TestCase memory tc) public pure {
@@ -37,6 +38,14 @@ contract BLS2Test is Test, Common {
         assert(callSuccess);
     }
 
+    function table_hash_to_point_steps(TestCase memory tc) public pure {
+        if (!eq(tc.scheme, "BLS12381")) {
+            return; // Skip row but not whole table
+        }
+        bytes memory uniform_bytes = BLS2.expandMsg(bytes(tc.dst), parseHex(tc.message), 128);
+        assertEq(uniform_bytes, parseHex(tc.uniform_bytes), "uniform_bytes");
+    }
+
     function table_unmarshal_compressed(TestCase memory tc) public view {
         if (!eq(tc.scheme, "BLS12381")) {
             return; // Skip row but not whole table
@@ -51,6 +60,40 @@ contract BLS2Test is Test, Common {
         assert(actual.y_lo == expected.y_lo);
     }
 
+    function table_expand_msg(ExpandMsgTestCase memory xmd) public {
+        if (!eq(xmd.hash, "SHA-256")) {
+            return; // Skip row but not whole table
+        }
+        bytes memory dst = bytes(xmd.dst);
+        bytes memory message = parseHex(xmd.msg);
+        if (dst.length > 255) {
+            vm.expectRevert(abi.encodeWithSelector(BLS2.InvalidDSTLength.selector, dst));
+            this.expandMsg(dst, message, uint8(xmd.len_in_bytes));
+
+            // rfc9380 section 5.3.3 reduces oversize DSTs instead
+            dst = abi.encodePacked(sha256(abi.encodePacked("H2C-OVERSIZE-DST-", dst)));
+        }
+        bytes memory uniform_bytes = BLS2.expandMsg(dst, message, uint8(xmd.len_in_bytes));
+        assertEq(uniform_bytes, parseHex(xmd.uniform_bytes));
+    }
+
+    /// @dev External so that reverts can be expected
+    function expandMsg(bytes calldata dst, bytes calldata message, uint8 n_bytes) external pure returns (bytes memory) {
+        return BLS2.expandMsg(dst, message, n_bytes);
+    }
+
+    function test_fixtures_verify() public view {
+        BLS2.PointG1 memory sig = BLS2.g1UnmarshalCompressed(Fixtures.QUICKNET_20791007_SIG_COMPRESSED);
+        assertEq(BLS2.g1Marshal(sig), BLS2.g1Marshal(Fixtures.quicknet20791007Sig()));
+
+        BLS2.PointG1 memory m = BLS2.hashToPoint(Fixtures.QUICKNET_20791007_DST, Fixtures.QUICKNET_20791007_MESSAGE);
+        assertEq(BLS2.g1Marshal(m), BLS2.g1Marshal(Fixtures.quicknet20791007MExpected()));
+
+        (bool pairingSuccess, bool callSuccess) = BLS2.verifySingle(sig, Fixtures.quicknet20791007Pk(), m);
+        assert(pairingSuccess);
+        assert(callSuccess);
+    }
+
     function test_snapshot_verify_compressed() public {
         // snapshots do not work well in table tests as of Foundry 1.3.1, workaround here.
         TestCase memory tc = fixture_tc()[3];
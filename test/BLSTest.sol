@@ -5,6 +5,7 @@ import {Test, console} from "forge-std-1.10.0/src/Test.sol";
 import {BLS} from "src/libraries/BLS.sol";
 
 import {Common} from "test/Common.sol";
+import {Fixtures} from "test/data/Fixtures.sol";
 
 contract BLSTest is Test, Common {
     function test_sample_signature() public {
@@ -57,6 +58,56 @@ contract BLSTest is Test, Common {
         assert(callSuccess);
     }
 
+    function table_expand_msg(ExpandMsgTestCase memory xmd) public {
+        if (!eq(xmd.hash, "KECCAK-256")) {
+            return; // Skip row but not whole table
+        }
+        bytes memory dst = bytes(xmd.dst);
+        bytes memory message = parseHex(xmd.msg);
+        if (dst.length > 255) {
+            vm.expectRevert(abi.encodeWithSelector(BLS.InvalidDSTLength.selector, dst));
+            this.expandMsg(dst, message, uint8(xmd.len_in_bytes));
+            vm.expectRevert(abi.encodeWithSelector(BLS.InvalidDSTLength.selector, dst));
+            this.expandMsgTo96(dst, message);
+
+            // rfc9380 section 5.3.3 reduces oversize DSTs instead
+            dst = abi.encodePacked(keccak256(abi.encodePacked("H2C-OVERSIZE-DST-", dst)));
+        }
+        bytes memory uniform_bytes = BLS.expandMsg(dst, message, uint8(xmd.len_in_bytes));
+        assertEq(uniform_bytes, parseHex(xmd.uniform_bytes));
+        if (xmd.len_in_bytes == 96) {
+            assertEq(BLS.expandMsgTo96(dst, message), parseHex(xmd.uniform_bytes));
+        }
+    }
+
+    /// @dev External so that reverts can be expected
+    function expandMsg(bytes calldata dst, bytes calldata message, uint8 n_bytes) external pure returns (bytes memory) {
+        return BLS.expandMsg(dst, message, n_bytes);
+    }
+
+    /// @dev External so that reverts can be expected
+    function expandMsgTo96(bytes calldata dst, bytes calldata message) external pure returns (bytes memory) {
+        return BLS.expandMsgTo96(dst, message);
+    }
+
+    function table_hash_to_point_steps(TestCase memory tc) public view {
+        if (!eq(tc.scheme, "BN254")) {
+            return; // Skip row but not whole table
+        }
+        bytes memory dst = bytes(tc.dst);
+        bytes memory message = parseHex(tc.message);
+        assertEq(BLS.expandMsgTo96(dst, message), parseHex(tc.uniform_bytes), "uniform_bytes");
+
+        uint256[2] memory u = BLS.hashToField(dst, message);
+        assertEq(u[0], uint256(bytes32(parseHex(tc.u0))), "u0");
+        assertEq(u[1], uint256(bytes32(parseHex(tc.u1))), "u1");
+
+        uint256[2] memory q0 = BLS.mapToPoint(u[0]);
+        assertEq(BLS.g1Marshal(BLS.PointG1(q0[0], q0[1])), parseHex(tc.q0), "q0");
+        uint256[2] memory q1 = BLS.mapToPoint(u[1]);
+        assertEq(BLS.g1Marshal(BLS.PointG1(q1[0], q1[1])), parseHex(tc.q1), "q1");
+    }
+
     function test_snapshot_verify_uncompressed() public {
         // snapshots do not work well in table tests as of Foundry 1.3.1, workaround here.
         TestCase memory tc = fixture_tc()[4];
@@ -72,6 +123,16 @@ contract BLSTest is Test, Common {
         assert(pairingSuccess && callSuccess);
     }
 
+    function test_fixtures_verify() public view {
+        BLS.PointG1 memory m = BLS.hashToPoint(Fixtures.EVMNET_9337227_DST, Fixtures.EVMNET_9337227_MESSAGE);
+        assertEq(BLS.g1Marshal(m), BLS.g1Marshal(Fixtures.evmnet9337227MExpected()));
+
+        (bool pairingSuccess, bool callSuccess) =
+            BLS.verifySingle(Fixtures.evmnet9337227Sig(), Fixtures.evmnet9337227Pk(), m);
+        assert(pairingSuccess);
+        assert(callSuccess);
+    }
+
     function test_marshal_unmarshal_with_dcipher_adkg_cli_output() public {
         // Test g2Unmarshal with known values
         bytes memory g2data =
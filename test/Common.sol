@@ -13,9 +13,23 @@ abstract contract Common is TestBase {
         string m_expected;
         string message;
         string pk;
+        string q0; // map_to_curve(u0), before cofactor clearing
+        string q1; // map_to_curve(u1), before cofactor clearing
         string scheme; // either "BN254" or "BLS12381"
         string sig;
         string sig_compressed;
+        string u0;
+        string u1;
+        string uniform_bytes;
+    }
+
+    struct ExpandMsgTestCase {
+        // alphabetical order due to vm.parseJson quirks
+        string dst;
+        string hash; // either "SHA-256" or "KECCAK-256"
+        uint256 len_in_bytes;
+        string msg;
+        string uniform_bytes;
     }
 
     function eq(string memory a, string memory b) public pure returns (bool) {
@@ -41,4 +55,9 @@ abstract contract Common is TestBase {
         bytes memory data = vm.parseJson(vm.readFile("test/data/testcases.json"));
         return abi.decode(data, (TestCase[]));
     }
+
+    function fixture_xmd() public view returns (ExpandMsgTestCase[] memory testcases) {
+        bytes memory data = vm.parseJson(vm.readFile("test/data/expand_msg.json"));
+        return abi.decode(data, (ExpandMsgTestCase[]));
+    }
 }
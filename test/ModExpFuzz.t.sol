@@ -4,11 +4,25 @@ pragma solidity ^0.8.13;
 import {Test} from "forge-std-1.10.0/src/Test.sol";
 import {ModexpInverse} from "src/libraries/ModExp.sol";
 import {ModexpSqrt} from "src/libraries/ModExp.sol";
+import {ModexpInverse as GeneratedModexpInverse} from "test/generated/ModExp.sol";
+import {ModexpSqrt as GeneratedModexpSqrt} from "test/generated/ModExp.sol";
+import {ModexpLegendre} from "test/generated/ModExp.sol";
+import {BLS2ModexpInverse} from "test/generated/ModExp.sol";
+import {BLS2ModexpSqrt} from "test/generated/ModExp.sol";
 
 contract ModExpFuzz is Test {
     // BN254 field order
     uint256 constant N = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47;
 
+    // BLS12-381 base field order, as the (uint128, uint256) pair of BLS2.sol
+    uint128 constant P_HI = 0x1a0111ea397fe69a4b1ba7b6434bacd7;
+    uint256 constant P_LO = 0x64774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab;
+    // P - 2 and (P + 1) / 4
+    bytes constant P_MINUS_2 =
+        hex"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaa9";
+    bytes constant P_PLUS_1_DIV_4 =
+        hex"0680447a8e5ff9a692c6e9ed90d2eb35d91dd2e13ce144afd9cc34a83dac3d8907aaffffac54ffffee7fbfffffffeaab";
+
     function testFfiModExpInverse(uint256 base) public {
         // Convert base to hex string
         string memory baseHex = vm.toString(abi.encodePacked(base));
@@ -61,6 +75,90 @@ contract ModExpFuzz is Test {
         assertEq(rustResult, solResult, "Rust ModexpSqrt and Solidity ModexpSqrt should match");
     }
 
+    // test/generated/ModExp.sol is the output of `just addchain`, checked against the modexp1 oracle
+    function testFfiGeneratedModExpInverse(uint256 base) public {
+        uint256 result = GeneratedModexpInverse.run(base);
+
+        assertEq(result, _ffiModExp(base, N - 2), "Generated ModexpInverse and modexp1 should match");
+        assertEq(result, ModexpInverse.run(base), "Generated and existing ModexpInverse should match");
+    }
+
+    function testFfiGeneratedModExpSqrt(uint256 base) public {
+        uint256 result = GeneratedModexpSqrt.run(base);
+
+        assertEq(result, _ffiModExp(base, (N + 1) / 4), "Generated ModexpSqrt and modexp1 should match");
+        assertEq(result, ModexpSqrt.run(base), "Generated and existing ModexpSqrt should match");
+    }
+
+    function testFfiModExpLegendre(uint256 base) public {
+        uint256 result = ModexpLegendre.run(base);
+
+        assertEq(result, _ffiModExp(base, (N - 1) / 2), "ModexpLegendre and modexp1 should match");
+        if (base % N == 0) {
+            assertEq(result, 0, "The Legendre symbol of zero should be zero");
+        } else {
+            assertTrue(result == 1 || result == N - 1, "The Legendre symbol should be 1 or -1");
+        }
+    }
+
+    // the BLS12-381 libraries reduce each product with MODEXP, checked against the modexp2 oracle and
+    // a single MODEXP by the exponent
+    function testFfiGeneratedBLS2ModExpInverse(uint128 x_hi, uint256 x_lo) public {
+        (uint128 hi, uint256 lo) = BLS2ModexpInverse.run(x_hi, x_lo);
+
+        (uint128 ffi_hi, uint256 ffi_lo) = _ffiModExp2(x_hi, x_lo, P_MINUS_2);
+        assertEq(hi, ffi_hi, "BLS2ModexpInverse and modexp2 should match");
+        assertEq(lo, ffi_lo, "BLS2ModexpInverse and modexp2 should match");
+        (uint128 modexp_hi, uint256 modexp_lo) = _modExp2(x_hi, x_lo, P_MINUS_2);
+        assertEq(hi, modexp_hi, "BLS2ModexpInverse and MODEXP should match");
+        assertEq(lo, modexp_lo, "BLS2ModexpInverse and MODEXP should match");
+    }
+
+    function testFfiGeneratedBLS2ModExpSqrt(uint128 x_hi, uint256 x_lo) public {
+        (uint128 hi, uint256 lo) = BLS2ModexpSqrt.run(x_hi, x_lo);
+
+        (uint128 ffi_hi, uint256 ffi_lo) = _ffiModExp2(x_hi, x_lo, P_PLUS_1_DIV_4);
+        assertEq(hi, ffi_hi, "BLS2ModexpSqrt and modexp2 should match");
+        assertEq(lo, ffi_lo, "BLS2ModexpSqrt and modexp2 should match");
+        (uint128 modexp_hi, uint256 modexp_lo) = _modExp2(x_hi, x_lo, P_PLUS_1_DIV_4);
+        assertEq(hi, modexp_hi, "BLS2ModexpSqrt and MODEXP should match");
+        assertEq(lo, modexp_lo, "BLS2ModexpSqrt and MODEXP should match");
+    }
+
+    function _ffiModExp(uint256 base, uint256 exponent) internal returns (uint256) {
+        // Call the Rust binary to compute base^exponent mod N
+        string[] memory cmd = new string[](4);
+        cmd[0] = "./target/release/bls_ffi";
+        cmd[1] = "modexp1";
+        cmd[2] = vm.toString(abi.encodePacked(base));
+        cmd[3] = vm.toString(abi.encodePacked(exponent));
+
+        string memory output = string(vm.ffi(cmd));
+        return _bytesToUint256(vm.parseBytes(_extractValue(output, "modexp_result: ")));
+    }
+
+    function _ffiModExp2(uint128 x_hi, uint256 x_lo, bytes memory exponent) internal returns (uint128, uint256) {
+        // Call the Rust binary to compute x^exponent mod P
+        string[] memory cmd = new string[](4);
+        cmd[0] = "./target/release/bls_ffi";
+        cmd[1] = "modexp2";
+        cmd[2] = vm.toString(abi.encodePacked(uint256(x_hi), x_lo));
+        cmd[3] = vm.toString(exponent);
+
+        string memory output = string(vm.ffi(cmd));
+        return _bytesToFp(vm.parseBytes(_extractValue(output, "modexp_result: ")));
+    }
+
+    function _modExp2(uint128 x_hi, uint256 x_lo, bytes memory exponent) internal view returns (uint128, uint256) {
+        bytes memory input = abi.encodePacked(
+            uint256(64), exponent.length, uint256(64), uint256(x_hi), x_lo, exponent, uint256(P_HI), P_LO
+        );
+        (bool ok, bytes memory output) = address(0x05).staticcall(input);
+        assertTrue(ok, "MODEXP should succeed");
+        (uint256 hi, uint256 lo) = abi.decode(output, (uint256, uint256));
+        return (uint128(hi), lo);
+    }
+
     function _extractValue(string memory output, string memory key) internal pure returns (string memory) {
         // Extract the value corresponding to the key from the output
         bytes memory outputBytes = bytes(output);
@@ -104,4 +202,12 @@ contract ModExpFuzz is Test {
             number = number | uint8(b[i]);
         }
     }
+
+    function _bytesToFp(bytes memory b) internal pure returns (uint128 hi, uint256 lo) {
+        // at most 48 bytes, the first ones shifted from lo to hi
+        for (uint256 i = 0; i < b.length; i++) {
+            hi = (hi << 8) | uint128(lo >> 248);
+            lo = (lo << 8) | uint8(b[i]);
+        }
+    }
 }
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8;
+
+import {Test} from "forge-std-1.10.0/src/Test.sol";
+import {ErrorsLib} from "src/libraries/ErrorsLib.sol";
+import {BN254SignatureScheme} from "src/signature-schemes/BN254SignatureScheme.sol";
+import {TestScheduledUpgradeable, MockImplementation} from "test/ScheduledUpgradeableTest.t.sol";
+
+/// @notice End-to-end ScheduledUpgradeable flows, authorized with signatures from `bls_ffi upgrade-*`
+contract ScheduledUpgradeableFfiTest is Test {
+    TestScheduledUpgradeable public scheduledUpgradeable;
+    BN254SignatureScheme public blsValidator;
+    MockImplementation public mockImpl;
+
+    string public constant APPLICATION = "scheduled-upgradeable-test";
+    string public constant PRIVATE_KEY = "0x2a4b1a2cd8d6ee4a4d1f7e0b6c9f23a6a0e2ab0c6d5e2f7c1b9e6a3d8f0c4b71";
+    uint256 public constant MIN_DELAY = 2 days;
+    bytes public constant UPGRADE_CALLDATA = abi.encodeWithSignature("initialize()");
+
+    function setUp() public {
+        mockImpl = new MockImplementation();
+        scheduledUpgradeable = new TestScheduledUpgradeable();
+
+        // the public key does not depend on the signed message
+        (bytes memory publicKey,) = _authorize("upgrade-set-delay", _params(vm.toString(MIN_DELAY), "0"));
+        blsValidator = new BN254SignatureScheme(publicKey, APPLICATION);
+
+        scheduledUpgradeable.initialize(address(blsValidator), MIN_DELAY);
+    }
+
+    function testFfi_scheduleAndCancelUpgrade() public {
+        uint256 upgradeTime = block.timestamp + MIN_DELAY + 1;
+
+        (, bytes memory signature) = _authorize(
+            "upgrade-schedule",
+            _params(
+                vm.toString(address(0)),
+                vm.toString(address(mockImpl)),
+                vm.toString(UPGRADE_CALLDATA),
+                vm.toString(upgradeTime),
+                "1"
+            )
+        );
+        scheduledUpgradeable.scheduleUpgrade(address(mockImpl), UPGRADE_CALLDATA, upgradeTime, signature);
+        assertEq(scheduledUpgradeable.scheduledImplementation(), address(mockImpl));
+        assertEq(scheduledUpgradeable.scheduledTimestampForUpgrade(), upgradeTime);
+
+        (, signature) = _authorize(
+            "upgrade-cancel",
+            _params(vm.toString(address(mockImpl)), vm.toString(UPGRADE_CALLDATA), vm.toString(upgradeTime), "2")
+        );
+        scheduledUpgradeable.cancelUpgrade(signature);
+        assertEq(scheduledUpgradeable.scheduledImplementation(), address(0));
+        assertEq(scheduledUpgradeable.currentNonce(), 2);
+    }
+
+    function testFfi_setContractUpgradeBlsValidator() public {
+        BN254SignatureScheme newValidator = new BN254SignatureScheme(blsValidator.getPublicKeyBytes(), "other");
+
+        (, bytes memory signature) =
+            _authorize("upgrade-set-validator", _params(vm.toString(address(newValidator)), "1"));
+        scheduledUpgradeable.setContractUpgradeBlsValidator(address(newValidator), signature);
+        assertEq(address(scheduledUpgradeable.contractUpgradeBlsValidator()), address(newValidator));
+    }
+
+    function testFfi_setMinimumContractUpgradeDelay() public {
+        uint256 newDelay = 3 days;
+
+        (, bytes memory signature) = _authorize("upgrade-set-delay", _params(vm.toString(newDelay), "1"));
+        scheduledUpgradeable.setMinimumContractUpgradeDelay(newDelay, signature);
+        assertEq(scheduledUpgradeable.minimumContractUpgradeDelay(), newDelay);
+    }
+
+    function testFfi_revertsOnWrongNonce() public {
+        uint256 newDelay = 3 days;
+
+        (, bytes memory signature) = _authorize("upgrade-set-delay", _params(vm.toString(newDelay), "2"));
+        vm.expectRevert(ErrorsLib.BLSSignatureVerificationFailed.selector);
+        scheduledUpgradeable.setMinimumContractUpgradeDelay(newDelay, signature);
+    }
+
+    /// @dev Runs `bls_ffi <command> BN254 <dst> <private key> <chain id> <contract> <params...>`
+    function _authorize(string memory command, string[] memory params)
+        internal
+        returns (bytes memory publicKey, bytes memory signature)
+    {
+        string[] memory cmd = new string[](7 + params.length);
+        cmd[0] = "./target/release/bls_ffi";
+        cmd[1] = command;
+        cmd[2] = "BN254";
+        cmd[3] = string.concat(
+            APPLICATION, "-BN254G1_XMD:KECCAK-256_SVDW_RO_", vm.toString(bytes32(block.chainid)), "_"
+        );
+        cmd[4] = PRIVATE_KEY;
+        cmd[5] = vm.toString(block.chainid);
+        cmd[6] = vm.toString(address(scheduledUpgradeable));
+        for (uint256 i = 0; i < params.length; i++) {
+            cmd[7 + i] = params[i];
+        }
+
+        string memory output = string(vm.ffi(cmd));
+        publicKey = vm.parseBytes(_extractValue(output, "public_key: "));
+        signature = vm.parseBytes(_extractValue(output, "signature: "));
+    }
+
+    function _params(string memory a, string memory b) internal pure returns (string[] memory params) {
+        params = new string[](2);
+        params[0] = a;
+        params[1] = b;
+    }
+
+    function _params(string memory a, string memory b, string memory c, string memory d)
+        internal
+        pure
+        returns (string[] memory params)
+    {
+        params = new string[](4);
+        params[0] = a;
+        params[1] = b;
+        params[2] = c;
+        params[3] = d;
+    }
+
+    function _params(string memory a, string memory b, string memory c, string memory d, string memory e)
+        internal
+        pure
+        returns (string[] memory params)
+    {
+        params = new string[](5);
+        params[0] = a;
+        params[1] = b;
+        params[2] = c;
+        params[3] = d;
+        params[4] = e;
+    }
+
+    function _extractValue(string memory output, string memory key) internal pure returns (string memory) {
+        // Extract the value corresponding to the key from the output
+        bytes memory outputBytes = bytes(output);
+        bytes memory keyBytes = bytes(key);
+        uint256 start = _indexOf(outputBytes, keyBytes) + keyBytes.length;
+        uint256 end = start;
+
+        // Find the end of the value (newline character)
+        while (end < outputBytes.length && outputBytes[end] != 0x0a) {
+            end++;
+        }
+
+        // Copy the range [start:end] into a new bytes array
+        bytes memory value = new bytes(end - start);
+        for (uint256 i = start; i < end; i++) {
+            value[i - start] = outputBytes[i];
+        }
+
+        return string(value);
+    }
+
+    function _indexOf(bytes memory haystack, bytes memory needle) internal pure returns (uint256) {
+        for (uint256 i = 0; i <= haystack.length - needle.length; i++) {
+            bool isMatch = true;
+            for (uint256 j = 0; j < needle.length; j++) {
+                if (haystack[i + j] != needle[j]) {
+                    isMatch = false;
+                    break;
+                }
+            }
+            if (isMatch) {
+                return i;
+            }
+        }
+        revert("Key not found");
+    }
+}
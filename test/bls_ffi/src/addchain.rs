@@ -0,0 +1,87 @@
+//! Generates the addition chain libraries of `ModExp.sol`, e.g. `test/generated/ModExp.sol` with
+//! `just addchain`, after checking each chain against `modpow` like the `modexp1` and `modexp2`
+//! oracles.
+
+use bls_solidity_rs::addchain::{self, Chain, Exponentiation};
+use num_bigint::BigUint;
+
+/// Entry point for the `addchain` command: [--stats] <exponentiation>...
+pub fn run(args: &[String]) -> anyhow::Result<()> {
+    let (stats, names) = match args {
+        [flag, names @ ..] if flag == "--stats" => (true, names),
+        names => (false, names),
+    };
+    if names.is_empty() {
+        anyhow::bail!("missing arguments, see usage");
+    }
+
+    let mut libraries = Vec::new();
+    for name in names {
+        let exponentiation = parse(name)?;
+        let chain = Chain::search(&exponentiation.exponent);
+        verify(&exponentiation, &chain)?;
+        if stats {
+            println!(
+                "{name}: {} mulmod, {} squarings and {} multiplications, window of {} bits, {} registers",
+                chain.len(),
+                chain.squarings(),
+                chain.multiplications(),
+                chain.window(),
+                chain.registers()
+            );
+        } else {
+            let command = format!("bls_ffi addchain {name}");
+            libraries.push(
+                exponentiation
+                    .library(&chain, &command)
+                    .map_err(|e| anyhow::anyhow!("{name}: {e}, only --stats supports it"))?,
+            );
+        }
+    }
+    if !stats {
+        print!("{}", addchain::solidity(&libraries));
+    }
+    Ok(())
+}
+
+/// One of `Exponentiation::NAMES`, or <library>:<modulus hex>:<exponent hex>.
+fn parse(name: &str) -> anyhow::Result<Exponentiation> {
+    let Some((library, rest)) = name.split_once(':') else {
+        return Ok(Exponentiation::named(name)?);
+    };
+    let Some((modulus, exponent)) = rest.split_once(':') else {
+        anyhow::bail!("expected <library>:<modulus hex>:<exponent hex>, got {name}");
+    };
+    let uint = |hex: &str| {
+        BigUint::parse_bytes(hex.strip_prefix("0x").unwrap_or(hex).as_bytes(), 16)
+            .ok_or_else(|| anyhow::anyhow!("invalid hex: {hex}"))
+    };
+    let (modulus, exponent) = (uint(modulus)?, uint(exponent)?);
+    if exponent.bits() == 0 || modulus.bits() < 2 {
+        anyhow::bail!("the exponent must not be zero and the modulus must be at least 2");
+    }
+    Ok(Exponentiation::custom(library, modulus, exponent))
+}
+
+/// The chain computes the exponent, and the registers of the generated code give the same results
+/// as `modpow` for a few bases.
+fn verify(exponentiation: &Exponentiation, chain: &Chain) -> anyhow::Result<()> {
+    let Exponentiation {
+        modulus, exponent, ..
+    } = exponentiation;
+    if chain.exponent() != *exponent {
+        anyhow::bail!("the chain computes x^0x{:x}", chain.exponent());
+    }
+    let bases = [
+        BigUint::from(2u32),
+        BigUint::from(3u32),
+        modulus - 1u32,
+        modulus / 3u32,
+    ];
+    for base in bases {
+        if chain.evaluate(&base, modulus) != base.modpow(exponent, modulus) {
+            anyhow::bail!("the chain and modpow differ for 0x{base:x}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Parsing of command line arguments into ABI values.
+
+use bls_solidity_rs::abi::{Address, Uint256, left_pad};
+
+use num_bigint::BigUint;
+
+/// Parses a decimal or `0x` prefixed hex unsigned integer into a 32 bytes word.
+pub fn parse_uint(s: &str) -> anyhow::Result<Uint256> {
+    let value = match s.strip_prefix("0x") {
+        Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+        None => BigUint::parse_bytes(s.as_bytes(), 10),
+    }
+    .ok_or_else(|| anyhow::anyhow!("invalid integer: {s}"))?;
+    let bytes = value.to_bytes_be();
+    anyhow::ensure!(bytes.len() <= 32, "integer does not fit in uint256: {s}");
+    Ok(left_pad(&bytes))
+}
+
+/// Parses a decimal or `0x` prefixed hex unsigned integer that fits in 64 bits, e.g. a nonce or a
+/// timestamp.
+pub fn parse_u64(s: &str) -> anyhow::Result<u64> {
+    let word = parse_uint(s)?;
+    anyhow::ensure!(
+        word[..24].iter().all(|b| *b == 0),
+        "integer does not fit in uint64: {s}"
+    );
+    Ok(u64::from_be_bytes(word[24..].try_into().unwrap()))
+}
+
+/// Parses a `0x` prefixed address, with or without checksum.
+pub fn parse_address(s: &str) -> anyhow::Result<Address> {
+    let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("invalid address: {s}"))
+}
@@ -0,0 +1,57 @@
+//! Runs the simulated DKG of `bls_solidity_rs::dkg` and prints its transcript as a JSON fixture.
+
+use bls_solidity_rs::Scheme;
+use bls_solidity_rs::dkg::{self, Config, Fault};
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Entry point for the `dkg` command: <scheme> <n> <t> [options]
+pub fn run(args: &[String]) -> anyhow::Result<()> {
+    let [scheme, n, t, options @ ..] = args else {
+        anyhow::bail!("missing arguments, see usage");
+    };
+    let scheme: Scheme = scheme.parse()?;
+    let mut config = Config {
+        n: n.parse()?,
+        t: t.parse()?,
+        faults: Vec::new(),
+    };
+    let mut rng = StdRng::from_entropy();
+
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        let Some(value) = options.next() else {
+            anyhow::bail!("missing value for {option}");
+        };
+        match option.as_str() {
+            "--seed" => rng = StdRng::seed_from_u64(value.parse()?),
+            "--absent" => config.faults.push(Fault::Absent {
+                dealer: value.parse()?,
+            }),
+            // <dealer>:<recipient>,<recipient>...[:answered]
+            "--wrong-shares" => {
+                let (dealer, rest) = value
+                    .split_once(':')
+                    .ok_or_else(|| anyhow::anyhow!("invalid --wrong-shares: {value}"))?;
+                let (recipients, answers) = match rest.strip_suffix(":answered") {
+                    Some(recipients) => (recipients, true),
+                    None => (rest, false),
+                };
+                config.faults.push(Fault::WrongShares {
+                    dealer: dealer.parse()?,
+                    recipients: recipients
+                        .split(',')
+                        .map(str::parse)
+                        .collect::<Result<_, _>>()?,
+                    answers,
+                });
+            }
+            _ => anyhow::bail!("unknown option {option}"),
+        }
+    }
+
+    let transcript = dkg::simulate(scheme, &config, &mut rng)?;
+    println!("{}", transcript.to_json());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Keystores holding the secret keys of the `upgrade-*` commands, so that they are not passed on
+//! the command line.
+//!
+//! The password is read from `BLS_KEYSTORE_PASSWORD`, or from stdin when it is not set.
+
+use bls_solidity_rs::keystore::{self, Kdf, Keystore, SECRET_LENGTH};
+use bls_solidity_rs::{Curve, Scheme};
+
+use rand::RngCore;
+
+use std::io::BufRead;
+
+const PASSWORD_VAR: &str = "BLS_KEYSTORE_PASSWORD";
+const KEYSTORE_PREFIX: &str = "keystore:";
+
+/// The big-endian secret key of a `<private key>` argument: hex, or `keystore:<path>`.
+pub fn secret_key(private_key: &str, scheme: Scheme) -> anyhow::Result<Vec<u8>> {
+    match private_key.strip_prefix(KEYSTORE_PREFIX) {
+        Some(path) => {
+            let keystore = read(path)?;
+            Ok(keystore.unlock(scheme.into(), &password()?)?.to_vec())
+        }
+        None => Ok(hex::decode(
+            private_key.strip_prefix("0x").unwrap_or(private_key),
+        )?),
+    }
+}
+
+/// Entry point for the `keystore-*` commands.
+pub fn run(command: &str, args: &[String]) -> anyhow::Result<()> {
+    match (command, args) {
+        ("keystore-new", [scheme, path, kdf @ ..]) => {
+            let curve = Curve::from(scheme.parse::<Scheme>()?);
+            let mut secret = [0u8; SECRET_LENGTH];
+            // rejection sampling, the scalar must be smaller than the group order
+            while keystore::public_key(curve, &secret).is_none() {
+                rand::rngs::OsRng.fill_bytes(&mut secret);
+            }
+            create(curve, &secret, path, kdf)
+        }
+        ("keystore-import", [scheme, path, kdf @ ..]) => {
+            // read from stdin rather than the arguments, before the password if it is not set
+            let curve = Curve::from(scheme.parse::<Scheme>()?);
+            let line = read_line()?;
+            let secret = hex::decode(line.strip_prefix("0x").unwrap_or(&line))?;
+            let secret: [u8; SECRET_LENGTH] = secret.try_into().map_err(|s: Vec<u8>| {
+                anyhow::anyhow!("expected {SECRET_LENGTH} bytes, got {}", s.len())
+            })?;
+            create(curve, &secret, path, kdf)
+        }
+        ("keystore-pubkey", [path]) => {
+            let keystore = read(path)?;
+            println!("curve: {}", keystore.curve);
+            println!("public_key: 0x{}", hex::encode(&keystore.pubkey));
+            Ok(())
+        }
+        _ => anyhow::bail!("invalid arguments for {command}, see usage"),
+    }
+}
+
+fn create(
+    curve: Curve,
+    secret: &[u8; SECRET_LENGTH],
+    path: &str,
+    kdf: &[String],
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !std::path::Path::new(path).exists(),
+        "{path} already exists"
+    );
+    let rng = &mut rand::rngs::OsRng;
+    let kdf = match kdf {
+        [] => Kdf::scrypt(rng),
+        [kdf] if kdf == "scrypt" => Kdf::scrypt(rng),
+        [kdf] if kdf == "pbkdf2" => Kdf::pbkdf2(rng),
+        _ => anyhow::bail!("<kdf> is scrypt or pbkdf2"),
+    };
+    let keystore = Keystore::encrypt(curve, secret, &password()?, kdf, "", rng)?;
+    std::fs::write(path, keystore.to_json())?;
+    println!("public_key: 0x{}", hex::encode(&keystore.pubkey));
+    Ok(())
+}
+
+fn read(path: &str) -> anyhow::Result<Keystore> {
+    let json =
+        std::fs::read_to_string(path).map_err(|e| anyhow::anyhow!("cannot read {path}: {e}"))?;
+    Ok(Keystore::from_json(&json)?)
+}
+
+fn password() -> anyhow::Result<String> {
+    match std::env::var(PASSWORD_VAR) {
+        Ok(password) => Ok(password),
+        Err(_) => read_line(),
+    }
+}
+
+fn read_line() -> anyhow::Result<String> {
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_owned())
+}
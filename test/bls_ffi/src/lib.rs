@@ -0,0 +1,3 @@
+//! Reference implementations shared by the `bls_ffi` commands and the fuzz targets.
+
+pub mod svdw;
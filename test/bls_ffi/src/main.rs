@@ -1,18 +1,16 @@
+mod addchain;
+mod args;
+mod dkg;
+mod keystore;
+mod precompile;
+mod pubkey;
+mod upgrade;
+
 use num_bigint::BigUint;
 use std::env;
-// use num_traits::{Zero, One};
-use ark_ec::{AffineRepr, CurveGroup};
-use ark_ff::PrimeField;
-use ark_ff::Zero;
-
 
-
-use utils::hash_to_curve::CustomPairingHashToCurve;
-use utils::serialize::point::PointSerializeUncompressed;
-
-fn hex_ser_uncompressed(p: &impl PointSerializeUncompressed) -> String {
-    hex::encode(p.ser_uncompressed().unwrap())
-}
+use bls_ffi::svdw;
+use bls_solidity_rs::{bls12_381, bn254};
 
 fn hex_format_bytes(bytes: &[u8]) -> String {
     let hex_str = format!("{:x}", BigUint::from_bytes_be(bytes));
@@ -37,11 +35,60 @@ fn main() {
         eprintln!(
             "  - modexp1 <base_hex> <exponent_hex> -> Compute modular exponentiation (base^exponent mod modulus)"
         );
+        eprintln!(
+            "  - modexp2 <base_hex> <exponent_hex> -> Compute modular exponentiation modulo the BLS12-381 base field order"
+        );
         eprintln!("  - ModexpInverse <base_hex> -> Compute modular inverse (base^(N-2) mod N)");
         eprintln!(
             "  - ModexpSqrt <base_hex> -> Compute modular square root (base^((N+1)/4) mod N)"
         );
         eprintln!("  - mapToPointBN254 <u_hex> -> Map a field element to a point on BN254 curve");
+        eprintln!(
+            "  - upgrade-schedule <scheme> <dst> <private key> <chain_id> <contract> <pending_implementation> <new_implementation> <calldata_hex> <upgrade_time> <nonce> -> Sign a ScheduledUpgradeable scheduleUpgrade"
+        );
+        eprintln!(
+            "  - upgrade-cancel <scheme> <dst> <private key> <chain_id> <contract> <scheduled_implementation> <calldata_hex> <upgrade_time> <nonce> -> Sign a ScheduledUpgradeable cancelUpgrade"
+        );
+        eprintln!(
+            "  - upgrade-set-validator <scheme> <dst> <private key> <chain_id> <contract> <validator> <nonce> -> Sign a ScheduledUpgradeable setContractUpgradeBlsValidator"
+        );
+        eprintln!(
+            "  - upgrade-set-delay <scheme> <dst> <private key> <chain_id> <contract> <delay> <nonce> -> Sign a ScheduledUpgradeable setMinimumContractUpgradeDelay"
+        );
+        eprintln!(
+            "    <scheme> is the validator SCHEME_ID (BN254, BLS12381 or BLS12381Compressed), <private key> is big-endian hex or keystore:<path>"
+        );
+        eprintln!(
+            "  - keystore-new <scheme> <path> [scrypt|pbkdf2] -> Create a keystore with a random secret key"
+        );
+        eprintln!(
+            "  - keystore-import <scheme> <path> [scrypt|pbkdf2] -> Create a keystore with the big-endian secret key read from stdin"
+        );
+        eprintln!("  - keystore-pubkey <path> -> Print the g2Marshal public key of a keystore");
+        eprintln!(
+            "  - dkg <scheme> <n> <t> [--seed <u64>] [--absent <dealer>] [--wrong-shares <dealer>:<recipient>,...[:answered]] -> Simulate a DKG and print its shares, commitments and group public key as JSON"
+        );
+        eprintln!(
+            "  - pubkey <curve> <from> <to|all> <public_key_hex> -> Validate a public key and convert it between formats"
+        );
+        eprintln!(
+            "    <curve> is bn254 or bls12-381, formats are compressed, g2marshal, arkworks, eip2537 and solidity (compressed and eip2537 are BLS12-381 only)"
+        );
+        eprintln!(
+            "  - precompile <address> <input_hex> -> Emulate the MODEXP, ECADD, ECMUL, ECPAIRING, BLS12_G1ADD, BLS12_PAIRING_CHECK or BLS12_MAP_FP_TO_G1 precompile"
+        );
+        eprintln!(
+            "  - addchain [--stats] <exponentiation>... -> Generate the ModExp.sol libraries of addition chains, or print their length"
+        );
+        eprintln!(
+            "    <exponentiation> is bn254-inverse, bn254-sqrt, bn254-legendre, bls12-381-inverse, bls12-381-sqrt or <library>:<modulus_hex>:<exponent_hex>"
+        );
+        eprintln!(
+            "    moduli of up to 256 bits are reduced by mulmod, larger ones of up to 384 bits by the MODEXP precompile"
+        );
+        eprintln!(
+            "    keystore passwords are read from BLS_KEYSTORE_PASSWORD, or from stdin when it is not set"
+        );
         std::process::exit(1);
     }
     let version = &args[1];
@@ -70,19 +117,18 @@ fn main() {
         private_key_array.copy_from_slice(&private_key_bytes[..64]);
 
         let dst = b"BLS_DST";
-        let private_key = ark_bn254::Fr::from_le_bytes_mod_order(&private_key_array);
+        let private_key = bn254::SecretKey::from_le_bytes_mod_order(&private_key_array);
 
         // Compute the public key (private_key * G2 generator)
-        let public_key = (ark_bn254::G2Affine::generator() * private_key).into_affine();
+        let public_key = private_key.public_key();
 
         // Hash the message to a point on G1
-        let hashed_message =
-            ark_bn254::Bn254::hash_to_g1_custom::<sha3::Keccak256>(&msg_bytes, dst).into_affine();
+        let hashed_message = bn254::hash_to_point(dst, &msg_bytes);
         // Compute the signature (private_key * hashed_message)
-        let signature = (hashed_message * private_key).into_affine();
-        let hex_public_key = hex_ser_uncompressed(&public_key);
-        let hex_signature = hex_ser_uncompressed(&signature);
-        let hex_hashed_message = hex_ser_uncompressed(&hashed_message);
+        let signature = private_key.sign(dst, &msg_bytes);
+        let hex_public_key = hex::encode(public_key.to_bytes());
+        let hex_signature = hex::encode(signature.to_bytes());
+        let hex_hashed_message = hex::encode(bn254::g1_marshal(&hashed_message));
 
         // Print the public key, signature, and private key in hex format
         println!("public_key: 0x{}", hex_public_key);
@@ -112,18 +158,16 @@ fn main() {
         private_key_array.copy_from_slice(&private_key_bytes[..64]);
 
         let dst = b"BLS_DST";
-        let private_key = ark_bls12_381::Fr::from_le_bytes_mod_order(&private_key_array);
+        let private_key = bls12_381::SecretKey::from_le_bytes_mod_order(&private_key_array);
         // Compute the public key (private_key * G2 generator)
-        let public_key = (ark_bls12_381::G2Affine::generator() * private_key).into_affine();
+        let public_key = private_key.public_key();
         // Hash the message to a point on G1
-        let hashed_message =
-            ark_bls12_381::Bls12_381::hash_to_g1_custom::<sha2::Sha256>(&msg_bytes, dst)
-                .into_affine();
+        let hashed_message = bls12_381::hash_to_point(dst, &msg_bytes);
         // Compute the signature (private_key * hashed_message)
-        let signature = (hashed_message * private_key).into_affine();
-        let hex_public_key = hex_ser_uncompressed(&public_key);
-        let hex_signature = hex_ser_uncompressed(&signature);
-        let hex_hashed_message = hex_ser_uncompressed(&hashed_message);
+        let signature = private_key.sign(dst, &msg_bytes);
+        let hex_public_key = hex::encode(public_key.to_bytes());
+        let hex_signature = hex::encode(signature.to_bytes());
+        let hex_hashed_message = hex::encode(bls12_381::g1_marshal(&hashed_message));
 
         // Print the public key, signature, and private key in hex format
         println!("public_key: 0x{}", hex_public_key);
@@ -160,6 +204,41 @@ fn main() {
 
         let result = base.modpow(&exponent, &modulus);
 
+        println!(
+            "modexp_result: 0x{}",
+            hex_format_bytes(&result.to_bytes_be())
+        );
+    } else if version == "modexp2" {
+        // Usage: bls_ffi modexp2 <base_hex> <exponent_hex>, modulo the BLS12-381 base field order
+        if args.len() != 4 {
+            eprintln!("Usage: bls_ffi modexp2 <base_hex> <exponent_hex>");
+            std::process::exit(1);
+        }
+        let base_hex = &args[2];
+        let exponent_hex = &args[3];
+
+        let base = BigUint::parse_bytes(
+            base_hex.strip_prefix("0x").unwrap_or(base_hex).as_bytes(),
+            16,
+        )
+        .unwrap();
+        let exponent = BigUint::parse_bytes(
+            exponent_hex
+                .strip_prefix("0x")
+                .unwrap_or(exponent_hex)
+                .as_bytes(),
+            16,
+        )
+        .unwrap();
+        // BLS12-381 base field order
+        let modulus = BigUint::parse_bytes(
+            b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+            16,
+        )
+        .unwrap();
+
+        let result = base.modpow(&exponent, &modulus);
+
         println!(
             "modexp_result: 0x{}",
             hex_format_bytes(&result.to_bytes_be())
@@ -228,131 +307,61 @@ fn main() {
         let u_hex = &args[2];
         let u =
             BigUint::parse_bytes(u_hex.strip_prefix("0x").unwrap_or(u_hex).as_bytes(), 16).unwrap();
-        // BN254 field order
-        let n = BigUint::parse_bytes(
-            b"30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
-            16,
-        )
-        .unwrap();
-        // Constants from Solidity
-        let c1 = BigUint::from(4u32);
-        let c2 = BigUint::parse_bytes(
-            b"183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3",
-            16,
-        )
-        .unwrap();
-        let c3 =
-            BigUint::parse_bytes(b"16789af3a83522eb353c98fc6b36d713d5d8d1cc5dffffffa", 16).unwrap();
-        let c4 = BigUint::parse_bytes(
-            b"10216f7ba065e00de81ac1e7808072c9dd2b2385cd7b438469602eb24829a9bd",
-            16,
-        )
-        .unwrap();
-        let z = BigUint::from(1u32);
-        // Helper functions
-        fn addmod(a: &BigUint, b: &BigUint, n: &BigUint) -> BigUint {
-            (a + b) % n
-        }
-        fn mulmod(a: &BigUint, b: &BigUint, n: &BigUint) -> BigUint {
-            (a * b) % n
-        }
-        fn inv0(a: &BigUint, n: &BigUint) -> BigUint {
-            if a.is_zero() {
-                BigUint::zero()
-            } else {
-                a.modpow(&(n - BigUint::from(2u32)), n)
-            }
-        }
-        fn sgn0(x: &BigUint) -> u8 {
-            if x.bit(0) { 1 } else { 0 }
-        }
-        fn g(x: &BigUint, n: &BigUint) -> BigUint {
-            addmod(&mulmod(&mulmod(x, x, n), x, n), &BigUint::from(3u32), n)
-        }
-        fn legendre(u: &BigUint, n: &BigUint) -> i8 {
-            let exp = (n.clone() - BigUint::from(1u32)) / BigUint::from(2u32);
-            let x = u.modpow(&exp, n);
-            if x == n.clone() - BigUint::from(1u32) {
-                -1
-            } else if x == BigUint::zero() {
-                0
-            } else if x == BigUint::from(1u32) {
-                1
-            } else {
-                panic!("MapToPointFailed: legendre({})", u)
-            }
-        }
-        fn sqrt(xx: &BigUint, n: &BigUint) -> Option<BigUint> {
-            let exp = (n.clone() + BigUint::from(1u32)) / BigUint::from(4u32);
-            let x = xx.modpow(&exp, n);
-            if mulmod(&x, &x, n) == *xx {
-                Some(x)
-            } else {
-                None
-            }
-        }
-        // SvdW mapping
-        if u >= n {
-            eprintln!("InvalidFieldElement: u >= N");
-            std::process::exit(1);
-        }
         println!("u = {}", u);
-        let tv1 = mulmod(&mulmod(&u, &u, &n), &c1, &n);
-        let tv2 = addmod(&BigUint::from(1u32), &tv1, &n);
-        let tv1_ = addmod(&BigUint::from(1u32), &(n.clone() - tv1.clone()), &n);
-        let tv3 = inv0(&mulmod(&tv1_, &tv2, &n), &n);
-        let tv5 = mulmod(&mulmod(&mulmod(&u, &tv1_, &n), &tv3, &n), &c3, &n);
-        let x1 = addmod(&c2, &(n.clone() - tv5.clone()), &n);
-        let x2 = addmod(&c2, &tv5, &n);
-        let tv7 = mulmod(&tv2, &tv2, &n);
-        let tv8 = mulmod(&tv7, &tv3, &n);
-        let x3 = addmod(&z, &mulmod(&c4, &mulmod(&tv8, &tv8, &n), &n), &n);
-
-        println!("x3 = {}", x3);
-
-        let mut px = None;
-        let mut py = None;
-        let mut gx = None;
-        if legendre(&g(&x1, &n), &n) == 1 {
-            px = Some(x1.clone());
-            gx = Some(g(&x1, &n));
-            py = sqrt(&gx.clone().unwrap(), &n);
-            if py.is_none() {
-                eprintln!("MapToPointFailed: no sqrt for gx");
-                std::process::exit(1);
-            }
-        } else if legendre(&g(&x2, &n), &n) == 1 {
-            px = Some(x2.clone());
-            gx = Some(g(&x2, &n));
-            py = sqrt(&gx.clone().unwrap(), &n);
-            if py.is_none() {
-                eprintln!("MapToPointFailed: no sqrt for gx");
+        let (px, py) = match svdw::map_to_point(&u) {
+            Ok(point) => point,
+            Err(e) => {
+                eprintln!("{}", e);
                 std::process::exit(1);
             }
-        } else {
-            px = Some(x3.clone());
-            gx = Some(g(&x3, &n));
-            py = sqrt(&gx.clone().unwrap(), &n);
-            if py.is_none() {
-                eprintln!("MapToPointFailed: no sqrt for gx");
-                std::process::exit(1);
-            }
-        }
-        let mut py_val = py.unwrap();
-        if sgn0(&u) != sgn0(&py_val) {
-            py_val = &n - &py_val;
-        }
+        };
 
-        println!("px = {}", px.clone().unwrap());
-        println!("py = {}", py_val);
+        println!("px = {}", px);
+        println!("py = {}", py);
         println!(
             "mapToPointBN254: x = 0x{}",
-            hex_format_bytes(&px.unwrap().to_bytes_be())
+            hex_format_bytes(&px.to_bytes_be())
         );
         println!(
             "mapToPointBN254: y = 0x{}",
-            hex_format_bytes(&py_val.to_bytes_be())
+            hex_format_bytes(&py.to_bytes_be())
         );
+    } else if version.starts_with("upgrade-") {
+        // Usage: bls_ffi upgrade-<action> <scheme> <dst> <private key> <chain_id> <contract> ...
+        if let Err(e) = upgrade::run(version, &args[2..]) {
+            eprintln!("{}: {}", version, e);
+            std::process::exit(1);
+        }
+    } else if version == "dkg" {
+        // Usage: bls_ffi dkg <scheme> <n> <t> [options]
+        if let Err(e) = dkg::run(&args[2..]) {
+            eprintln!("{}: {}", version, e);
+            std::process::exit(1);
+        }
+    } else if version == "pubkey" {
+        // Usage: bls_ffi pubkey <curve> <from> <to|all> <public_key_hex>
+        if let Err(e) = pubkey::run(&args[2..]) {
+            eprintln!("{}: {}", version, e);
+            std::process::exit(1);
+        }
+    } else if version == "precompile" {
+        // Usage: bls_ffi precompile <address> <input_hex>
+        if let Err(e) = precompile::run(&args[2..]) {
+            eprintln!("{}: {}", version, e);
+            std::process::exit(1);
+        }
+    } else if version == "addchain" {
+        // Usage: bls_ffi addchain [--stats] <exponentiation>...
+        if let Err(e) = addchain::run(&args[2..]) {
+            eprintln!("{}: {}", version, e);
+            std::process::exit(1);
+        }
+    } else if version.starts_with("keystore-") {
+        // Usage: bls_ffi keystore-<action> ...
+        if let Err(e) = keystore::run(version, &args[2..]) {
+            eprintln!("{}: {}", version, e);
+            std::process::exit(1);
+        }
     } else {
         eprintln!("Unsupported version: {}", version);
         std::process::exit(1);
@@ -0,0 +1,19 @@
+//! Replays a precompile call outside of an EVM, e.g. an input copied from a `forge test -vvvv`
+//! trace, printing the output or the check that makes the call fail.
+
+use bls_solidity_rs::precompiles;
+
+/// Entry point for the `precompile` command: <address> <input hex>
+pub fn run(args: &[String]) -> anyhow::Result<()> {
+    let [address, input] = args else {
+        anyhow::bail!("missing arguments, see usage");
+    };
+    let address = match address.strip_prefix("0x") {
+        Some(hex) => u8::from_str_radix(hex, 16)?,
+        None => address.parse()?,
+    };
+    let input = hex::decode(input.strip_prefix("0x").unwrap_or(input))?;
+    let output = precompiles::call(address, &input)?;
+    println!("0x{}", hex::encode(output));
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! Converts a public key between the layouts of `bls_solidity_rs::pubkey`, e.g. drand's compressed
+//! quicknet key to the `publicKeyBytes` of a signature scheme constructor.
+
+use bls_solidity_rs::Curve;
+use bls_solidity_rs::pubkey::{Format, PublicKey};
+
+/// Entry point for the `pubkey` command: <curve> <from> <to|all> <public key hex>
+pub fn run(args: &[String]) -> anyhow::Result<()> {
+    let [curve, from, to, key] = args else {
+        anyhow::bail!("missing arguments, see usage");
+    };
+    let curve: Curve = curve.parse()?;
+    let bytes = hex::decode(key.strip_prefix("0x").unwrap_or(key))?;
+    let key = PublicKey::decode(curve, from.parse()?, &bytes)?;
+
+    if to == "all" {
+        for format in Format::ALL.into_iter().filter(|f| f.supports(curve)) {
+            println!("{format}: 0x{}", hex::encode(key.encode(format)?));
+        }
+    } else {
+        println!("0x{}", hex::encode(key.encode(to.parse()?)?));
+    }
+    Ok(())
+}
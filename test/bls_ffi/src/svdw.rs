@@ -0,0 +1,127 @@
+//! `BLS.mapToPoint` on big integers, the reference the `mapToPointBN254` command prints for
+//! `BLSTestFuzz`: the Shallue-van de Woestijne map of RFC 9380 §6.6.1 with the constants of
+//! `BLS.sol`.
+
+use ark_ff::{One, Zero};
+use num_bigint::BigUint;
+
+use std::fmt;
+
+/// BN254 field order
+const N: &[u8] = b"30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+// Constants from Solidity
+const C2: &[u8] = b"183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3";
+const C3: &[u8] = b"16789af3a83522eb353c98fc6b36d713d5d8d1cc5dffffffa";
+const C4: &[u8] = b"10216f7ba065e00de81ac1e7808072c9dd2b2385cd7b438469602eb24829a9bd";
+
+/// Errors named like the reverts of `BLS.mapToPoint`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `u` is not smaller than the field order.
+    InvalidFieldElement,
+    /// The Legendre symbol or square root computation gave a value it cannot, e.g. for a modulus
+    /// that is not prime.
+    MapToPointFailed(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidFieldElement => write!(f, "InvalidFieldElement: u >= N"),
+            Error::MapToPointFailed(reason) => write!(f, "MapToPointFailed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The field order `mapToPoint` reduces into.
+pub fn modulus() -> BigUint {
+    parse(N)
+}
+
+/// The affine coordinates `BLS.mapToPoint(u)` returns.
+pub fn map_to_point(u: &BigUint) -> Result<(BigUint, BigUint), Error> {
+    let n = modulus();
+    if *u >= n {
+        return Err(Error::InvalidFieldElement);
+    }
+    let c1 = BigUint::from(4u32);
+    let (c2, c3, c4) = (parse(C2), parse(C3), parse(C4));
+    let z = BigUint::one();
+    let one = BigUint::one();
+
+    let tv1 = mulmod(&mulmod(u, u, &n), &c1, &n);
+    let tv2 = addmod(&one, &tv1, &n);
+    let tv1_ = addmod(&one, &(&n - &tv1), &n);
+    let tv3 = inv0(&mulmod(&tv1_, &tv2, &n), &n);
+    let tv5 = mulmod(&mulmod(&mulmod(u, &tv1_, &n), &tv3, &n), &c3, &n);
+    let x1 = addmod(&c2, &(&n - &tv5), &n);
+    let x2 = addmod(&c2, &tv5, &n);
+    let tv7 = mulmod(&tv2, &tv2, &n);
+    let tv8 = mulmod(&tv7, &tv3, &n);
+    let x3 = addmod(&z, &mulmod(&c4, &mulmod(&tv8, &tv8, &n), &n), &n);
+
+    let x = if legendre(&g(&x1, &n), &n)? == 1 {
+        x1
+    } else if legendre(&g(&x2, &n), &n)? == 1 {
+        x2
+    } else {
+        x3
+    };
+    let gx = g(&x, &n);
+    let mut y =
+        sqrt(&gx, &n).ok_or_else(|| Error::MapToPointFailed("no sqrt for gx".to_owned()))?;
+    if sgn0(u) != sgn0(&y) {
+        y = (&n - &y) % &n;
+    }
+    Ok((x, y))
+}
+
+fn parse(hex: &[u8]) -> BigUint {
+    BigUint::parse_bytes(hex, 16).expect("valid hex constant")
+}
+
+fn addmod(a: &BigUint, b: &BigUint, n: &BigUint) -> BigUint {
+    (a + b) % n
+}
+
+fn mulmod(a: &BigUint, b: &BigUint, n: &BigUint) -> BigUint {
+    (a * b) % n
+}
+
+fn inv0(a: &BigUint, n: &BigUint) -> BigUint {
+    if a.is_zero() {
+        BigUint::zero()
+    } else {
+        a.modpow(&(n - BigUint::from(2u32)), n)
+    }
+}
+
+fn sgn0(x: &BigUint) -> bool {
+    x.bit(0)
+}
+
+fn g(x: &BigUint, n: &BigUint) -> BigUint {
+    addmod(&mulmod(&mulmod(x, x, n), x, n), &BigUint::from(3u32), n)
+}
+
+fn legendre(u: &BigUint, n: &BigUint) -> Result<i8, Error> {
+    let exp = (n - BigUint::one()) / BigUint::from(2u32);
+    let x = u.modpow(&exp, n);
+    if x == n - BigUint::one() {
+        Ok(-1)
+    } else if x.is_zero() {
+        Ok(0)
+    } else if x.is_one() {
+        Ok(1)
+    } else {
+        Err(Error::MapToPointFailed(format!("legendre({u})")))
+    }
+}
+
+fn sqrt(xx: &BigUint, n: &BigUint) -> Option<BigUint> {
+    let exp = (n + BigUint::one()) / BigUint::from(4u32);
+    let x = xx.modpow(&exp, n);
+    (mulmod(&x, &x, n) == *xx).then_some(x)
+}
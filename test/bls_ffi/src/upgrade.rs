@@ -0,0 +1,116 @@
+//! Signs the authorizations `ScheduledUpgradeable` checks before changing its state.
+//!
+//! Messages are built by the `bls_solidity_rs::upgrade` planner from the contract state given on
+//! the command line, hashed to G1 with the DST of the validator contract, then signed with the
+//! threshold key. The nonce is not checked, so that tests can sign replays.
+
+use crate::{args, keystore};
+
+use bls_solidity_rs::upgrade::{Operation, Planner, Target, UpgradeState, Validator};
+use bls_solidity_rs::{Scheme, bls12_381, bn254};
+
+pub struct Authorization {
+    /// public key to deploy the validator with, as expected by its constructor
+    pub public_key: Vec<u8>,
+    /// output of the validator's `hashToBytes(message)`
+    pub message_g1: Vec<u8>,
+    /// signature bytes to pass to the `ScheduledUpgradeable` function
+    pub signature: Vec<u8>,
+}
+
+/// Hashes `message` with the validator's DST and signs it with the big-endian secret key.
+pub fn sign(scheme: Scheme, dst: &[u8], secret_key: &[u8], message: &[u8]) -> Authorization {
+    match scheme {
+        Scheme::Bn254 => {
+            let sk = bn254::SecretKey::from_be_bytes_mod_order(secret_key);
+            Authorization {
+                public_key: sk.public_key().to_bytes().to_vec(),
+                message_g1: scheme.hash_to_bytes(dst, message),
+                signature: sk.sign(dst, message).to_bytes().to_vec(),
+            }
+        }
+        Scheme::Bls12381 | Scheme::Bls12381Compressed => {
+            let sk = bls12_381::SecretKey::from_be_bytes_mod_order(secret_key);
+            let s = sk.sign(dst, message);
+            let signature = if scheme == Scheme::Bls12381Compressed {
+                s.to_compressed_bytes().to_vec()
+            } else {
+                s.to_bytes().to_vec()
+            };
+            Authorization {
+                public_key: sk.public_key().to_bytes().to_vec(),
+                message_g1: scheme.hash_to_bytes(dst, message),
+                signature,
+            }
+        }
+    }
+}
+
+/// Entry point for the `upgrade-*` commands.
+/// Common arguments: <scheme> <dst> <private_key> <chain_id> <contract>
+pub fn run(command: &str, args: &[String]) -> anyhow::Result<()> {
+    let [scheme, dst, private_key, chain_id, contract, rest @ ..] = args else {
+        anyhow::bail!("missing arguments, see usage");
+    };
+    let scheme: Scheme = scheme.parse()?;
+    let secret_key = keystore::secret_key(private_key, scheme)?;
+    let target = Target {
+        contract: args::parse_address(contract)?,
+        chain_id: args::parse_uint(chain_id)?,
+    };
+
+    // the state the message is signed against, the rest of the storage is not part of it
+    let mut state = UpgradeState {
+        current_nonce: 0,
+        scheduled_implementation: Default::default(),
+        scheduled_implementation_calldata: Vec::new(),
+        scheduled_timestamp_for_upgrade: 0,
+        minimum_contract_upgrade_delay: 0,
+        contract_upgrade_bls_validator: Validator {
+            address: Default::default(),
+            scheme,
+            dst: dst.as_bytes().to_vec(),
+        },
+    };
+    let (operation, nonce) = match (command, rest) {
+        ("upgrade-schedule", [pending, new, calldata, upgrade_time, nonce]) => {
+            state.scheduled_implementation = args::parse_address(pending)?;
+            let operation = Operation::ScheduleUpgrade {
+                new_implementation: args::parse_address(new)?,
+                upgrade_calldata: hex::decode(calldata.strip_prefix("0x").unwrap_or(calldata))?,
+                upgrade_time: args::parse_u64(upgrade_time)?,
+            };
+            (operation, nonce)
+        }
+        ("upgrade-cancel", [scheduled, calldata, upgrade_time, nonce]) => {
+            state.scheduled_implementation = args::parse_address(scheduled)?;
+            state.scheduled_implementation_calldata =
+                hex::decode(calldata.strip_prefix("0x").unwrap_or(calldata))?;
+            state.scheduled_timestamp_for_upgrade = args::parse_u64(upgrade_time)?;
+            (Operation::CancelUpgrade, nonce)
+        }
+        ("upgrade-set-validator", [validator, nonce]) => {
+            let validator = Validator {
+                address: args::parse_address(validator)?,
+                ..state.contract_upgrade_bls_validator.clone()
+            };
+            (Operation::SetContractUpgradeBlsValidator(validator), nonce)
+        }
+        ("upgrade-set-delay", [delay, nonce]) => (
+            Operation::SetMinimumContractUpgradeDelay(args::parse_u64(delay)?),
+            nonce,
+        ),
+        _ => anyhow::bail!("invalid arguments for {command}, see usage"),
+    };
+    let message = Planner::new(target, state)
+        .authorize(&operation, args::parse_u64(nonce)?)
+        .expect("only executeUpgrade is not signed")
+        .message;
+
+    let auth = sign(scheme, dst.as_bytes(), &secret_key, &message);
+    println!("message: 0x{}", hex::encode(&message));
+    println!("message_g1: 0x{}", hex::encode(&auth.message_g1));
+    println!("public_key: 0x{}", hex::encode(&auth.public_key));
+    println!("signature: 0x{}", hex::encode(&auth.signature));
+    Ok(())
+}
@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8;
+
+// Code generated by make_test_signature. DO NOT EDIT.
+
+import {BLS} from "src/libraries/BLS.sol";
+import {BLS2} from "src/libraries/BLS2.sol";
+
+/// @notice Known-good vectors from test/data/testcases.json as typed constants
+library Fixtures {
+    bytes internal constant BLS12_DST = "BLS12381G1_XMD:SHA-256_SSWU_RO";
+    bytes internal constant BLS12_MESSAGE = hex"68656c6c6f";
+    bytes internal constant BLS12_SIG_COMPRESSED =
+        hex"994929b59a7ae688de17265497aadfee598969e2e31e72d98a492a6c2999d3712b08d2800c39956e934bd55215f20e2f";
+
+    bytes internal constant BN254_DST = "BN254G1_XMD:KECCAK-256_SVDW_RO";
+    bytes internal constant BN254_MESSAGE = hex"68656c6c6f";
+
+    bytes internal constant QUICKNET_20791007_DST = "BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+    bytes internal constant QUICKNET_20791007_MESSAGE =
+        hex"eb26460c7495053b531c3d007789953c47874f3380635090554e0f68619bbbeb";
+    bytes internal constant QUICKNET_20791007_SIG_COMPRESSED =
+        hex"8d2c8bbc37170dbacc5e280a21d4e195cff5f32a19fd6a58633fa4e4670478b5fb39bc13dd8f8c4372c5a76191198ac5";
+    uint64 internal constant QUICKNET_20791007_ROUND = 20791007;
+
+    bytes internal constant QUICKNET_20905307_DST = "BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+    bytes internal constant QUICKNET_20905307_MESSAGE =
+        hex"b33ffdb664cbfb43d83dc13179ce992b7272e01041df2f4c1ae7b41b4ef000ff";
+    bytes internal constant QUICKNET_20905307_SIG_COMPRESSED =
+        hex"8a60486975062d9f06633c284cf1a7b46fb343f56f329f180530ca40a9e86320244f4fbfc37ae866cf25ef499665a31f";
+    uint64 internal constant QUICKNET_20905307_ROUND = 20905307;
+
+    bytes internal constant EVMNET_9337227_DST = "BLS_SIG_BN254G1_XMD:KECCAK-256_SVDW_RO_NUL_";
+    bytes internal constant EVMNET_9337227_MESSAGE =
+        hex"baf09720c37cb921fd8362b1d907232ac0b813ffba768c714aeaace987e7fd6b";
+    uint64 internal constant EVMNET_9337227_ROUND = 9337227;
+
+    bytes internal constant DCIPHER_BLS12_DST =
+        "dcipher-helloworld-v01-BLS12381G1_XMD:SHA-256_SSWU_RO_0x0000000000000000000000000000000000000000000000000000000000007a69_";
+    bytes internal constant DCIPHER_BLS12_MESSAGE = hex"68656c6c6f";
+    bytes internal constant DCIPHER_BLS12_SIG_COMPRESSED =
+        hex"a9982f56bebf00ff391ad129162c4bd09340717537de5f52cb5180d2e19a6eef8870b0b0a50025ba38bc22d6ed4848d7";
+    string internal constant DCIPHER_BLS12_APPLICATION = "dcipher-helloworld-v01";
+
+    bytes internal constant DCIPHER_BN254_DST =
+        "dcipher-helloworld-v01-BN254G1_XMD:KECCAK-256_SVDW_RO_0x0000000000000000000000000000000000000000000000000000000000007a69_";
+    bytes internal constant DCIPHER_BN254_MESSAGE = hex"68656c6c6f";
+    string internal constant DCIPHER_BN254_APPLICATION = "dcipher-helloworld-v01";
+
+    bytes internal constant RFC9380_SSWU_0_DST = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    bytes internal constant RFC9380_SSWU_0_MESSAGE = hex"";
+    bytes internal constant RFC9380_SSWU_0_SIG_COMPRESSED =
+        hex"b37824c1faf852d652da20464c146f547ff109dffc0183d5bb390192b1e9a5cf6b8d6d98a50e692e88003f8a11bc77e4";
+
+    bytes internal constant RFC9380_SSWU_1_DST = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    bytes internal constant RFC9380_SSWU_1_MESSAGE = hex"616263";
+    bytes internal constant RFC9380_SSWU_1_SIG_COMPRESSED =
+        hex"b023683e7f2a3a329f2add97a991001d5f37c4502ab7c000fb8d26efaacfb15fa862cf7006a4087906a6e9d63622a882";
+
+    bytes internal constant RFC9380_SSWU_2_DST = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    bytes internal constant RFC9380_SSWU_2_MESSAGE = hex"61626364656630313233343536373839";
+    bytes internal constant RFC9380_SSWU_2_SIG_COMPRESSED =
+        hex"aca98ab7c950845e120359483b1c81c001361b4423e3459c41c8dce8713f9f7f743fd29ab00a1298ff15dc316bf1108b";
+
+    bytes internal constant RFC9380_SSWU_3_DST = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    bytes internal constant RFC9380_SSWU_3_MESSAGE =
+        hex"713132385f7171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171717171";
+    bytes internal constant RFC9380_SSWU_3_SIG_COMPRESSED =
+        hex"a1c911fea6636d6a9868f4f4f8f1aef2c9017e3873756b7979b9eec84ce90f399c84e0ff61ed693b22d2b075e7cf0bd9";
+
+    bytes internal constant RFC9380_SSWU_4_DST = "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    bytes internal constant RFC9380_SSWU_4_MESSAGE =
+        hex"613531325f6161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161";
+    bytes internal constant RFC9380_SSWU_4_SIG_COMPRESSED =
+        hex"aea663bb95b50de111daef8acbeff9c62ce9683772376ac3187ef5e482bff0f488e8ccf8e3087eeeb269bff01dd72852";
+
+    function bls12Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x0eb3c62c162b4bf3da2df034c4ebf8f7,
+            0x53c929a6e2424269f41558c8d3c6358a38bc199199a3cc4f3c275525f72e6ed0,
+            0x0ea36aa928f4d6a58765ac61398baed7,
+            0xd1b195b71f7de3714fb0b87edf71792313a5b1650264cbfff03f78bafbd65900,
+            0x01d140f45a64fcf285f51f2e55ed1143,
+            0x2e3829cd027dc2e6adb4a2fbc99e2aac0faf0aef517b525a3d5d80aa6cc41acd,
+            0x12c785bd8662d22ce36627e15ea5de6d,
+            0x3cb642be582410da7c95dc1ffc9bff902f05fff594f4956b2137cde3f172c71d
+        );
+    }
+
+    function bls12Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x194929b59a7ae688de17265497aadfee,
+            0x598969e2e31e72d98a492a6c2999d3712b08d2800c39956e934bd55215f20e2f,
+            0x09c9dc98ea512821c8b4613e738f57bf,
+            0x87d0ad7fd9a0af37a64ce8d291863389462611ee1ecacf2ac499eee6f92ebbf0
+        );
+    }
+
+    function bls12MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x1404a1e08bc4ad361ba2a7a93c02823a,
+            0x1ecf9b9adac07e0b31e58ed3d84ef789d4a6a7d49eb84b5ff619de010b4eaac1,
+            0x07cf4227e834183905d8b35feca3be6c,
+            0x0b596d8c20172b85acf11c09f6de1eb501e4c162c05d78bb88b4fff7628b8175
+        );
+    }
+
+    function bn254Pk() internal pure returns (BLS.PointG2 memory) {
+        return BLS.PointG2(
+            [
+                0x23b64419d9ddd59cb837cdb98daf579b4cc9d90f321f95ef3798019f0c1616bf,
+                0x22cad93df062e86037c449ecf0f1ab956bc0fe08e18f41ce08dcc23ed8b4a720
+            ],
+            [
+                0x0ba32ef34cb863878ed8808a575664c16f2858e8658f41dbe2e8f8ac9dc748e9,
+                0x24cb4991ed92a138a3f61f8e3e650ac31f387db455a11007d577adf37aa7e431
+            ]
+        );
+    }
+
+    function bn254Sig() internal pure returns (BLS.PointG1 memory) {
+        return BLS.PointG1(
+            0x3051b9c6270891200c4f7bc979485abafb3640fe96e622f014c317341a5def5c,
+            0x07fd4fa7c3da14eb90f03c7fd57b0c8c78996b24c1aed724f2f0bbc31ec7f2b9
+        );
+    }
+
+    function bn254MExpected() internal pure returns (BLS.PointG1 memory) {
+        return BLS.PointG1(
+            0x0beef402c4c94fac44fe220ef0cb7f56fb2d2f6a0500930282c170ee6afa447f,
+            0x1cb8fd2d4488f28d14d1ab7a4ac224049e24f245481e670a51178d2895bb10a1
+        );
+    }
+
+    function quicknet20791007Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x03cf0f2896adee7eb8b5f01fcad39122,
+            0x12c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d106451,
+            0x0d1fec758c921cc22b0e17e63aaf4bcb,
+            0x5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a,
+            0x01a714f2edb74119a2f2b0d5a7c75ba9,
+            0x02d163700a61bc224ededd8e63aef7be1aaf8e93d7a9718b047ccddb3eb5d68b,
+            0x0e5db2b6bfbb01c867749cadffca88b3,
+            0x6c24f3012ba09fc4d3022c5c37dce0f977d3adb5d183c7477c442b1f04515273
+        );
+    }
+
+    function quicknet20791007Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x0d2c8bbc37170dbacc5e280a21d4e195,
+            0xcff5f32a19fd6a58633fa4e4670478b5fb39bc13dd8f8c4372c5a76191198ac5,
+            0x0823ff37364b4060af65c7ec4dde05a4,
+            0x28e4a444713680d95c34a4b109f112af1792643c742b75d85940c4bdcfdfbfa1
+        );
+    }
+
+    function quicknet20791007MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x17d2ccf27b4a3e2a3f58f0c09eb4b281,
+            0x37d1d1beb5c37628bec43f645dcbc58d86f482b7f6b2bd5ebd53f7f7361d7855,
+            0x0c0ac30904f6d5a300f034d9a6200d00,
+            0x8e451c13dc50443a0667755a4a61e10a51edc491d7cd96bdc6c33415213107a5
+        );
+    }
+
+    function quicknet20905307Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x03cf0f2896adee7eb8b5f01fcad39122,
+            0x12c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d106451,
+            0x0d1fec758c921cc22b0e17e63aaf4bcb,
+            0x5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a,
+            0x01a714f2edb74119a2f2b0d5a7c75ba9,
+            0x02d163700a61bc224ededd8e63aef7be1aaf8e93d7a9718b047ccddb3eb5d68b,
+            0x0e5db2b6bfbb01c867749cadffca88b3,
+            0x6c24f3012ba09fc4d3022c5c37dce0f977d3adb5d183c7477c442b1f04515273
+        );
+    }
+
+    function quicknet20905307Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x0a60486975062d9f06633c284cf1a7b4,
+            0x6fb343f56f329f180530ca40a9e86320244f4fbfc37ae866cf25ef499665a31f,
+            0x08c61b5471ed86344d6b347d1b0e1a41,
+            0x46877a57c28507448678d8249521d91be74cd5a44fb6fce5f869b235e085ebe6
+        );
+    }
+
+    function quicknet20905307MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x01785306ff8d502fc0c90bd175d2c74c,
+            0xfe6906f3196f7db7026af3344c65fd6ad5c32a5ae541d17c9d9643c74b2799d9,
+            0x09c6ff272912edc97e93772adf5f8476,
+            0x0aff5754019c11037964aacb782022fc83c386fc6abafc3707e25c11437af908
+        );
+    }
+
+    function evmnet9337227Pk() internal pure returns (BLS.PointG2 memory) {
+        return BLS.PointG2(
+            [
+                0x0557ec32c2ad488e4d4f6008f89a346f18492092ccc0d594610de2732c8b808f,
+                0x07e1d1d335df83fa98462005690372c643340060d205306a9aa8106b6bd0b382
+            ],
+            [
+                0x297d3a4f9749b33eb2d904c9d9ebf17224150ddd7abd7567a9bec6c74480ee0b,
+                0x0095685ae3a85ba243747b1b2f426049010f6b73a0cf1d389351d5aaaa1047f6
+            ]
+        );
+    }
+
+    function evmnet9337227Sig() internal pure returns (BLS.PointG1 memory) {
+        return BLS.PointG1(
+            0x01d65d6128f4b2df3d08de85543d8efe06b0281d0770246ae3672e8ddd3efda0,
+            0x269373123458f0b5c0073eeed1c816a06809e127421513e34ee07df6987910b3
+        );
+    }
+
+    function evmnet9337227MExpected() internal pure returns (BLS.PointG1 memory) {
+        return BLS.PointG1(
+            0x1626082c3dd0751bdaaf8c3e709b5dd7cdedf45d4e81a5aa3e270f1e78924b32,
+            0x2bf29ab3af54dfe3c053ad4efa93db05d3586368463e9d7334c7ba61f6f4e955
+        );
+    }
+
+    function dcipherBls12Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x0eb3c62c162b4bf3da2df034c4ebf8f7,
+            0x53c929a6e2424269f41558c8d3c6358a38bc199199a3cc4f3c275525f72e6ed0,
+            0x0ea36aa928f4d6a58765ac61398baed7,
+            0xd1b195b71f7de3714fb0b87edf71792313a5b1650264cbfff03f78bafbd65900,
+            0x01d140f45a64fcf285f51f2e55ed1143,
+            0x2e3829cd027dc2e6adb4a2fbc99e2aac0faf0aef517b525a3d5d80aa6cc41acd,
+            0x12c785bd8662d22ce36627e15ea5de6d,
+            0x3cb642be582410da7c95dc1ffc9bff902f05fff594f4956b2137cde3f172c71d
+        );
+    }
+
+    function dcipherBls12Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x09982f56bebf00ff391ad129162c4bd0,
+            0x9340717537de5f52cb5180d2e19a6eef8870b0b0a50025ba38bc22d6ed4848d7,
+            0x0e23af4f08f04aec75f33c2a9e989ba1,
+            0x97442f9ce8c0b3189e1cadce259689336d7bfcd12085352b5e76e195078804be
+        );
+    }
+
+    function dcipherBls12MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x15268ac9c58e50542abb4bee936d1fa6,
+            0x5d582f6e404db90999fff550b733f7810756378f553f8e1284ec1154ca493562,
+            0x114b58bb4e2cf0af9dabbbc327159fa8,
+            0x6c55f609a63b889de99eed50917b04400e3316a3f7e73aa8a7c85a4880f6758b
+        );
+    }
+
+    function dcipherBn254Pk() internal pure returns (BLS.PointG2 memory) {
+        return BLS.PointG2(
+            [
+                0x23b64419d9ddd59cb837cdb98daf579b4cc9d90f321f95ef3798019f0c1616bf,
+                0x22cad93df062e86037c449ecf0f1ab956bc0fe08e18f41ce08dcc23ed8b4a720
+            ],
+            [
+                0x0ba32ef34cb863878ed8808a575664c16f2858e8658f41dbe2e8f8ac9dc748e9,
+                0x24cb4991ed92a138a3f61f8e3e650ac31f387db455a11007d577adf37aa7e431
+            ]
+        );
+    }
+
+    function dcipherBn254Sig() internal pure returns (BLS.PointG1 memory) {
+        return BLS.PointG1(
+            0x11c4a53e9b6daab0578b02e08ada2921a4b01de3d090111094f077200a79ba81,
+            0x295f100f7d5f2dd4c38bdbe573ffa7b69de99dd7dd7b84b31fe2ce9126b583f7
+        );
+    }
+
+    function dcipherBn254MExpected() internal pure returns (BLS.PointG1 memory) {
+        return BLS.PointG1(
+            0x08b70ec12bec2465bd5e245a7ddde3e619dba68b6e59dc4fce6bb1c58d76451a,
+            0x0f78beba66e72e912d5699966a3c4de5ea28e792b267671552e7d07936fc78aa
+        );
+    }
+
+    function rfc9380Sswu0Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x0eb3c62c162b4bf3da2df034c4ebf8f7,
+            0x53c929a6e2424269f41558c8d3c6358a38bc199199a3cc4f3c275525f72e6ed0,
+            0x0ea36aa928f4d6a58765ac61398baed7,
+            0xd1b195b71f7de3714fb0b87edf71792313a5b1650264cbfff03f78bafbd65900,
+            0x01d140f45a64fcf285f51f2e55ed1143,
+            0x2e3829cd027dc2e6adb4a2fbc99e2aac0faf0aef517b525a3d5d80aa6cc41acd,
+            0x12c785bd8662d22ce36627e15ea5de6d,
+            0x3cb642be582410da7c95dc1ffc9bff902f05fff594f4956b2137cde3f172c71d
+        );
+    }
+
+    function rfc9380Sswu0Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x137824c1faf852d652da20464c146f54,
+            0x7ff109dffc0183d5bb390192b1e9a5cf6b8d6d98a50e692e88003f8a11bc77e4,
+            0x1352153313cfa3188118d5497adb9392,
+            0x1502677fa8ef49dfec1ce067d74a7e08d1d887c5c57003919992c2be9530f792
+        );
+    }
+
+    function rfc9380Sswu0MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x052926add2207b76ca4fa57a8734416c,
+            0x8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1,
+            0x08ba738453bfed09cb546dbb0783dbb3,
+            0xa5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265
+        );
+    }
+
+    function rfc9380Sswu1Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x0eb3c62c162b4bf3da2df034c4ebf8f7,
+            0x53c929a6e2424269f41558c8d3c6358a38bc199199a3cc4f3c275525f72e6ed0,
+            0x0ea36aa928f4d6a58765ac61398baed7,
+            0xd1b195b71f7de3714fb0b87edf71792313a5b1650264cbfff03f78bafbd65900,
+            0x01d140f45a64fcf285f51f2e55ed1143,
+            0x2e3829cd027dc2e6adb4a2fbc99e2aac0faf0aef517b525a3d5d80aa6cc41acd,
+            0x12c785bd8662d22ce36627e15ea5de6d,
+            0x3cb642be582410da7c95dc1ffc9bff902f05fff594f4956b2137cde3f172c71d
+        );
+    }
+
+    function rfc9380Sswu1Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x1023683e7f2a3a329f2add97a991001d,
+            0x5f37c4502ab7c000fb8d26efaacfb15fa862cf7006a4087906a6e9d63622a882,
+            0x11b1e66f31c898c3f326ef2e23366ddf,
+            0xc7151ee207f99bdacc8252b89fee7b96414d177fb58bbb9c98501e4283d35595
+        );
+    }
+
+    function rfc9380Sswu1MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x03567bc5ef9c690c2ab2ecdf6a96ef1c,
+            0x139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903,
+            0x0b9c15f3fe6e5cf4211f346271d7b01c,
+            0x8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d
+        );
+    }
+
+    function rfc9380Sswu2Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x0eb3c62c162b4bf3da2df034c4ebf8f7,
+            0x53c929a6e2424269f41558c8d3c6358a38bc199199a3cc4f3c275525f72e6ed0,
+            0x0ea36aa928f4d6a58765ac61398baed7,
+            0xd1b195b71f7de3714fb0b87edf71792313a5b1650264cbfff03f78bafbd65900,
+            0x01d140f45a64fcf285f51f2e55ed1143,
+            0x2e3829cd027dc2e6adb4a2fbc99e2aac0faf0aef517b525a3d5d80aa6cc41acd,
+            0x12c785bd8662d22ce36627e15ea5de6d,
+            0x3cb642be582410da7c95dc1ffc9bff902f05fff594f4956b2137cde3f172c71d
+        );
+    }
+
+    function rfc9380Sswu2Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x0ca98ab7c950845e120359483b1c81c0,
+            0x01361b4423e3459c41c8dce8713f9f7f743fd29ab00a1298ff15dc316bf1108b,
+            0x124d91735be647561040c2c8abcdf3bb,
+            0xa09171b4e5d1139b4c3d229065495dd5a9fd4394f5953bf9f43da4fdfdc00014
+        );
+    }
+
+    function rfc9380Sswu2MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x11e0b079dea29a68f0383ee94fed1b94,
+            0x0995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98,
+            0x03a87ae2caf14e8ee52e51fa2ed8eefe,
+            0x80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709
+        );
+    }
+
+    function rfc9380Sswu3Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x0eb3c62c162b4bf3da2df034c4ebf8f7,
+            0x53c929a6e2424269f41558c8d3c6358a38bc199199a3cc4f3c275525f72e6ed0,
+            0x0ea36aa928f4d6a58765ac61398baed7,
+            0xd1b195b71f7de3714fb0b87edf71792313a5b1650264cbfff03f78bafbd65900,
+            0x01d140f45a64fcf285f51f2e55ed1143,
+            0x2e3829cd027dc2e6adb4a2fbc99e2aac0faf0aef517b525a3d5d80aa6cc41acd,
+            0x12c785bd8662d22ce36627e15ea5de6d,
+            0x3cb642be582410da7c95dc1ffc9bff902f05fff594f4956b2137cde3f172c71d
+        );
+    }
+
+    function rfc9380Sswu3Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x01c911fea6636d6a9868f4f4f8f1aef2,
+            0xc9017e3873756b7979b9eec84ce90f399c84e0ff61ed693b22d2b075e7cf0bd9,
+            0x0e2cb09e7c8c8582364016a394b74a65,
+            0x10198cae66ec6113a014893851aa263a838da0e18c556d870b04c4aee00ac193
+        );
+    }
+
+    function rfc9380Sswu3MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x15f68eaa693b95ccb85215dc65fa8103,
+            0x8d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488,
+            0x1807a1d50c29f430b8cafc4f8638dfee,
+            0xadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38
+        );
+    }
+
+    function rfc9380Sswu4Pk() internal pure returns (BLS2.PointG2 memory) {
+        return BLS2.PointG2(
+            0x0eb3c62c162b4bf3da2df034c4ebf8f7,
+            0x53c929a6e2424269f41558c8d3c6358a38bc199199a3cc4f3c275525f72e6ed0,
+            0x0ea36aa928f4d6a58765ac61398baed7,
+            0xd1b195b71f7de3714fb0b87edf71792313a5b1650264cbfff03f78bafbd65900,
+            0x01d140f45a64fcf285f51f2e55ed1143,
+            0x2e3829cd027dc2e6adb4a2fbc99e2aac0faf0aef517b525a3d5d80aa6cc41acd,
+            0x12c785bd8662d22ce36627e15ea5de6d,
+            0x3cb642be582410da7c95dc1ffc9bff902f05fff594f4956b2137cde3f172c71d
+        );
+    }
+
+    function rfc9380Sswu4Sig() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x0ea663bb95b50de111daef8acbeff9c6,
+            0x2ce9683772376ac3187ef5e482bff0f488e8ccf8e3087eeeb269bff01dd72852,
+            0x190e8e17518a0cceada3e298ec050afb,
+            0x53c3b71a15fffe15c666e1e1d3ac0aebd9c78aa25b17d9c720eccd0c2b840fb2
+        );
+    }
+
+    function rfc9380Sswu4MExpected() internal pure returns (BLS2.PointG1 memory) {
+        return BLS2.PointG1(
+            0x082aabae8b7dedb0e78aeb619ad3bfd9,
+            0x277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe,
+            0x05b84ae5a942248eea39e1d91030458c,
+            0x40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8
+        );
+    }
+}
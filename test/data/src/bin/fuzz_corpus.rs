@@ -0,0 +1,80 @@
+//! Writes the seed corpus of the fuzz targets in fuzz/ from testcases.json, so fuzzing starts from
+//! valid points and from the hash to curve inputs the contracts are tested with.
+//!
+//! Usage: fuzz_corpus [testcases.json] [corpus dir], by default ../../fuzz/corpus
+
+use bls_solidity_rs::{bls12_381, compressed, eip2537};
+
+use anyhow::Context;
+use serde_json::Value;
+
+use std::fs::File;
+use std::path::PathBuf;
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let testcases = args.next().unwrap_or_else(|| "testcases.json".to_owned());
+    let corpus = PathBuf::from(
+        args.next()
+            .unwrap_or_else(|| "../../fuzz/corpus".to_owned()),
+    );
+
+    let testcases: Vec<Value> = serde_json::from_reader(
+        File::open(&testcases).with_context(|| format!("cannot open {testcases}"))?,
+    )?;
+    let mut count = 0;
+    for (i, testcase) in testcases.iter().enumerate() {
+        let field = |name: &str| -> anyhow::Result<Vec<u8>> {
+            let value = testcase[name]
+                .as_str()
+                .with_context(|| format!("testcase {i} has no {name}"))?;
+            Ok(hex::decode(value)?)
+        };
+        let mut write = |target: &str, name: &str, bytes: &[u8]| -> anyhow::Result<()> {
+            let dir = corpus.join(target);
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join(format!("{i}-{name}")), bytes)?;
+            count += 1;
+            Ok(())
+        };
+
+        let (pk, m_expected) = (field("pk")?, field("m_expected")?);
+        for name in ["sig", "q0", "q1"] {
+            write("decode_points", name, &field(name)?)?;
+        }
+        write("decode_points", "pk", &pk)?;
+        write("decode_points", "m_expected", &m_expected)?;
+
+        // hash_to_field reads a u16 DST length, the DST and the message
+        let dst = testcase["dst"].as_str().context("dst")?.as_bytes();
+        let length = u16::try_from(dst.len())?.to_be_bytes();
+        write(
+            "hash_to_field",
+            "dst-message",
+            &[&length[..], dst, &field("message")?].concat(),
+        )?;
+
+        match testcase["scheme"].as_str() {
+            Some("BN254") => {
+                write("map_to_point_bn254", "u0", &field("u0")?)?;
+                write("map_to_point_bn254", "u1", &field("u1")?)?;
+            }
+            Some("BLS12381") => {
+                write("decode_points", "sig_compressed", &field("sig_compressed")?)?;
+                // the other encodings of the same points
+                let pk = bls12_381::g2_unmarshal(&pk)?;
+                let m = bls12_381::g1_unmarshal(&m_expected)?;
+                write("decode_points", "pk-eip2537", &eip2537::g2_encode(&pk))?;
+                write(
+                    "decode_points",
+                    "pk-compressed",
+                    &compressed::g2_compress(&pk)?,
+                )?;
+                write("decode_points", "m-eip2537", &eip2537::g1_encode(&m))?;
+            }
+            scheme => anyhow::bail!("testcase {i} has an unknown scheme {scheme:?}"),
+        }
+    }
+    println!("{count} inputs in {}", corpus.display());
+    Ok(())
+}
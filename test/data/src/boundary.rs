@@ -0,0 +1,27 @@
+//! Length edge cases for `BLS.expandMsg`/`BLS2.expandMsg` and the hashing built on top of them.
+
+/// Empty, single byte, around the SHA-256 (64) and Keccak-256 (136) block sizes, and multi-kilobyte.
+pub const MESSAGE_LENGTHS: [usize; 11] = [0, 1, 55, 56, 63, 64, 65, 135, 136, 137, 4096];
+
+/// The DST length is encoded in a single byte.
+pub const DST_LENGTHS: [usize; 3] = [0, 1, 255];
+
+/// DSTs rejected with `InvalidDSTLength`, reduced with `H2C-OVERSIZE-DST-` by RFC 9380.
+pub const OVERSIZE_DST_LENGTHS: [usize; 2] = [256, 1024];
+
+/// An application name long enough for the dcipher DST derived from it to be oversize.
+pub const LONG_APPLICATION: &str = concat!(
+    "dcipher-an-application-whose-name-is-long-enough-to-overflow-the-domain-separation-tag-",
+    "which-is-limited-to-255-bytes-by-expand-message-xmd-once-the-curve-suffix-is-added-v01",
+);
+
+/// Output lengths of `expandMsg`, which writes whole 32 bytes words.
+pub const EXPAND_LENGTHS: [usize; 5] = [32, 48, 96, 128, 255];
+
+pub fn message(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+pub fn dst(len: usize) -> String {
+    "D".repeat(len)
+}
@@ -0,0 +1,222 @@
+//! Renders the test vectors as a Solidity library of typed constants.
+//!
+//! The JSON vectors can only be consumed through `vm.parseJson` in Foundry tests. The generated
+//! `Fixtures.sol` exposes the same data as `BLS.PointG1`/`BLS.PointG2` (BN254) and
+//! `BLS2.PointG1`/`BLS2.PointG2` (BLS12-381) values, so contracts and scripts can use them too.
+
+use crate::TestCase;
+
+use std::fmt::Write;
+
+// forge fmt line length
+const LINE_LENGTH: usize = 120;
+
+pub fn library(testcases: &[(String, TestCase)]) -> String {
+    let mut out = String::new();
+    out.push_str("// SPDX-License-Identifier: MIT\n");
+    out.push_str("pragma solidity ^0.8;\n\n");
+    out.push_str("// Code generated by make_test_signature. DO NOT EDIT.\n\n");
+    out.push_str("import {BLS} from \"src/libraries/BLS.sol\";\n");
+    out.push_str("import {BLS2} from \"src/libraries/BLS2.sol\";\n\n");
+    out.push_str(
+        "/// @notice Known-good vectors from test/data/testcases.json as typed constants\n",
+    );
+    out.push_str("library Fixtures {\n");
+
+    let mut items = Vec::new();
+    for (name, tc) in testcases {
+        items.push(constants(name, tc));
+    }
+    for (name, tc) in testcases {
+        items.push(points(name, tc));
+    }
+    out.push_str(&items.join("\n"));
+
+    out.push_str("}\n");
+    out
+}
+
+fn constants(name: &str, tc: &TestCase) -> String {
+    let name = name.to_uppercase();
+    let mut out = String::new();
+    constant(
+        &mut out,
+        "bytes",
+        &format!("{name}_DST"),
+        &string_literal(&tc.dst),
+    );
+    constant(
+        &mut out,
+        "bytes",
+        &format!("{name}_MESSAGE"),
+        &format!("hex\"{}\"", tc.message),
+    );
+    if tc.scheme == "BLS12381" {
+        constant(
+            &mut out,
+            "bytes",
+            &format!("{name}_SIG_COMPRESSED"),
+            &format!("hex\"{}\"", tc.sig_compressed),
+        );
+    }
+    if !tc.application.is_empty() {
+        constant(
+            &mut out,
+            "string",
+            &format!("{name}_APPLICATION"),
+            &string_literal(&tc.application),
+        );
+    }
+    if tc.drand_round_number != 0 {
+        constant(
+            &mut out,
+            "uint64",
+            &format!("{name}_ROUND"),
+            &tc.drand_round_number.to_string(),
+        );
+    }
+    out
+}
+
+/// A Solidity string literal of `value`: printable ASCII is kept, quotes and backslashes are
+/// escaped and any other byte of the UTF-8 encoding becomes a `\xNN` escape.
+fn string_literal(value: &str) -> String {
+    let mut out = String::from("\"");
+    for &b in value.as_bytes() {
+        match b {
+            b'"' | b'\\' => {
+                out.push('\\');
+                out.push(b as char);
+            }
+            b' '..=b'~' => out.push(b as char),
+            _ => write!(out, "\\x{b:02x}").unwrap(),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn constant(out: &mut String, ty: &str, name: &str, value: &str) {
+    let line = format!("    {ty} internal constant {name} = {value};");
+    if line.len() <= LINE_LENGTH {
+        writeln!(out, "{line}").unwrap();
+    } else {
+        writeln!(out, "    {ty} internal constant {name} =\n        {value};").unwrap();
+    }
+}
+
+fn points(name: &str, tc: &TestCase) -> String {
+    let name = camel_case(name);
+    let (lib, pk, sig, m_expected) = match tc.scheme.as_str() {
+        "BN254" => (
+            "BLS",
+            bn254_g2(&tc.pk),
+            bn254_g1(&tc.sig),
+            bn254_g1(&tc.m_expected),
+        ),
+        "BLS12381" => (
+            "BLS2",
+            bls12_g2(&tc.pk),
+            bls12_g1(&tc.sig),
+            bls12_g1(&tc.m_expected),
+        ),
+        scheme => panic!("unknown scheme {scheme}"),
+    };
+
+    let mut out = String::new();
+    function(
+        &mut out,
+        &format!("{name}Pk"),
+        &format!("{lib}.PointG2"),
+        &pk,
+    );
+    out.push('\n');
+    function(
+        &mut out,
+        &format!("{name}Sig"),
+        &format!("{lib}.PointG1"),
+        &sig,
+    );
+    out.push('\n');
+    function(
+        &mut out,
+        &format!("{name}MExpected"),
+        &format!("{lib}.PointG1"),
+        &m_expected,
+    );
+    out
+}
+
+fn function(out: &mut String, name: &str, ty: &str, body: &str) {
+    writeln!(
+        out,
+        "    function {name}() internal pure returns ({ty} memory) {{"
+    )
+    .unwrap();
+    writeln!(out, "        return {ty}(\n{body}\n        );").unwrap();
+    writeln!(out, "    }}").unwrap();
+}
+
+/// BLS.PointG1(x, y) from `BLS.g1Marshal` bytes
+fn bn254_g1(hex: &str) -> String {
+    args(&limbs(hex, &[32, 32]), 12)
+}
+
+/// BLS.PointG2([x0, x1], [y0, y1]) from `BLS.g2Marshal` bytes, which start with x1
+fn bn254_g2(hex: &str) -> String {
+    let l = limbs(hex, &[32, 32, 32, 32]);
+    let x = format!(
+        "            [\n{}\n            ]",
+        args(&[&l[1], &l[0]], 16)
+    );
+    let y = format!(
+        "            [\n{}\n            ]",
+        args(&[&l[3], &l[2]], 16)
+    );
+    format!("{x},\n{y}")
+}
+
+/// BLS2.PointG1(x_hi, x_lo, y_hi, y_lo) from `BLS2.g1Marshal` bytes
+fn bls12_g1(hex: &str) -> String {
+    args(&limbs(hex, &[16, 32, 16, 32]), 12)
+}
+
+/// BLS2.PointG2(x1_hi, x1_lo, x0_hi, x0_lo, y1_hi, y1_lo, y0_hi, y0_lo) from `BLS2.g2Marshal` bytes
+fn bls12_g2(hex: &str) -> String {
+    args(&limbs(hex, &[16, 32, 16, 32, 16, 32, 16, 32]), 12)
+}
+
+/// Splits a hex encoded point into `0x` prefixed limbs of the given byte sizes.
+fn limbs(hex: &str, sizes: &[usize]) -> Vec<String> {
+    assert_eq!(hex.len(), 2 * sizes.iter().sum::<usize>());
+    let mut rest = hex;
+    sizes
+        .iter()
+        .map(|size| {
+            let (limb, tail) = rest.split_at(2 * size);
+            rest = tail;
+            format!("0x{limb}")
+        })
+        .collect()
+}
+
+fn args(values: &[impl AsRef<str>], indent: usize) -> String {
+    values
+        .iter()
+        .map(|v| format!("{:indent$}{}", "", v.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
+fn camel_case(name: &str) -> String {
+    let mut parts = name.split('_');
+    let mut out = parts.next().unwrap_or_default().to_owned();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            out.extend(first.to_uppercase());
+            out.push_str(chars.as_str());
+        }
+    }
+    out
+}
@@ -0,0 +1,102 @@
+//! Intermediate values of hash_to_curve, following the steps of `BLS.hashToPoint` and
+//! `BLS2.hashToPoint` so a failing deployment can be narrowed down to a single step.
+
+use crate::xmd::expand_message_xmd;
+
+use bls_solidity_rs::{bls12_381, bn254};
+
+use ark_ec::hashing::curve_maps::wb::WBMap;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, LegendreSymbol, MontFp, PrimeField};
+use serde::{Deserialize, Serialize};
+
+// SvdW constants of BLS.sol
+const Z: ark_bn254::Fq = MontFp!("1");
+const B: ark_bn254::Fq = MontFp!("3");
+const C1: ark_bn254::Fq = MontFp!("4");
+const C2: ark_bn254::Fq =
+    MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291");
+const C3: ark_bn254::Fq = MontFp!("8815841940592487685674414971303048083897117035520822607866");
+const C4: ark_bn254::Fq =
+    MontFp!("7296080957279758407415468581752425029565437052432607887563012631548408736189");
+
+/// Intermediate values of hashing `message` with `dst`, hex encoded.
+/// Field elements are big-endian, points are marshalled like `m_expected`.
+#[derive(Serialize, Deserialize)]
+pub struct HashToCurve {
+    pub uniform_bytes: String,
+    pub u0: String,
+    pub u1: String,
+    pub q0: String,
+    pub q1: String,
+}
+
+/// `BLS.expandMsgTo96`, `BLS.hashToField` and `BLS.mapToPoint` for each of the two field elements.
+pub fn bn254(msg: &[u8], dst: &[u8]) -> HashToCurve {
+    let uniform_bytes = expand_message_xmd::<sha3::Keccak256>(msg, dst, 96);
+    let u0 = ark_bn254::Fq::from_be_bytes_mod_order(&uniform_bytes[..48]);
+    let u1 = ark_bn254::Fq::from_be_bytes_mod_order(&uniform_bytes[48..]);
+    let q0 = svdw(u0);
+    let q1 = svdw(u1);
+
+    // BN254 G1 has no cofactor, P = Q0 + Q1
+    assert_eq!((q0 + q1).into_affine(), bn254::hash_to_point(dst, msg));
+
+    HashToCurve {
+        uniform_bytes: hex::encode(uniform_bytes),
+        u0: hex::encode(u0.into_bigint().to_bytes_be()),
+        u1: hex::encode(u1.into_bigint().to_bytes_be()),
+        q0: hex::encode(bn254::g1_marshal(&q0)),
+        q1: hex::encode(bn254::g1_marshal(&q1)),
+    }
+}
+
+/// `BLS2.expandMsg`, then hash_to_field and map_to_curve from RFC 9380 §6.6.3.
+/// Q0 and Q1 are before cofactor clearing, as in the RFC; the EIP-2537 `MAP_FP_TO_G1`
+/// precompile returns them with the cofactor cleared.
+pub fn bls12(msg: &[u8], dst: &[u8]) -> HashToCurve {
+    let uniform_bytes = expand_message_xmd::<sha2::Sha256>(msg, dst, 128);
+    let u0 = ark_bls12_381::Fq::from_be_bytes_mod_order(&uniform_bytes[..64]);
+    let u1 = ark_bls12_381::Fq::from_be_bytes_mod_order(&uniform_bytes[64..]);
+    let q0 = WBMap::<ark_bls12_381::g1::Config>::map_to_curve(u0).unwrap();
+    let q1 = WBMap::<ark_bls12_381::g1::Config>::map_to_curve(u1).unwrap();
+
+    assert_eq!(
+        (q0 + q1).into_affine().clear_cofactor(),
+        bls12_381::hash_to_point(dst, msg)
+    );
+
+    HashToCurve {
+        uniform_bytes: hex::encode(uniform_bytes),
+        u0: hex::encode(u0.into_bigint().to_bytes_be()),
+        u1: hex::encode(u1.into_bigint().to_bytes_be()),
+        q0: hex::encode(bls12_381::g1_marshal(&q0)),
+        q1: hex::encode(bls12_381::g1_marshal(&q1)),
+    }
+}
+
+/// `BLS.mapToPoint`: Shallue-van de Woestijne map from RFC 9380 §6.6.1.
+pub fn svdw(u: ark_bn254::Fq) -> ark_bn254::G1Affine {
+    let g = |x: ark_bn254::Fq| x * x * x + B;
+
+    let tv1 = u * u * C1;
+    let tv2 = ark_bn254::Fq::ONE + tv1;
+    let tv1 = ark_bn254::Fq::ONE - tv1;
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_default();
+    let tv5 = u * tv1 * tv3 * C3;
+    let x1 = C2 - tv5;
+    let x2 = C2 + tv5;
+    let tv8 = tv2 * tv2 * tv3;
+    let x3 = Z + C4 * tv8 * tv8;
+
+    let x = [x1, x2]
+        .into_iter()
+        .find(|x| g(*x).legendre() == LegendreSymbol::QuadraticResidue)
+        .unwrap_or(x3);
+    let mut y = g(x).sqrt().unwrap();
+    if u.into_bigint().is_odd() != y.into_bigint().is_odd() {
+        y = -y;
+    }
+    ark_bn254::G1Affine::new(x, y)
+}
@@ -0,0 +1,5 @@
+//! The hash to curve references behind the `uniform_bytes`, `u0`, `u1`, `q0` and `q1` of
+//! `testcases.json`, shared with the fuzz targets.
+
+pub mod h2c;
+pub mod xmd;
@@ -1,20 +1,18 @@
-use utils::hash_to_curve::CustomPairingHashToCurve;
-use utils::serialize::point::{
-    PointDeserializeCompressed, PointDeserializeUncompressed, PointSerializeCompressed,
-    PointSerializeUncompressed,
-};
+use bls_solidity_rs::dst::Dst;
+use bls_solidity_rs::{Scheme, bls12_381, bn254, compressed, drand};
+use make_test_signature::h2c::{self, HashToCurve};
+use make_test_signature::xmd;
 
-use ark_bls12_381::Bls12_381;
-use ark_bn254::Bn254;
-use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
-use ark_ff::{BigInt, Zero};
-
-use digest::Digest;
+use ark_ff::BigInt;
 
 use serde::{Deserialize, Serialize};
 
 use std::fs::File;
 
+mod boundary;
+mod fixtures;
+mod rfc9380;
+
 #[derive(Serialize, Deserialize)]
 struct TestCase {
     dst: String,
@@ -27,200 +25,327 @@ struct TestCase {
     sig_compressed: String,
     drand_round_number: u64, // Optional: 0 if n/a
     application: String,
+    #[serde(flatten)]
+    h2c: HashToCurve,
 }
 
-static BN254_DST: &str = "BN254G1_XMD:KECCAK-256_SVDW_RO";
-static BLS12_DST: &str = "BLS12381G1_XMD:SHA-256_SSWU_RO";
-
-// Chain ID 31337: anvil
-static HEX_CHAINID: &str = "0x0000000000000000000000000000000000000000000000000000000000007a69";
-
-fn hex_ser_compressed(p: &impl PointSerializeCompressed) -> String {
-    hex::encode(p.ser_compressed().unwrap())
-}
-
-fn hex_ser_uncompressed(p: &impl PointSerializeUncompressed) -> String {
-    hex::encode(p.ser_uncompressed().unwrap())
+#[derive(Serialize, Deserialize)]
+struct ExpandMsgTestCase {
+    dst: String,
+    hash: String, // either "SHA-256" or "KECCAK-256"
+    len_in_bytes: usize,
+    msg: String,
+    uniform_bytes: String,
 }
 
-fn hex_deser_compressed<T: PointDeserializeCompressed>(s: &str) -> T {
-    let bytes = hex::decode(s).unwrap();
-    T::deser_compressed(&mut &bytes[..]).unwrap()
-}
+static BN254_DST: &str = bn254::H2C_SUFFIX;
+static BLS12_DST: &str = bls12_381::H2C_SUFFIX;
 
-fn hex_deser_uncompressed<T: PointDeserializeUncompressed>(s: &str) -> T {
-    let bytes = hex::decode(s).unwrap();
-    T::deser_uncompressed(&mut &bytes[..]).unwrap()
-}
+// Chain ID of anvil
+const CHAIN_ID: u64 = 31337;
 
 fn main() -> anyhow::Result<()> {
     let msg = "hello";
 
-    let bls12_sk = ark_bls12_381::Fr::new(BigInt::new([0, 0, 0, 0xdeadbeef]));
-    let bn254_sk = ark_bn254::Fr::new(BigInt::new([0, 0, 0, 0xdeadbeef]));
+    let bls12_sk =
+        bls12_381::SecretKey::new(ark_bls12_381::Fr::new(BigInt::new([0, 0, 0, 0xdeadbeef])));
+    let bn254_sk = bn254::SecretKey::new(ark_bn254::Fr::new(BigInt::new([0, 0, 0, 0xdeadbeef])));
 
-    serde_json::to_writer_pretty(
-        File::create("testcases.json")?,
-        &[
-            bls12_test_case(msg, bls12_sk),
-            bn254_test_case(msg, bn254_sk),
+    let mut testcases = vec![
+        ("bls12".to_owned(), bls12_test_case(msg, &bls12_sk)),
+        ("bn254".to_owned(), bn254_test_case(msg, &bn254_sk)),
+        (
+            "quicknet_20791007".to_owned(),
             quicknet_test_case(
                 "8d2c8bbc37170dbacc5e280a21d4e195cff5f32a19fd6a58633fa4e4670478b5fb39bc13dd8f8c4372c5a76191198ac5",
                 20791007,
             ),
+        ),
+        (
+            "quicknet_20905307".to_owned(),
             quicknet_test_case(
                 "8a60486975062d9f06633c284cf1a7b46fb343f56f329f180530ca40a9e86320244f4fbfc37ae866cf25ef499665a31f",
                 20905307,
             ),
+        ),
+        (
+            "evmnet_9337227".to_owned(),
             evmnet_test_case(
                 "01d65d6128f4b2df3d08de85543d8efe06b0281d0770246ae3672e8ddd3efda0269373123458f0b5c0073eeed1c816a06809e127421513e34ee07df6987910b3",
                 9337227,
             ),
-            dcipher_bls12_test_case("dcipher-helloworld-v01", msg, bls12_sk),
-            dcipher_bn254_test_case("dcipher-helloworld-v01", msg, bn254_sk),
-        ],
+        ),
+        (
+            "dcipher_bls12".to_owned(),
+            dcipher_bls12_test_case("dcipher-helloworld-v01", msg, &bls12_sk),
+        ),
+        (
+            "dcipher_bn254".to_owned(),
+            dcipher_bn254_test_case("dcipher-helloworld-v01", msg, &bn254_sk),
+        ),
+    ];
+    testcases.extend(
+        rfc9380::BLS12381G1_XMD_SHA256_SSWU_RO
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("rfc9380_sswu_{i}"), rfc9380_test_case(v, &bls12_sk))),
+    );
+
+    // the boundary vectors are too large and repetitive for Fixtures.sol
+    std::fs::write("Fixtures.sol", fixtures::library(&testcases))?;
+
+    for len in boundary::MESSAGE_LENGTHS {
+        let msg = boundary::message(len);
+        testcases.push((
+            format!("bls12_msg_len_{len}"),
+            bls12_signed_test_case(BLS12_DST, &msg, &bls12_sk),
+        ));
+        testcases.push((
+            format!("bn254_msg_len_{len}"),
+            bn254_signed_test_case(BN254_DST, &msg, &bn254_sk),
+        ));
+    }
+    for len in boundary::DST_LENGTHS {
+        let dst = boundary::dst(len);
+        testcases.push((
+            format!("bls12_dst_len_{len}"),
+            bls12_signed_test_case(&dst, msg.as_bytes(), &bls12_sk),
+        ));
+        testcases.push((
+            format!("bn254_dst_len_{len}"),
+            bn254_signed_test_case(&dst, msg.as_bytes(), &bn254_sk),
+        ));
+    }
+
+    let mut expand_msg_testcases = Vec::new();
+    for v in &rfc9380::EXPAND_MSG_XMD_SHA256 {
+        expand_msg_testcases.push(rfc9380_expand_msg_test_case(
+            rfc9380::EXPAND_MSG_XMD_SHA256_DST,
+            v,
+        ));
+    }
+    for v in &rfc9380::EXPAND_MSG_XMD_SHA256_LONG {
+        expand_msg_testcases.push(rfc9380_expand_msg_test_case(
+            rfc9380::EXPAND_MSG_XMD_SHA256_LONG_DST,
+            v,
+        ));
+    }
+    for (hash, scheme) in [("SHA-256", Scheme::Bls12381), ("KECCAK-256", Scheme::Bn254)] {
+        let default_dst = scheme.h2c_suffix();
+        let msg = boundary::message(32);
+        for len in boundary::EXPAND_LENGTHS {
+            expand_msg_testcases.push(expand_msg_test_case(hash, default_dst, &msg, len));
+        }
+        for len in boundary::DST_LENGTHS
+            .into_iter()
+            .chain(boundary::OVERSIZE_DST_LENGTHS)
+        {
+            expand_msg_testcases.push(expand_msg_test_case(hash, &boundary::dst(len), &msg, 32));
+        }
+        let app = boundary::LONG_APPLICATION;
+        let dst = Dst::new(scheme, app, CHAIN_ID).to_string();
+        expand_msg_testcases.push(expand_msg_test_case(hash, &dst, &msg, 96));
+        expand_msg_testcases.push(expand_msg_test_case(hash, &dst, &msg, 128));
+    }
+
+    serde_json::to_writer_pretty(
+        File::create("testcases.json")?,
+        &testcases.iter().map(|(_, tc)| tc).collect::<Vec<_>>(),
     )?;
+    serde_json::to_writer_pretty(File::create("expand_msg.json")?, &expand_msg_testcases)?;
     Ok(())
 }
 
-fn dcipher_bls12_test_case(app: &str, msg: &str, sk: ark_bls12_381::Fr) -> TestCase {
-    let dst = format!("{app}-{BLS12_DST}_{HEX_CHAINID}_");
-    let p = (ark_bls12_381::G2Affine::generator() * sk).into_affine();
-    let m =
-        Bls12_381::hash_to_g1_custom::<sha2::Sha256>(msg.as_bytes(), dst.as_bytes()).into_affine();
-    let s = (m * sk).into_affine();
+fn expand_msg_test_case(
+    hash: &str,
+    dst: &str,
+    msg: &[u8],
+    len_in_bytes: usize,
+) -> ExpandMsgTestCase {
+    let uniform_bytes = match hash {
+        "SHA-256" => xmd::expand_message_xmd::<sha2::Sha256>(msg, dst.as_bytes(), len_in_bytes),
+        "KECCAK-256" => {
+            xmd::expand_message_xmd::<sha3::Keccak256>(msg, dst.as_bytes(), len_in_bytes)
+        }
+        hash => panic!("unknown hash {hash}"),
+    };
+
+    ExpandMsgTestCase {
+        dst: dst.to_owned(),
+        hash: hash.to_owned(),
+        len_in_bytes,
+        msg: hex::encode(msg),
+        uniform_bytes: hex::encode(uniform_bytes),
+    }
+}
+
+fn rfc9380_test_case(v: &rfc9380::HashToCurveVector, sk: &bls12_381::SecretKey) -> TestCase {
+    let dst = rfc9380::BLS12381G1_XMD_SHA256_SSWU_RO_DST;
+    let msg = v.msg.bytes();
+    let tc = bls12_signed_test_case(dst, &msg, sk);
 
-    assert!(
-        Bls12_381::multi_pairing(&[m, s], &[p, -ark_bls12_381::G2Affine::generator()]).is_zero()
+    assert_eq!(tc.m_expected, v.p, "RFC 9380 hash_to_curve mismatch");
+    assert_eq!(
+        [&tc.h2c.u0, &tc.h2c.u1, &tc.h2c.q0, &tc.h2c.q1],
+        [v.u0, v.u1, v.q0, v.q1],
+        "RFC 9380 hash_to_curve intermediate mismatch"
     );
+    tc
+}
 
-    TestCase {
+fn rfc9380_expand_msg_test_case(dst: &str, v: &rfc9380::ExpandMsgVector) -> ExpandMsgTestCase {
+    let msg = v.msg.bytes();
+    let uniform_bytes =
+        xmd::expand_message_xmd::<sha2::Sha256>(&msg, dst.as_bytes(), v.len_in_bytes);
+
+    assert_eq!(
+        hex::encode(&uniform_bytes),
+        v.uniform_bytes,
+        "RFC 9380 expand_message_xmd mismatch"
+    );
+
+    ExpandMsgTestCase {
         dst: dst.to_owned(),
-        scheme: "BLS12381".to_owned(),
-        message: hex::encode(msg),
-        pk: hex_ser_uncompressed(&p),
-        m_expected: hex_ser_uncompressed(&m),
-        sig: hex_ser_uncompressed(&s),
-        sig_compressed: hex_ser_compressed(&s),
-        drand_round_number: 0,
-        application: app.to_owned(),
+        hash: "SHA-256".to_owned(),
+        len_in_bytes: v.len_in_bytes,
+        msg: hex::encode(msg),
+        uniform_bytes: v.uniform_bytes.to_owned(),
     }
 }
 
-fn dcipher_bn254_test_case(app: &str, msg: &str, sk: ark_bn254::Fr) -> TestCase {
-    let dst = format!("{app}-{BN254_DST}_{HEX_CHAINID}_");
-    let p = (ark_bn254::G2Affine::generator() * sk).into_affine();
-    let m =
-        Bn254::hash_to_g1_custom::<sha3::Keccak256>(msg.as_bytes(), dst.as_bytes()).into_affine();
-    let s = (m * sk).into_affine();
-
-    assert!(Bn254::multi_pairing(&[m, s], &[p, -ark_bn254::G2Affine::generator()]).is_zero());
+fn dcipher_bls12_test_case(app: &str, msg: &str, sk: &bls12_381::SecretKey) -> TestCase {
+    let dst = Dst::new(Scheme::Bls12381, app, CHAIN_ID).to_string();
+    TestCase {
+        application: app.to_owned(),
+        ..bls12_signed_test_case(&dst, msg.as_bytes(), sk)
+    }
+}
 
+fn dcipher_bn254_test_case(app: &str, msg: &str, sk: &bn254::SecretKey) -> TestCase {
+    let dst = Dst::new(Scheme::Bn254, app, CHAIN_ID).to_string();
     TestCase {
-        dst: dst.to_owned(),
-        scheme: "BN254".to_owned(),
-        message: hex::encode(msg),
-        pk: hex_ser_uncompressed(&p),
-        m_expected: hex_ser_uncompressed(&m),
-        sig: hex_ser_uncompressed(&s),
-        sig_compressed: "not applicable".to_owned(),
-        drand_round_number: 0,
         application: app.to_owned(),
+        ..bn254_signed_test_case(&dst, msg.as_bytes(), sk)
     }
 }
 
-fn bls12_test_case(msg: &str, sk: ark_bls12_381::Fr) -> TestCase {
-    let dst = BLS12_DST;
-    let p = (ark_bls12_381::G2Affine::generator() * sk).into_affine();
-    let m =
-        Bls12_381::hash_to_g1_custom::<sha2::Sha256>(msg.as_bytes(), dst.as_bytes()).into_affine();
-    let s = (m * sk).into_affine();
+fn bls12_test_case(msg: &str, sk: &bls12_381::SecretKey) -> TestCase {
+    bls12_signed_test_case(BLS12_DST, msg.as_bytes(), sk)
+}
 
-    assert!(
-        Bls12_381::multi_pairing(&[m, s], &[p, -ark_bls12_381::G2Affine::generator()]).is_zero()
-    );
+fn bn254_test_case(msg: &str, sk: &bn254::SecretKey) -> TestCase {
+    bn254_signed_test_case(BN254_DST, msg.as_bytes(), sk)
+}
+
+/// Signs `msg` with `sk`, checking the signature before exporting it.
+fn bls12_signed_test_case(dst: &str, msg: &[u8], sk: &bls12_381::SecretKey) -> TestCase {
+    let p = sk.public_key();
+    let m = bls12_381::hash_to_point(dst.as_bytes(), msg);
+    let s = sk.sign(dst.as_bytes(), msg);
+
+    assert!(p.verify(dst.as_bytes(), msg, &s));
 
     TestCase {
         dst: dst.to_owned(),
-        scheme: "BLS12381".to_owned(),
+        scheme: bls12_381::SCHEME_ID.to_owned(),
         message: hex::encode(msg),
-        pk: hex_ser_uncompressed(&p),
-        m_expected: hex_ser_uncompressed(&m),
-        sig: hex_ser_uncompressed(&s),
-        sig_compressed: hex_ser_compressed(&s),
+        pk: hex::encode(p.to_bytes()),
+        m_expected: hex::encode(bls12_381::g1_marshal(&m)),
+        sig: hex::encode(s.to_bytes()),
+        sig_compressed: hex::encode(s.to_compressed_bytes()),
         drand_round_number: 0,
         application: "".to_owned(),
+        h2c: h2c::bls12(msg, dst.as_bytes()),
     }
 }
 
-fn bn254_test_case(msg: &str, sk: ark_bn254::Fr) -> TestCase {
-    let dst = BN254_DST;
-    let p = (ark_bn254::G2Affine::generator() * sk).into_affine();
-    let m =
-        Bn254::hash_to_g1_custom::<sha3::Keccak256>(msg.as_bytes(), dst.as_bytes()).into_affine();
-    let s = (m * sk).into_affine();
+/// Signs `msg` with `sk`, checking the signature before exporting it.
+fn bn254_signed_test_case(dst: &str, msg: &[u8], sk: &bn254::SecretKey) -> TestCase {
+    let p = sk.public_key();
+    let m = bn254::hash_to_point(dst.as_bytes(), msg);
+    let s = sk.sign(dst.as_bytes(), msg);
 
-    assert!(Bn254::multi_pairing(&[m, s], &[p, -ark_bn254::G2Affine::generator()]).is_zero());
+    assert!(p.verify(dst.as_bytes(), msg, &s));
 
     TestCase {
         dst: dst.to_owned(),
-        scheme: "BN254".to_owned(),
+        scheme: bn254::SCHEME_ID.to_owned(),
         message: hex::encode(msg),
-        pk: hex_ser_uncompressed(&p),
-        m_expected: hex_ser_uncompressed(&m),
-        sig: hex_ser_uncompressed(&s),
+        pk: hex::encode(p.to_bytes()),
+        m_expected: hex::encode(bn254::g1_marshal(&m)),
+        sig: hex::encode(s.to_bytes()),
         sig_compressed: "not applicable".to_owned(),
         drand_round_number: 0,
         application: "".to_owned(),
+        h2c: h2c::bn254(msg, dst.as_bytes()),
     }
 }
 
 fn quicknet_test_case(sig: &str, round: u64) -> TestCase {
-    let dst = format!("BLS_SIG_{BLS12_DST}_NUL_");
-
-    let pk = "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a";
-    let p = hex_deser_compressed(pk);
-    let s = hex_deser_compressed(sig);
-    let msg = &sha2::Sha256::digest(round.to_be_bytes());
-    let m = Bls12_381::hash_to_g1_custom::<sha2::Sha256>(msg, dst.as_bytes());
-
-    assert!(
-        Bls12_381::multi_pairing(&[m, s], &[p, -ark_bls12_381::G2Affine::generator()]).is_zero()
+    let chain = drand::ChainInfo::quicknet();
+    let dst = chain.scheme.dst();
+
+    let p = bls12_381::PublicKey::from_compressed_bytes(&chain.public_key).unwrap();
+    let s = bls12_381::Signature::from_compressed_bytes(&hex::decode(sig).unwrap()).unwrap();
+    // the signatures QuicknetRegistry.proveRound accepts
+    assert_eq!(
+        compressed::g1_compress(&s.0).unwrap(),
+        &hex::decode(sig).unwrap()[..]
     );
+    let msg = &chain.scheme.message(round);
+    let m = bls12_381::hash_to_point(dst.as_bytes(), msg);
+
+    assert!(p.verify(dst.as_bytes(), msg, &s));
+    chain
+        .verify(&drand::Beacon {
+            round,
+            signature: hex::decode(sig).unwrap(),
+            randomness: None,
+        })
+        .unwrap();
 
     TestCase {
         dst: dst.to_owned(),
-        scheme: "BLS12381".to_owned(),
+        scheme: bls12_381::SCHEME_ID.to_owned(),
         message: hex::encode(msg),
-        pk: hex_ser_uncompressed(&p),
-        m_expected: hex_ser_uncompressed(&m),
-        sig: hex_ser_uncompressed(&s),
+        pk: hex::encode(p.to_bytes()),
+        m_expected: hex::encode(bls12_381::g1_marshal(&m)),
+        sig: hex::encode(s.to_bytes()),
         sig_compressed: sig.to_owned(),
         drand_round_number: round,
         application: "".to_owned(),
+        h2c: h2c::bls12(msg, dst.as_bytes()),
     }
 }
 
 fn evmnet_test_case(sig: &str, round: u64) -> TestCase {
-    let dst = format!("BLS_SIG_{BN254_DST}_NUL_");
-
-    let pk = "07e1d1d335df83fa98462005690372c643340060d205306a9aa8106b6bd0b3820557ec32c2ad488e4d4f6008f89a346f18492092ccc0d594610de2732c8b808f0095685ae3a85ba243747b1b2f426049010f6b73a0cf1d389351d5aaaa1047f6297d3a4f9749b33eb2d904c9d9ebf17224150ddd7abd7567a9bec6c74480ee0b";
-    let p = hex_deser_uncompressed(pk);
-    let s = hex_deser_uncompressed(sig);
-    let msg = &sha3::Keccak256::digest(round.to_be_bytes());
-    let m = Bn254::hash_to_g1_custom::<sha3::Keccak256>(msg, dst.as_bytes()).into_affine();
-
-    assert!(Bn254::multi_pairing(&[m, s], &[p, -ark_bn254::G2Affine::generator()]).is_zero());
+    let chain = drand::ChainInfo::evmnet();
+    let dst = chain.scheme.dst();
+
+    let p = bn254::PublicKey::from_bytes(&chain.public_key).unwrap();
+    let s = bn254::Signature::from_bytes(&hex::decode(sig).unwrap()).unwrap();
+    let msg = &chain.scheme.message(round);
+    let m = bn254::hash_to_point(dst.as_bytes(), msg);
+
+    assert!(p.verify(dst.as_bytes(), msg, &s));
+    chain
+        .verify(&drand::Beacon {
+            round,
+            signature: hex::decode(sig).unwrap(),
+            randomness: None,
+        })
+        .unwrap();
 
     TestCase {
         dst: dst.to_owned(),
-        scheme: "BN254".to_owned(),
+        scheme: bn254::SCHEME_ID.to_owned(),
         message: hex::encode(msg),
-        pk: pk.to_owned(),
-        m_expected: hex_ser_uncompressed(&m),
+        pk: hex::encode(&chain.public_key),
+        m_expected: hex::encode(bn254::g1_marshal(&m)),
         sig: sig.to_owned(),
         sig_compressed: "not applicable".to_owned(),
         drand_round_number: round,
         application: "".to_owned(),
+        h2c: h2c::bn254(msg, dst.as_bytes()),
     }
 }
@@ -0,0 +1,68 @@
+//! Reference `expand_message_xmd`, used to export the `uniform_bytes` checked against
+//! `BLS.expandMsg` (Keccak-256) and `BLS2.expandMsg` (SHA-256).
+//!
+//! Unlike the Solidity libraries, which revert with `InvalidDSTLength`, DSTs longer than 255 bytes
+//! are reduced as specified by RFC 9380 §5.3.3.
+
+use digest::Digest;
+use digest::core_api::BlockSizeUser;
+
+/// Prefix of oversize DSTs, see RFC 9380 §5.3.3.
+pub const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// DST used in place of `dst` when it is longer than 255 bytes: H("H2C-OVERSIZE-DST-" || dst).
+pub fn reduce_dst<H: Digest>(dst: &[u8]) -> Vec<u8> {
+    if dst.len() <= 255 {
+        return dst.to_vec();
+    }
+    H::new()
+        .chain_update(OVERSIZE_DST_PREFIX)
+        .chain_update(dst)
+        .finalize()
+        .to_vec()
+}
+
+/// expand_message_xmd as described in RFC 9380 §5.3.1.
+pub fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Vec<u8> {
+    let b_in_bytes = <H as Digest>::output_size();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    assert!(
+        ell <= 255 && len_in_bytes <= 65535,
+        "len_in_bytes too large"
+    );
+
+    let dst = reduce_dst::<H>(dst);
+    let dst_prime = [&dst[..], &[dst.len() as u8]].concat();
+    let z_pad = vec![0u8; H::block_size()];
+    let l_i_b_str = (len_in_bytes as u16).to_be_bytes();
+
+    let b_0 = H::new()
+        .chain_update(z_pad)
+        .chain_update(msg)
+        .chain_update(l_i_b_str)
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = b_i.to_vec();
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = H::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
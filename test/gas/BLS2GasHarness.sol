@@ -0,0 +1,75 @@
+pragma solidity ^0.8;
+
+import {BLS2} from "src/libraries/BLS2.sol";
+
+/// @notice Exposes the BLS2 library to the gas profiler of crates/bls-solidity-evm.
+/// @dev Each function returns the gas used between the same points as the snapshot tests,
+/// leaving out ABI decoding and the unmarshalling of the public key.
+contract BLS2GasHarness {
+    function verifyCompressed(bytes memory pk, bytes memory sig, bytes memory dst, bytes memory message)
+        external
+        view
+        returns (bool ok, uint256 gasUsed)
+    {
+        BLS2.PointG2 memory pubkey = BLS2.g2Unmarshal(pk);
+        uint256 start = gasleft();
+        BLS2.PointG1 memory s = BLS2.g1UnmarshalCompressed(sig);
+        BLS2.PointG1 memory m = BLS2.hashToPoint(dst, message);
+        (bool pairingSuccess, bool callSuccess) = BLS2.verifySingle(s, pubkey, m);
+        gasUsed = start - gasleft();
+        ok = pairingSuccess && callSuccess;
+    }
+
+    function verifyUncompressed(bytes memory pk, bytes memory sig, bytes memory dst, bytes memory message)
+        external
+        view
+        returns (bool ok, uint256 gasUsed)
+    {
+        BLS2.PointG2 memory pubkey = BLS2.g2Unmarshal(pk);
+        uint256 start = gasleft();
+        BLS2.PointG1 memory s = BLS2.g1Unmarshal(sig);
+        BLS2.PointG1 memory m = BLS2.hashToPoint(dst, message);
+        (bool pairingSuccess, bool callSuccess) = BLS2.verifySingle(s, pubkey, m);
+        gasUsed = start - gasleft();
+        ok = pairingSuccess && callSuccess;
+    }
+
+    function g1Unmarshal(bytes memory sig) external view returns (bytes memory point, uint256 gasUsed) {
+        uint256 start = gasleft();
+        BLS2.PointG1 memory p = BLS2.g1Unmarshal(sig);
+        gasUsed = start - gasleft();
+        point = BLS2.g1Marshal(p);
+    }
+
+    function g1UnmarshalCompressed(bytes memory sig) external view returns (bytes memory point, uint256 gasUsed) {
+        uint256 start = gasleft();
+        BLS2.PointG1 memory p = BLS2.g1UnmarshalCompressed(sig);
+        gasUsed = start - gasleft();
+        point = BLS2.g1Marshal(p);
+    }
+
+    function hashToPoint(bytes memory dst, bytes memory message)
+        external
+        view
+        returns (bytes memory point, uint256 gasUsed)
+    {
+        uint256 start = gasleft();
+        BLS2.PointG1 memory p = BLS2.hashToPoint(dst, message);
+        gasUsed = start - gasleft();
+        point = BLS2.g1Marshal(p);
+    }
+
+    function verifySingle(bytes memory sig, bytes memory pk, bytes memory m)
+        external
+        view
+        returns (bool ok, uint256 gasUsed)
+    {
+        BLS2.PointG1 memory s = BLS2.g1Unmarshal(sig);
+        BLS2.PointG2 memory pubkey = BLS2.g2Unmarshal(pk);
+        BLS2.PointG1 memory message = BLS2.g1Unmarshal(m);
+        uint256 start = gasleft();
+        (bool pairingSuccess, bool callSuccess) = BLS2.verifySingle(s, pubkey, message);
+        gasUsed = start - gasleft();
+        ok = pairingSuccess && callSuccess;
+    }
+}
@@ -0,0 +1,54 @@
+pragma solidity ^0.8;
+
+import {BLS} from "src/libraries/BLS.sol";
+
+/// @notice Exposes the BLS library to the gas profiler of crates/bls-solidity-evm.
+/// @dev Each function returns the gas used between the same points as the snapshot tests,
+/// leaving out ABI decoding and the unmarshalling of the public key.
+contract BLSGasHarness {
+    function verifyUncompressed(bytes memory pk, bytes memory sig, bytes memory dst, bytes memory message)
+        external
+        view
+        returns (bool ok, uint256 gasUsed)
+    {
+        BLS.PointG2 memory pubkey = BLS.g2Unmarshal(pk);
+        uint256 start = gasleft();
+        BLS.PointG1 memory s = BLS.g1Unmarshal(sig);
+        BLS.PointG1 memory m = BLS.hashToPoint(dst, message);
+        (bool pairingSuccess, bool callSuccess) = BLS.verifySingle(s, pubkey, m);
+        gasUsed = start - gasleft();
+        ok = pairingSuccess && callSuccess;
+    }
+
+    function g1Unmarshal(bytes memory sig) external view returns (bytes memory point, uint256 gasUsed) {
+        uint256 start = gasleft();
+        BLS.PointG1 memory p = BLS.g1Unmarshal(sig);
+        gasUsed = start - gasleft();
+        point = BLS.g1Marshal(p);
+    }
+
+    function hashToPoint(bytes memory dst, bytes memory message)
+        external
+        view
+        returns (bytes memory point, uint256 gasUsed)
+    {
+        uint256 start = gasleft();
+        BLS.PointG1 memory p = BLS.hashToPoint(dst, message);
+        gasUsed = start - gasleft();
+        point = BLS.g1Marshal(p);
+    }
+
+    function verifySingle(bytes memory sig, bytes memory pk, bytes memory m)
+        external
+        view
+        returns (bool ok, uint256 gasUsed)
+    {
+        BLS.PointG1 memory s = BLS.g1Unmarshal(sig);
+        BLS.PointG2 memory pubkey = BLS.g2Unmarshal(pk);
+        BLS.PointG1 memory message = BLS.g1Unmarshal(m);
+        uint256 start = gasleft();
+        (bool pairingSuccess, bool callSuccess) = BLS.verifySingle(s, pubkey, message);
+        gasUsed = start - gasleft();
+        ok = pairingSuccess && callSuccess;
+    }
+}
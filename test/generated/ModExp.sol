@@ -0,0 +1,1981 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8;
+
+/// @title Compute Inverse by Modular Exponentiation
+/// @notice Compute $input^(N - 2) mod N$ using Addition Chain method.
+/// Where     N = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+/// and   N - 2 = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45
+/// @dev the function body is generated with `bls_ffi addchain bn254-inverse`: 253 squarings and 55 multiplications
+library ModexpInverse {
+    function run(uint256 t1) internal pure returns (uint256 t0) {
+        assembly {
+            let n := 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+            t0 := mulmod(t1, t1, n)
+            let t2 := mulmod(t1, t0, n)
+            let t3 := mulmod(t0, t2, n)
+            let t4 := mulmod(t0, t3, n)
+            let t5 := mulmod(t0, t4, n)
+            let t6 := mulmod(t0, t5, n)
+            let t7 := mulmod(t0, t6, n)
+            let t8 := mulmod(t0, t7, n)
+            t0 := mulmod(t2, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+        }
+    }
+}
+
+/// @title Compute Square Root by Modular Exponentiation
+/// @notice Compute $input^{(N + 1) / 4} mod N$ using Addition Chain method.
+/// Where           N = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+/// and   (N + 1) / 4 = 0xc19139cb84c680a6e14116da060561765e05aa45a1c72a34f082305b61f3f52
+/// @dev the function body is generated with `bls_ffi addchain bn254-sqrt`: 251 squarings and 54 multiplications
+library ModexpSqrt {
+    function run(uint256 t1) internal pure returns (uint256 t0) {
+        assembly {
+            let n := 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+            t0 := mulmod(t1, t1, n)
+            let t2 := mulmod(t1, t0, n)
+            let t3 := mulmod(t0, t2, n)
+            let t4 := mulmod(t0, t3, n)
+            let t5 := mulmod(t0, t4, n)
+            let t6 := mulmod(t0, t5, n)
+            let t7 := mulmod(t0, t6, n)
+            let t8 := mulmod(t0, t7, n)
+            t0 := mulmod(t2, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+        }
+    }
+}
+
+/// @title Compute Legendre Symbol by Modular Exponentiation
+/// @notice Compute $input^{(N - 1) / 2} mod N$ using Addition Chain method.
+/// Where           N = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+/// and   (N - 1) / 2 = 0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3
+/// @dev the function body is generated with `bls_ffi addchain bn254-legendre`: 252 squarings and 55 multiplications
+library ModexpLegendre {
+    function run(uint256 t1) internal pure returns (uint256 t0) {
+        assembly {
+            let n := 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+            t0 := mulmod(t1, t1, n)
+            let t2 := mulmod(t1, t0, n)
+            let t3 := mulmod(t0, t2, n)
+            let t4 := mulmod(t0, t3, n)
+            let t5 := mulmod(t0, t4, n)
+            let t6 := mulmod(t0, t5, n)
+            let t7 := mulmod(t0, t6, n)
+            let t8 := mulmod(t0, t7, n)
+            t0 := mulmod(t2, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t4, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t6, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t7, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t5, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t8, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t3, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t1, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t0, n)
+            t0 := mulmod(t0, t2, n)
+        }
+    }
+}
+
+/// @title Compute Inverse by Modular Exponentiation
+/// @notice Compute $input^(N - 2) mod N$ using Addition Chain method.
+/// Where     N = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab
+/// and   N - 2 = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaa9
+/// @dev the function body is generated with `bls_ffi addchain bls12-381-inverse`: 378 squarings and 85 multiplications
+/// Field elements are (uint128, uint256) pairs like in BLS2.sol, each product being reduced by the MODEXP precompile.
+library BLS2ModexpInverse {
+    function run(uint128 x_hi, uint256 x_lo) internal view returns (uint128 hi, uint256 lo) {
+        assembly {
+            // t_r := t_a * t_b mod N, for registers of at most 384 bits
+            function mulmodn(m, r, a, b) {
+                let x := add(m, add(0x120, shl(6, a)))
+                let y := add(m, add(0x120, shl(6, b)))
+                let w1, w0 := mul512(mload(add(x, 0x20)), mload(add(y, 0x20)))
+                let w2, c := mul512(mload(x), mload(add(y, 0x20)))
+                w1 := add(w1, c)
+                w2 := add(w2, lt(w1, c))
+                let h, d := mul512(mload(add(x, 0x20)), mload(y))
+                w1 := add(w1, d)
+                w2 := add(add(w2, h), add(lt(w1, d), mul(mload(x), mload(y))))
+                mstore(add(m, 0x60), w2)
+                mstore(add(m, 0x80), w1)
+                mstore(add(m, 0xa0), w0)
+                if iszero(staticcall(gas(), 0x05, m, 0x101, add(m, add(0x120, shl(6, r))), 0x40)) {
+                    revert(0, 0)
+                }
+            }
+            // the 512 bits product of two words
+            function mul512(x, y) -> h, l {
+                l := mul(x, y)
+                let mm := mulmod(x, y, not(0))
+                h := sub(sub(mm, l), lt(mm, l))
+            }
+            let m := mload(0x40)
+            mstore(m, 0x60) // length of base, the product
+            mstore(add(m, 0x20), 0x01) // length of exponent
+            mstore(add(m, 0x40), 0x40) // length of modulus
+            mstore8(add(m, 0xc0), 0x01) // exponent
+            mstore(add(m, 0xc1), 0x1a0111ea397fe69a4b1ba7b6434bacd7)
+            mstore(add(m, 0xe1), 0x64774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab)
+            mstore(add(m, 0x160), x_hi)
+            mstore(add(m, 0x180), x_lo)
+            mulmodn(m, 0, 1, 1)
+            mulmodn(m, 2, 1, 0)
+            mulmodn(m, 3, 0, 2)
+            mulmodn(m, 4, 0, 3)
+            mulmodn(m, 5, 0, 4)
+            mulmodn(m, 6, 0, 5)
+            mulmodn(m, 7, 0, 6)
+            mulmodn(m, 8, 0, 7)
+            mulmodn(m, 0, 7, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            hi := mload(add(m, 0x120))
+            lo := mload(add(m, 0x140))
+        }
+    }
+}
+
+/// @title Compute Square Root by Modular Exponentiation
+/// @notice Compute $input^{(N + 1) / 4} mod N$ using Addition Chain method.
+/// Where           N = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab
+/// and   (N + 1) / 4 = 0x680447a8e5ff9a692c6e9ed90d2eb35d91dd2e13ce144afd9cc34a83dac3d8907aaffffac54ffffee7fbfffffffeaab
+/// @dev the function body is generated with `bls_ffi addchain bls12-381-sqrt`: 376 squarings and 85 multiplications
+/// Field elements are (uint128, uint256) pairs like in BLS2.sol, each product being reduced by the MODEXP precompile.
+library BLS2ModexpSqrt {
+    function run(uint128 x_hi, uint256 x_lo) internal view returns (uint128 hi, uint256 lo) {
+        assembly {
+            // t_r := t_a * t_b mod N, for registers of at most 384 bits
+            function mulmodn(m, r, a, b) {
+                let x := add(m, add(0x120, shl(6, a)))
+                let y := add(m, add(0x120, shl(6, b)))
+                let w1, w0 := mul512(mload(add(x, 0x20)), mload(add(y, 0x20)))
+                let w2, c := mul512(mload(x), mload(add(y, 0x20)))
+                w1 := add(w1, c)
+                w2 := add(w2, lt(w1, c))
+                let h, d := mul512(mload(add(x, 0x20)), mload(y))
+                w1 := add(w1, d)
+                w2 := add(add(w2, h), add(lt(w1, d), mul(mload(x), mload(y))))
+                mstore(add(m, 0x60), w2)
+                mstore(add(m, 0x80), w1)
+                mstore(add(m, 0xa0), w0)
+                if iszero(staticcall(gas(), 0x05, m, 0x101, add(m, add(0x120, shl(6, r))), 0x40)) {
+                    revert(0, 0)
+                }
+            }
+            // the 512 bits product of two words
+            function mul512(x, y) -> h, l {
+                l := mul(x, y)
+                let mm := mulmod(x, y, not(0))
+                h := sub(sub(mm, l), lt(mm, l))
+            }
+            let m := mload(0x40)
+            mstore(m, 0x60) // length of base, the product
+            mstore(add(m, 0x20), 0x01) // length of exponent
+            mstore(add(m, 0x40), 0x40) // length of modulus
+            mstore8(add(m, 0xc0), 0x01) // exponent
+            mstore(add(m, 0xc1), 0x1a0111ea397fe69a4b1ba7b6434bacd7)
+            mstore(add(m, 0xe1), 0x64774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab)
+            mstore(add(m, 0x160), x_hi)
+            mstore(add(m, 0x180), x_lo)
+            mulmodn(m, 0, 1, 1)
+            mulmodn(m, 2, 1, 0)
+            mulmodn(m, 3, 0, 2)
+            mulmodn(m, 4, 0, 3)
+            mulmodn(m, 5, 0, 4)
+            mulmodn(m, 6, 0, 5)
+            mulmodn(m, 7, 0, 6)
+            mulmodn(m, 8, 0, 7)
+            mulmodn(m, 0, 7, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 1)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 4)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 6)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 5)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 7)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 8)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 3)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 0)
+            mulmodn(m, 0, 0, 2)
+            hi := mload(add(m, 0x120))
+            lo := mload(add(m, 0x140))
+        }
+    }
+}